@@ -0,0 +1,154 @@
+use crate::*;
+
+/// The nine standard positions of a rect inside another rect for `Rect::align_in`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Align {
+    TopLeft,
+    Top,
+    TopRight,
+    Left,
+    Center,
+    Right,
+    BottomLeft,
+    Bottom,
+    BottomRight,
+}
+
+#[derive(Clone, Copy)]
+enum AxisAlign {
+    Start,
+    Center,
+    End,
+}
+
+impl AxisAlign {
+    /// Returns the start of a span of `len` aligned in `outer_start..outer_start + outer_len`.
+    #[inline]
+    fn place<T>(self, outer_start: T, outer_len: T, len: T) -> T
+    where
+        T: Scalar + core::ops::Div<T, Output = T> + One,
+    {
+        let two = T::one() + T::one();
+        match self {
+            Self::Start => outer_start,
+            Self::End => outer_start + outer_len - len,
+            Self::Center if outer_len >= len => outer_start + (outer_len - len) / two,
+            Self::Center => outer_start - (len - outer_len) / two,
+        }
+    }
+}
+
+impl Align {
+    #[inline]
+    fn axes(self) -> (AxisAlign, AxisAlign) {
+        use AxisAlign::*;
+        match self {
+            Self::TopLeft => (Start, Start),
+            Self::Top => (Center, Start),
+            Self::TopRight => (End, Start),
+            Self::Left => (Start, Center),
+            Self::Center => (Center, Center),
+            Self::Right => (End, Center),
+            Self::BottomLeft => (Start, End),
+            Self::Bottom => (Center, End),
+            Self::BottomRight => (End, End),
+        }
+    }
+}
+
+impl<T> Rect<T>
+where
+    T: Scalar + core::ops::Div<T, Output = T> + One,
+{
+    /// Moves `self` to the position of `align` inside `outer`, keeping the size.
+    ///
+    /// For integers a centered axis is placed at `(outer_len - len) / 2` from the start of
+    /// `outer`, rounded toward zero, so an odd leftover goes to the right or the bottom.
+    ///
+    /// A rect larger than `outer` sticks out of it, so its origin can be less than the origin
+    /// of `outer`.
+    ///
+    /// # Panics
+    ///
+    /// For unsigned `T`, panics on overflow in debug builds if the aligned origin would be
+    /// negative, which happens when `self` is larger than `outer` and `outer` is too near to 0.
+    #[inline]
+    pub fn align_in(&self, outer: &Rect<T>, align: Align) -> Rect<T> {
+        let (h, v) = align.axes();
+        let x = h.place(outer.origin.x, outer.size.width, self.size.width);
+        let y = v.place(outer.origin.y, outer.size.height, self.size.height);
+        Rect::new((x, y), self.size)
+    }
+
+    /// Same as `align_in` with `outer` deflated by `margin` on every side.
+    #[inline]
+    pub fn align_in_with_margin(
+        &self,
+        outer: &Rect<T>,
+        align: Align,
+        margin: impl Into<Size<T>>,
+    ) -> Rect<T>
+    where
+        T: Zero,
+    {
+        self.align_in(&outer.deflate(margin), align)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn align_in_test() {
+        let outer = rect((10, 20), (101, 51));
+        let a = rect((0, 0), (20, 10));
+        let cases = [
+            (Align::TopLeft, (10, 20)),
+            (Align::Top, (50, 20)),
+            (Align::TopRight, (91, 20)),
+            (Align::Left, (10, 40)),
+            (Align::Center, (50, 40)),
+            (Align::Right, (91, 40)),
+            (Align::BottomLeft, (10, 61)),
+            (Align::Bottom, (50, 61)),
+            (Align::BottomRight, (91, 61)),
+        ];
+        for (align, origin) in cases {
+            assert!(a.align_in(&outer, align) == rect(origin, (20, 10)));
+        }
+    }
+
+    #[test]
+    fn align_in_larger_test() {
+        let outer = rect((10u32, 10), (10, 10));
+        let a = rect((0, 0), (15, 15));
+        assert!(a.align_in(&outer, Align::Center) == rect((8, 8), (15, 15)));
+        assert!(a.align_in(&outer, Align::TopLeft) == rect((10, 10), (15, 15)));
+        let outer = rect((0, 0), (10, 10));
+        assert!(a.align_in(&outer, Align::TopLeft) == rect((0, 0), (15, 15)));
+        let (a, outer) = (rect((0, 0), (15i32, 15)), rect((0, 0), (10, 10)));
+        assert!(a.align_in(&outer, Align::Center) == rect((-2, -2), (15, 15)));
+        assert!(a.align_in(&outer, Align::BottomRight) == rect((-5, -5), (15, 15)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn align_in_larger_unsigned_at_zero_test() {
+        let outer = rect((0u32, 0), (10, 10));
+        rect((0, 0), (15, 15)).align_in(&outer, Align::BottomRight);
+    }
+
+    #[test]
+    fn align_in_with_margin_test() {
+        let panel = rect((0, 0), (400, 300));
+        let a = rect((0, 0), (100, 40));
+        let b = a.align_in_with_margin(&panel, Align::BottomRight, (8, 8));
+        assert!(b == rect((292, 252), (100, 40)));
+        let b = a.align_in_with_margin(&panel, Align::TopLeft, (8, 4));
+        assert!(b == rect((8, 4), (100, 40)));
+        let b = a.align_in_with_margin(&panel, Align::Center, (8, 8));
+        assert!(b == a.align_in(&panel, Align::Center));
+    }
+}
@@ -0,0 +1,125 @@
+use crate::*;
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+macro_rules! impl_approx {
+    ($($t:ident { $($field:ident),+ }),*) => {
+        $(
+            impl<T> AbsDiffEq for $t<T>
+            where
+                T: AbsDiffEq,
+                T::Epsilon: Copy,
+            {
+                type Epsilon = T::Epsilon;
+
+                #[inline]
+                fn default_epsilon() -> Self::Epsilon {
+                    T::default_epsilon()
+                }
+
+                #[inline]
+                fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+                    $(self.$field.abs_diff_eq(&other.$field, epsilon))&&+
+                }
+            }
+
+            impl<T> RelativeEq for $t<T>
+            where
+                T: RelativeEq,
+                T::Epsilon: Copy,
+            {
+                #[inline]
+                fn default_max_relative() -> Self::Epsilon {
+                    T::default_max_relative()
+                }
+
+                #[inline]
+                fn relative_eq(
+                    &self,
+                    other: &Self,
+                    epsilon: Self::Epsilon,
+                    max_relative: Self::Epsilon,
+                ) -> bool {
+                    $(self.$field.relative_eq(&other.$field, epsilon, max_relative))&&+
+                }
+            }
+
+            impl<T> UlpsEq for $t<T>
+            where
+                T: UlpsEq,
+                T::Epsilon: Copy,
+            {
+                #[inline]
+                fn default_max_ulps() -> u32 {
+                    T::default_max_ulps()
+                }
+
+                #[inline]
+                fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+                    $(self.$field.ulps_eq(&other.$field, epsilon, max_ulps))&&+
+                }
+            }
+        )*
+    };
+}
+
+impl_approx!(
+    Vector { x, y },
+    Point { x, y },
+    Size { width, height },
+    Rect { origin, size },
+    RoundedRect { rect, radius },
+    Circle { center, radius },
+    Segment { start, end },
+    Triangle { a, b, c },
+    Ray { origin, direction },
+    Rgba { r, g, b, a },
+    Vector3 { x, y, z },
+    Point3 { x, y, z },
+    Size3 {
+        width,
+        height,
+        depth
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::{
+        assert_abs_diff_eq, assert_relative_eq, assert_relative_ne, assert_ulps_eq, relative_eq,
+    };
+
+    #[test]
+    fn vector_test() {
+        let a = vector(0.1f32 + 0.2, 1.0);
+        assert_relative_eq!(a, vector(0.3, 1.0));
+        assert_ulps_eq!(a, vector(0.3, 1.0));
+        assert_relative_ne!(a, vector(0.3, 1.1));
+    }
+
+    #[test]
+    fn point_size_test() {
+        assert_abs_diff_eq!(point(1.0f64, 2.0), point(1.05, 2.0), epsilon = 0.1);
+        assert!(!relative_eq!(point(1.0f64, 2.0), point(1.05, 2.0)));
+        assert_relative_eq!(size(0.1f64 * 3.0, 2.0), size(0.3, 2.0));
+        assert_relative_ne!(size(1.0f64, 2.0), size(1.0, 2.5));
+    }
+
+    #[test]
+    fn rect_circle_test() {
+        let a = rect((0.1f32 + 0.2, 0.0), (10.0, 20.0));
+        assert_relative_eq!(a, rect((0.3, 0.0), (10.0, 20.0)));
+        assert_relative_ne!(a, rect((0.3, 0.0), (10.0, 21.0)));
+        let c = circle((0.1f64 + 0.2, 1.0), 0.7 * 3.0);
+        assert_ulps_eq!(c, circle((0.3, 1.0), 2.1));
+        assert_relative_ne!(c, circle((0.3, 1.0), 2.2));
+    }
+
+    #[test]
+    fn rgba_test() {
+        let a = rgba(0.1f32 + 0.2, 0.5, 0.25, 1.0);
+        assert_relative_eq!(a, rgba(0.3, 0.5, 0.25, 1.0));
+        assert_relative_ne!(a, rgba(0.3, 0.5, 0.25, 0.9));
+        assert_abs_diff_eq!(rgba(10u8, 20, 30, 40), rgba(10, 20, 30, 40));
+    }
+}
@@ -0,0 +1,361 @@
+use crate::*;
+
+/// Separable blend modes of the W3C Compositing and Blending spec, plus additive blending.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BlendMode {
+    Normal,
+    Multiply,
+    Screen,
+    /// `min(backdrop + source, 1)`
+    Additive,
+    Overlay,
+    Darken,
+    Lighten,
+}
+
+impl BlendMode {
+    #[inline]
+    fn apply<T: Float>(self, cb: T, cs: T) -> T {
+        let two = T::from(2.0).unwrap();
+        let screen = |a: T, b: T| a + b - a * b;
+        match self {
+            Self::Normal => cs,
+            Self::Multiply => cb * cs,
+            Self::Screen => screen(cb, cs),
+            Self::Additive => (cb + cs).min(T::one()),
+            Self::Overlay => {
+                if cb <= T::from(0.5).unwrap() {
+                    cs * two * cb
+                } else {
+                    screen(cs, two * cb - T::one())
+                }
+            }
+            Self::Darken => cb.min(cs),
+            Self::Lighten => cb.max(cs),
+        }
+    }
+}
+
+impl<T: Float> Rgba<T> {
+    /// Blends `self` as the source over `backdrop` with `mode` and composites with source-over.
+    ///
+    /// Both colors are straight (not premultiplied) alpha, as is the result.
+    pub fn blend(self, backdrop: impl Into<Rgba<T>>, mode: BlendMode) -> Rgba<T> {
+        let b = backdrop.into();
+        let (sa, ba) = (self.a, b.a);
+        let a = sa + ba * (T::one() - sa);
+        if a == T::zero() {
+            return Rgba::new(T::zero(), T::zero(), T::zero(), T::zero());
+        }
+        let f = |cs: T, cb: T| {
+            let cs = (T::one() - ba) * cs + ba * mode.apply(cb, cs);
+            (sa * cs + ba * cb * (T::one() - sa)) / a
+        };
+        Rgba::new(f(self.r, b.r), f(self.g, b.g), f(self.b, b.b), a)
+    }
+
+    /// Composites `self` over `backdrop`. Same as `blend` with `BlendMode::Normal`.
+    #[inline]
+    pub fn over(self, backdrop: impl Into<Rgba<T>>) -> Rgba<T> {
+        self.blend(backdrop, BlendMode::Normal)
+    }
+}
+
+/// `x / 255` rounded to nearest for `x <= 65535`, without a division.
+#[inline(always)]
+fn div255(x: u32) -> u32 {
+    let x = x + 128;
+    (x + (x >> 8)) >> 8
+}
+
+/// `(n + d / 2) / d` for `d > 0` and a quotient below 256.
+///
+/// It is a restoring division selecting with masks instead of branches, since a division by a
+/// variable prevents auto-vectorization.
+#[inline(always)]
+fn div_round_u8(n: u32, d: u32) -> u32 {
+    let mut n = n + d / 2;
+    let mut q = 0;
+    for i in (0..8).rev() {
+        let m = d << i;
+        let ge = ((n >= m) as u32).wrapping_neg();
+        n -= m & ge;
+        q |= (1 << i) & ge;
+    }
+    q
+}
+
+/// Composites `src` over `dst` for `blend_over_slice`.
+#[inline(always)]
+fn blend_over(src: Rgba<u8>, dst: Rgba<u8>) -> Rgba<u8> {
+    let sa = src.a as u32;
+    let sw = sa * 255;
+    let dw = dst.a as u32 * (255 - sa);
+    let w = sw + dw;
+    // both are transparent, so `dst` is kept as it is
+    let keep = ((w == 0) as u32).wrapping_neg();
+    let f = |sc: u8, dc: u8| {
+        let c = div_round_u8(sc as u32 * sw + dc as u32 * dw, w);
+        ((c & !keep) | (dc as u32 & keep)) as u8
+    };
+    Rgba::new(
+        f(src.r, dst.r),
+        f(src.g, dst.g),
+        f(src.b, dst.b),
+        div255(w) as u8,
+    )
+}
+
+/// Composites each pixel of `src` over the pixel of `dst` at the same index with straight alpha.
+///
+/// Only integer math is used. Channels are weighted in units of `1 / 255²` and divided with
+/// rounding to nearest, e.g. the alpha is `(w + 127) / 255`. The loop has no branches or
+/// divisions by a variable, so it can be auto-vectorized. A transparent `src` over a transparent
+/// `dst` leaves `dst` as it is.
+///
+/// # Panics
+///
+/// Panics if `src` and `dst` have different lengths.
+pub fn blend_over_slice(src: &[Rgba<u8>], dst: &mut [Rgba<u8>]) {
+    assert_eq!(src.len(), dst.len(), "blend_over_slice: length mismatch");
+    for (s, d) in src.iter().zip(dst.iter_mut()) {
+        *d = blend_over(*s, *d);
+    }
+}
+
+/// Composites each pixel of `src` over the pixel of `dst` at the same index with premultiplied alpha.
+///
+/// Each channel is computed as `s + d * (255 - s.a) / 255` with the division rounded to nearest.
+///
+/// # Panics
+///
+/// Panics if `src` and `dst` have different lengths.
+pub fn blend_over_slice_premultiplied(src: &[Rgba<u8>], dst: &mut [Rgba<u8>]) {
+    assert_eq!(
+        src.len(),
+        dst.len(),
+        "blend_over_slice_premultiplied: length mismatch"
+    );
+    for (s, d) in src.iter().zip(dst.iter_mut()) {
+        let inv = 255 - s.a;
+        let f = |sc: u8, dc: u8| sc.saturating_add(dc.channel_mul(inv));
+        *d = Rgba::new(f(s.r, d.r), f(s.g, d.g), f(s.b, d.b), f(s.a, d.a));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn near(a: Rgba<f32>, b: impl Into<Rgba<f32>>) -> bool {
+        let d = a - b.into();
+        d.r.abs() < 1e-6 && d.g.abs() < 1e-6 && d.b.abs() < 1e-6 && d.a.abs() < 1e-6
+    }
+
+    const S: Rgba<f32> = Rgba {
+        r: 0.25,
+        g: 0.5,
+        b: 0.75,
+        a: 1.0,
+    };
+    const B: Rgba<f32> = Rgba {
+        r: 0.5,
+        g: 0.25,
+        b: 0.75,
+        a: 1.0,
+    };
+
+    #[test]
+    fn normal_test() {
+        assert!(near(S.blend(B, BlendMode::Normal), S));
+        let s = rgba(1.0, 0.0, 0.0, 0.5);
+        let b = rgba(0.0, 0.0, 1.0, 1.0);
+        assert!(near(s.blend(b, BlendMode::Normal), (0.5, 0.0, 0.5, 1.0)));
+        let t = rgba(0.0, 0.0, 0.0, 0.0);
+        assert!(near(t.blend(t, BlendMode::Normal), t));
+    }
+
+    #[test]
+    fn multiply_test() {
+        assert!(near(
+            S.blend(B, BlendMode::Multiply),
+            (0.125, 0.125, 0.5625, 1.0)
+        ));
+        assert!(near(S.blend((1.0, 1.0, 1.0, 1.0), BlendMode::Multiply), S));
+    }
+
+    #[test]
+    fn screen_test() {
+        assert!(near(
+            S.blend(B, BlendMode::Screen),
+            (0.625, 0.625, 0.9375, 1.0)
+        ));
+        assert!(near(S.blend((0.0, 0.0, 0.0, 1.0), BlendMode::Screen), S));
+    }
+
+    #[test]
+    fn additive_test() {
+        assert!(near(
+            S.blend(B, BlendMode::Additive),
+            (0.75, 0.75, 1.0, 1.0)
+        ));
+    }
+
+    #[test]
+    fn overlay_test() {
+        assert!(near(
+            S.blend(B, BlendMode::Overlay),
+            (0.25, 0.25, 0.875, 1.0)
+        ));
+    }
+
+    #[test]
+    fn darken_lighten_test() {
+        assert!(near(S.blend(B, BlendMode::Darken), (0.25, 0.25, 0.75, 1.0)));
+        assert!(near(S.blend(B, BlendMode::Lighten), (0.5, 0.5, 0.75, 1.0)));
+    }
+
+    fn pixels() -> Vec<Rgba<u8>> {
+        let mut seed = 1u32;
+        let mut next = || {
+            seed = seed.wrapping_mul(1664525).wrapping_add(1013904223);
+            (seed >> 24) as u8
+        };
+        let mut v = (0..512)
+            .map(|_| rgba(next(), next(), next(), next()))
+            .collect::<Vec<_>>();
+        v.extend([rgba(1, 2, 3, 0), rgba(4, 5, 6, 255), rgba(7, 8, 9, 1)]);
+        v
+    }
+
+    fn near_u8(a: Rgba<u8>, b: Rgba<u8>) -> bool {
+        a.into_iter()
+            .zip(b)
+            .all(|(a, b)| (a as i32 - b as i32).abs() <= 1)
+    }
+
+    #[test]
+    fn over_test() {
+        assert!(near(S.over(B), S));
+        let s = rgba(1.0, 0.0, 0.0, 0.5);
+        assert!(near(s.over((0.0, 0.0, 1.0, 1.0)), (0.5, 0.0, 0.5, 1.0)));
+    }
+
+    #[test]
+    fn blend_over_slice_test() {
+        let src = pixels();
+        let mut dst = pixels();
+        dst.rotate_left(7);
+        let expected = src
+            .iter()
+            .zip(&dst)
+            .map(|(s, d)| s.to_f32().over(d.to_f32()).to_u8())
+            .collect::<Vec<_>>();
+        blend_over_slice(&src, &mut dst);
+        for (d, e) in dst.iter().zip(&expected) {
+            assert!(near_u8(*d, *e));
+        }
+    }
+
+    #[test]
+    fn blend_over_slice_matches_blend_over_test() {
+        let src = pixels();
+        let mut dst = pixels();
+        dst.rotate_left(3);
+        let expected = src
+            .iter()
+            .zip(&dst)
+            .map(|(s, d)| blend_over(*s, *d))
+            .collect::<Vec<_>>();
+        for (s, d) in src.iter().zip(&dst) {
+            assert!(near_u8(
+                blend_over(*s, *d),
+                s.to_f32().over(d.to_f32()).to_u8()
+            ));
+        }
+        blend_over_slice(&src, &mut dst);
+        assert!(dst == expected);
+    }
+
+    #[test]
+    fn div_round_u8_test() {
+        for d in (1..=65025u32).step_by(97).chain([255, 65025]) {
+            for q in [0u32, 1, 127, 254, 255] {
+                for n in [q * d, q * d + d / 2, (q * d + d / 2).saturating_sub(1)] {
+                    if n + d / 2 < 256 * d {
+                        assert!(div_round_u8(n, d) == (n + d / 2) / d);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn blend_over_slice_transparent_test() {
+        let src = [rgba(0, 0, 0, 0), rgba(1, 2, 3, 0)];
+        let mut dst = [rgba(10, 20, 30, 0), rgba(40, 50, 60, 0)];
+        blend_over_slice(&src, &mut dst);
+        assert!(dst == [rgba(10, 20, 30, 0), rgba(40, 50, 60, 0)]);
+    }
+
+    #[test]
+    fn div255_test() {
+        for x in 0..=65535u32 {
+            assert!(div255(x) == (x + 127) / 255);
+        }
+    }
+
+    #[test]
+    fn blend_over_slice_fast_path_test() {
+        let src = [rgba(1, 2, 3, 0), rgba(4, 5, 6, 255)];
+        let mut dst = [rgba(10, 20, 30, 40), rgba(50, 60, 70, 80)];
+        blend_over_slice(&src, &mut dst);
+        assert!(dst == [rgba(10, 20, 30, 40), rgba(4, 5, 6, 255)]);
+    }
+
+    #[test]
+    fn blend_over_slice_premultiplied_test() {
+        let premultiply = |c: Rgba<u8>| {
+            rgba(
+                c.r.channel_mul(c.a),
+                c.g.channel_mul(c.a),
+                c.b.channel_mul(c.a),
+                c.a,
+            )
+        };
+        let src = pixels().into_iter().map(premultiply).collect::<Vec<_>>();
+        let mut dst = pixels().into_iter().map(premultiply).collect::<Vec<_>>();
+        dst.rotate_left(7);
+        let expected = src
+            .iter()
+            .zip(&dst)
+            .map(|(s, d)| {
+                let (s, d) = (s.to_f32(), d.to_f32());
+                (s + d * (1.0 - s.a)).to_u8()
+            })
+            .collect::<Vec<_>>();
+        blend_over_slice_premultiplied(&src, &mut dst);
+        for (d, e) in dst.iter().zip(&expected) {
+            assert!(near_u8(*d, *e));
+        }
+        let src = [rgba(0, 0, 0, 0), rgba(4, 5, 6, 255)];
+        let mut dst = [rgba(10, 20, 30, 40), rgba(50, 60, 70, 80)];
+        blend_over_slice_premultiplied(&src, &mut dst);
+        assert!(dst == [rgba(10, 20, 30, 40), rgba(4, 5, 6, 255)]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn blend_over_slice_length_mismatch_test() {
+        blend_over_slice(&[rgba(0, 0, 0, 0)], &mut []);
+    }
+
+    #[test]
+    fn alpha_test() {
+        let s = rgba(1.0, 1.0, 1.0, 0.5);
+        let b = rgba(0.5, 0.5, 0.5, 0.5);
+        let c = s.blend(b, BlendMode::Multiply);
+        assert!(near(c, (2.0 / 3.0, 2.0 / 3.0, 2.0 / 3.0, 0.75)));
+    }
+}
@@ -0,0 +1,58 @@
+use crate::*;
+use bytemuck::{Pod, Zeroable};
+
+// SAFETY: All the types are `repr(C)` and consist only of fields of `T` (directly or through
+// other such types), so they have no padding and every bit pattern is valid when it is for `T`.
+macro_rules! impl_pod {
+    ($($t:ident),*) => {
+        $(
+            unsafe impl<T: Zeroable> Zeroable for $t<T> {}
+            unsafe impl<T: Pod> Pod for $t<T> {}
+        )*
+    };
+}
+
+impl_pod!(
+    Vector,
+    Point,
+    Size,
+    Rect,
+    RoundedRect,
+    Circle,
+    Segment,
+    Triangle,
+    Ray,
+    Rgba,
+    Vector3,
+    Point3,
+    Size3,
+    Transform
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cast_slice_test() {
+        let colors = [rgba(0.1f32, 0.2, 0.3, 0.4), rgba(0.5, 0.6, 0.7, 0.8)];
+        let v: &[f32] = bytemuck::cast_slice(&colors);
+        assert!(v == [0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8]);
+        let rects = [rect((1.0f32, 2.0), (3.0, 4.0))];
+        let v: &[f32] = bytemuck::cast_slice(&rects);
+        assert!(v == [1.0, 2.0, 3.0, 4.0]);
+        let circles = [circle((1i32, 2), 3)];
+        let v: &[i32] = bytemuck::cast_slice(&circles);
+        assert!(v == [1, 2, 3]);
+        let points = [point(1u16, 2), point(3, 4)];
+        let v: &[u16] = bytemuck::cast_slice(&points);
+        assert!(v == [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn zeroed_test() {
+        assert!(Vector::<f32>::zeroed() == (0.0, 0.0));
+        assert!(Size::<u32>::zeroed() == (0, 0));
+        assert!(Rect::<i32>::zeroed() == rect((0, 0), (0, 0)));
+    }
+}
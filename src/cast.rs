@@ -0,0 +1,114 @@
+use crate::*;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+enum CastValue {
+    Int(i128),
+    UInt(u128),
+    Float(f64),
+    Unknown,
+}
+
+impl CastValue {
+    #[inline]
+    fn of<T: ToPrimitive>(v: &T) -> Self {
+        // `to_i128` truncates floats, so keep the values which are not integers as floats
+        if let Some(f) = v.to_f64() {
+            if f as i128 as f64 != f && f as u128 as f64 != f {
+                return Self::Float(f);
+            }
+        }
+        if let Some(v) = v.to_i128() {
+            Self::Int(v)
+        } else if let Some(v) = v.to_u128() {
+            Self::UInt(v)
+        } else if let Some(v) = v.to_f64() {
+            Self::Float(v)
+        } else {
+            Self::Unknown
+        }
+    }
+}
+
+impl core::fmt::Display for CastValue {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::Int(v) => write!(f, "{}", v),
+            Self::UInt(v) => write!(f, "{}", v),
+            Self::Float(v) => write!(f, "{}", v),
+            Self::Unknown => write!(f, "?"),
+        }
+    }
+}
+
+/// The error of `try_cast`, naming the first component that does not fit the target type.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct CastError {
+    type_name: &'static str,
+    component: &'static str,
+    value: CastValue,
+}
+
+impl CastError {
+    /// The name of the geometry type, e.g. `"Rect"`.
+    #[inline]
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// The name of the component, e.g. `"origin.x"`.
+    #[inline]
+    pub fn component(&self) -> &'static str {
+        self.component
+    }
+}
+
+impl core::fmt::Display for CastError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(
+            f,
+            "{}.{} = {} is out of range of the target type",
+            self.type_name, self.component, self.value
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CastError {}
+
+#[inline]
+pub(crate) fn cast_component<T: ToPrimitive + Copy, U: NumCast>(
+    v: T,
+    type_name: &'static str,
+    component: &'static str,
+) -> Result<U, CastError> {
+    U::from(v).ok_or_else(|| CastError {
+        type_name,
+        component,
+        value: CastValue::of(&v),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_test() {
+        let e = point(1, -3).try_cast::<u8>().unwrap_err();
+        assert!(e.to_string() == "Point.y = -3 is out of range of the target type");
+        let e = rgba(0.5f32, f32::NAN, 0.0, 1.0)
+            .try_cast::<u8>()
+            .unwrap_err();
+        assert!(e.to_string() == "Rgba.g = NaN is out of range of the target type");
+        let e = vector(u128::MAX, 0).try_cast::<u64>().unwrap_err();
+        assert!(e.to_string().contains(&u128::MAX.to_string()));
+        let e = point(1000.7f64, 0.0).try_cast::<u8>().unwrap_err();
+        assert!(e.to_string() == "Point.x = 1000.7 is out of range of the target type");
+        let e = size(0.0f64, -200.25).try_cast::<i8>().unwrap_err();
+        assert!(e.to_string() == "Size.height = -200.25 is out of range of the target type");
+        let e = point(f64::INFINITY, 0.0).try_cast::<i32>().unwrap_err();
+        assert!(e.to_string() == "Point.x = inf is out of range of the target type");
+        let e = point(i128::MIN, 0).try_cast::<i64>().unwrap_err();
+        assert!(e.to_string().contains(&i128::MIN.to_string()));
+    }
+}
@@ -1,6 +1,6 @@
 use crate::*;
 
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Debug)]
 #[repr(C)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Circle<T> {
@@ -16,6 +16,154 @@ impl<T> Circle<T> {
             radius,
         }
     }
+
+    /// A `const` variant of `Circle::new`.
+    #[inline]
+    pub const fn from_center_radius(center: Point<T>, radius: T) -> Self {
+        Self { center, radius }
+    }
+}
+
+impl<T: FloatScalar> Circle<T> {
+    /// Creates the circle whose diameter is the segment from `a` to `b`.
+    #[inline]
+    pub fn from_diameter(a: impl Into<Point<T>>, b: impl Into<Point<T>>) -> Self {
+        let (a, b) = (a.into(), b.into());
+        let two = T::one() + T::one();
+        Self::new(a + (b - a) / two, a.distance(b) / two)
+    }
+
+    /// Creates the circle passing through `a`, `b` and `c`.
+    ///
+    /// Returns `None` if the points are collinear or nearly so, that is, if the sine of the angle
+    /// at `a` is at most `T::epsilon().sqrt()`, where the circle would be too large for the
+    /// precision of `T`. Coincident points count as collinear.
+    pub fn from_three_points(
+        a: impl Into<Point<T>>,
+        b: impl Into<Point<T>>,
+        c: impl Into<Point<T>>,
+    ) -> Option<Self> {
+        let a = a.into();
+        let (b, c) = (b.into() - a, c.into() - a);
+        let cross = b.cross(c);
+        if cross.abs() <= T::epsilon().sqrt() * b.abs() * c.abs() {
+            return None;
+        }
+        let d = cross + cross;
+        let (bb, cc) = (b.abs_pow2(), c.abs_pow2());
+        let u = vector((c.y * bb - b.y * cc) / d, (b.x * cc - c.x * bb) / d);
+        Some(Self::new(a + u, u.abs()))
+    }
+
+    /// Returns an approximate bounding circle of `points` in a single pass, or `None` if `points`
+    /// is empty.
+    ///
+    /// The circle grows to include each point outside it in turn, so it contains every point but
+    /// can be larger than the minimal one.
+    pub fn bounding(points: impl IntoIterator<Item = Point<T>>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        let two = T::one() + T::one();
+        let c = points.fold(Self::new(first, T::zero()), |c, p| {
+            let d = c.center.distance(p);
+            if d <= c.radius {
+                return c;
+            }
+            let radius = (c.radius + d) / two;
+            Self::new(
+                c.center + (p - c.center) * ((radius - c.radius) / d),
+                radius,
+            )
+        });
+        Some(c)
+    }
+
+    /// Returns `true` if every component differs from the one of `other` by at most `epsilon`.
+    #[inline]
+    pub fn approx_eq(&self, other: &Self, epsilon: T) -> bool {
+        self.center.approx_eq(&other.center, epsilon)
+            && (self.radius - other.radius).abs() <= epsilon
+    }
+
+    #[inline]
+    pub fn floor(self) -> Self {
+        Self::from_center_radius(self.center.floor(), self.radius.floor())
+    }
+
+    #[inline]
+    pub fn ceil(self) -> Self {
+        Self::from_center_radius(self.center.ceil(), self.radius.ceil())
+    }
+
+    #[inline]
+    pub fn round(self) -> Self {
+        Self::from_center_radius(self.center.round(), self.radius.round())
+    }
+
+    /// Returns the point on the circumference at `radians` measured from the +x axis toward +y.
+    #[inline]
+    pub fn point_at(self, radians: T) -> Point<T> {
+        let (sin, cos) = radians.sin_cos();
+        point(
+            self.center.x + self.radius * cos,
+            self.center.y + self.radius * sin,
+        )
+    }
+
+    /// Returns the nearest point to `p` on the circumference, or `p` itself if `p` is inside or
+    /// on the circle, which includes the center.
+    #[inline]
+    pub fn closest_point(&self, p: impl Into<Point<T>>) -> Point<T> {
+        let p = p.into();
+        let d = p - self.center;
+        let len = d.x.hypot(d.y);
+        if len <= self.radius {
+            p
+        } else {
+            self.center + d * (self.radius / len)
+        }
+    }
+}
+
+impl<T: FloatScalar + num::traits::FloatConst> Circle<T> {
+    #[inline]
+    pub fn area(self) -> T {
+        T::PI() * self.radius * self.radius
+    }
+
+    #[inline]
+    pub fn circumference(self) -> T {
+        T::TAU() * self.radius
+    }
+}
+
+impl<T: Scalar> Circle<T> {
+    /// Returns the smallest rect containing the circle.
+    #[inline]
+    pub fn bounding_rect(&self) -> Rect<T> {
+        let r = self.radius;
+        Rect::from_origin_size(self.center - vector(r, r), size(r + r, r + r))
+    }
+
+    /// Scales the radius by `s` and moves the center away from `anchor` by `s`, keeping `anchor`
+    /// fixed.
+    #[inline]
+    pub fn scale_from(&self, anchor: impl Into<Point<T>>, s: T) -> Self {
+        let anchor = anchor.into();
+        let center = point(
+            scale_about(self.center.x, anchor.x, s),
+            scale_about(self.center.y, anchor.y, s),
+        );
+        Self::new(center, self.radius * s)
+    }
+}
+
+impl<T: Widen> Circle<T> {
+    /// Converts each component to `T::Wide`, e.g. to compute products without overflow.
+    #[inline]
+    pub fn widen(self) -> Circle<T::Wide> {
+        Circle::from_center_radius(self.center.widen(), self.radius.widen())
+    }
 }
 
 impl<T: ToPrimitive> Circle<T> {
@@ -23,11 +171,61 @@ impl<T: ToPrimitive> Circle<T> {
     pub fn cast<U: NumCast>(self) -> Option<Circle<U>> {
         Some(Circle::new(self.center.cast::<U>()?, U::from(self.radius)?))
     }
+
+    #[inline]
+    pub fn try_cast<U: NumCast>(self) -> Result<Circle<U>, CastError>
+    where
+        T: Copy,
+    {
+        Ok(Circle::new(
+            (
+                cast_component(self.center.x, "Circle", "center.x")?,
+                cast_component(self.center.y, "Circle", "center.y")?,
+            ),
+            cast_component(self.radius, "Circle", "radius")?,
+        ))
+    }
+
+    /// Converts each component to `f32`. See [the conversions of `Point`](Point#conversions).
+    #[inline]
+    pub fn as_f32(self) -> Circle<f32> {
+        Circle::new(
+            self.center.as_f32(),
+            self.radius.to_f32().unwrap_or(f32::NAN),
+        )
+    }
+
+    /// Converts each component to `f64`. See [the conversions of `Point`](Point#conversions).
+    #[inline]
+    pub fn as_f64(self) -> Circle<f64> {
+        Circle::new(
+            self.center.as_f64(),
+            self.radius.to_f64().unwrap_or(f64::NAN),
+        )
+    }
+
+    /// Converts each component to `i32`. See [the conversions of `Point`](Point#conversions).
+    #[inline]
+    pub fn as_i32(self) -> Circle<i32> {
+        Circle::new(
+            self.center.as_i32(),
+            self.radius.to_f64().map_or(0, |v| v as i32),
+        )
+    }
+
+    /// Converts each component to `u32`. See [the conversions of `Point`](Point#conversions).
+    #[inline]
+    pub fn as_u32(self) -> Circle<u32> {
+        Circle::new(
+            self.center.as_u32(),
+            self.radius.to_f64().map_or(0, |v| v as u32),
+        )
+    }
 }
 
 impl<T> Circle<T>
 where
-    T: std::ops::Add<T, Output = T> + Copy,
+    T: core::ops::Add<T, Output = T> + Copy,
 {
     #[inline]
     pub fn translate(&self, v: impl Into<Vector<T>>) -> Self {
@@ -38,7 +236,7 @@ where
 
 impl<T> Circle<T>
 where
-    T: std::ops::Mul<T, Output = T> + Copy,
+    T: core::ops::Mul<T, Output = T> + Copy,
 {
     #[inline]
     pub fn scale(&self, s: T) -> Self {
@@ -46,6 +244,83 @@ where
     }
 }
 
+impl<T, U> core::ops::Add<U> for Circle<T>
+where
+    T: core::ops::Add<T, Output = T> + Copy,
+    U: Into<Vector<T>>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: U) -> Self {
+        Self {
+            center: self.center + rhs.into(),
+            ..self
+        }
+    }
+}
+
+impl<T, U> core::ops::Sub<U> for Circle<T>
+where
+    T: core::ops::Sub<T, Output = T> + Copy,
+    U: Into<Vector<T>>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: U) -> Self {
+        Self {
+            center: self.center - rhs.into(),
+            ..self
+        }
+    }
+}
+
+impl<T, U> core::ops::AddAssign<U> for Circle<T>
+where
+    T: core::ops::Add<T, Output = T> + Copy,
+    U: Into<Vector<T>>,
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: U) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T, U> core::ops::SubAssign<U> for Circle<T>
+where
+    T: core::ops::Sub<T, Output = T> + Copy,
+    U: Into<Vector<T>>,
+{
+    #[inline]
+    fn sub_assign(&mut self, rhs: U) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T> core::ops::Mul<T> for Circle<T>
+where
+    T: core::ops::Mul<T, Output = T> + Copy,
+{
+    type Output = Self;
+
+    /// Same as `Circle::scale`.
+    #[inline]
+    fn mul(self, rhs: T) -> Self {
+        self.scale(rhs)
+    }
+}
+
+impl<T> core::ops::MulAssign<T> for Circle<T>
+where
+    T: core::ops::Mul<T, Output = T> + Copy,
+{
+    #[inline]
+    fn mul_assign(&mut self, rhs: T) {
+        *self = self.scale(rhs);
+    }
+}
+
 #[inline]
 pub fn circle<T>(center: impl Into<Point<T>>, radius: T) -> Circle<T> {
     Circle::new(center, radius)
@@ -55,6 +330,142 @@ pub fn circle<T>(center: impl Into<Point<T>>, radius: T) -> Circle<T> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn as_test() {
+        assert!(circle((1, 2), 3).as_f64() == circle((1.0, 2.0), 3.0));
+        assert!(circle((-1.5f32, 2.5), 3.9).as_i32() == circle((-1, 2), 3));
+        assert!(circle((-1.5f32, 2.5), f32::NAN).as_u32() == circle((0, 2), 0));
+    }
+
+    #[test]
+    fn try_cast_test() {
+        assert!(circle((1, 2), 3).try_cast::<u8>() == Ok(circle((1, 2), 3)));
+        let e = circle((-1, 0), 0).try_cast::<u8>().unwrap_err();
+        assert!(e.type_name() == "Circle" && e.component() == "center.x");
+        let e = circle((0, 999), 0).try_cast::<u8>().unwrap_err();
+        assert!(e.type_name() == "Circle" && e.component() == "center.y");
+        let e = circle((0, 0), -3).try_cast::<u8>().unwrap_err();
+        assert!(e.type_name() == "Circle" && e.component() == "radius");
+        assert!(circle((1, 2), 3).cast::<u8>().is_some());
+    }
+
+    #[test]
+    fn const_test() {
+        const C: Circle<f32> = Circle::from_center_radius(point(1.0, 2.0), 3.0);
+        assert!(C == circle((1.0, 2.0), 3.0));
+    }
+
+    #[test]
+    fn approx_eq_test() {
+        let a = circle((0.1f64 + 0.2, 1.0), 0.7 + 0.1);
+        let b = circle((0.3, 1.0), 0.8);
+        assert!(a != b);
+        assert!(a.approx_eq(&b, 1e-12));
+        assert!(!a.approx_eq(&circle((0.3, 1.0), 0.9), 1e-12));
+    }
+
+    #[test]
+    fn round_test() {
+        let a = circle((-1.5f32, 0.4), 2.6);
+        assert!(a.floor() == circle((-2.0, 0.0), 2.0));
+        assert!(a.ceil() == circle((-1.0, 1.0), 3.0));
+        assert!(a.round() == circle((-2.0, 0.0), 3.0));
+    }
+
+    #[test]
+    fn from_diameter_test() {
+        assert!(Circle::from_diameter((0.0f32, 0.0), (6.0, 8.0)) == circle((3.0, 4.0), 5.0));
+        assert!(Circle::from_diameter((1.0f32, 1.0), (1.0, 1.0)) == circle((1.0, 1.0), 0.0));
+    }
+
+    #[test]
+    fn from_three_points_test() {
+        let h = 3.0f64.sqrt();
+        let (a, b, c) = (point(0.0, 0.0), point(2.0, 0.0), point(1.0, h));
+        let circum = Circle::from_three_points(a, b, c).unwrap();
+        let centroid = Point::centroid([a, b, c]).unwrap();
+        assert!(circum.center.approx_eq(&centroid, 1e-12));
+        assert!((circum.radius - 2.0 / h).abs() < 1e-12);
+        let c = Circle::from_three_points((0.0f32, 0.0), (4.0, 0.0), (0.0, 3.0)).unwrap();
+        assert!(c == circle((2.0, 1.5), 2.5));
+    }
+
+    #[test]
+    fn from_three_points_collinear_test() {
+        let c = Circle::from_three_points((0.0f32, 0.0), (1.0, 1.0), (3.0, 3.0));
+        assert!(c.is_none());
+        let c = Circle::from_three_points((0.0f32, 0.0), (0.0, 0.0), (3.0, 3.0));
+        assert!(c.is_none());
+        let c = Circle::from_three_points((1.0f64, 3.0), (1.1, 3.3), (1.7, 5.1));
+        assert!(c.is_none());
+        let c = Circle::from_three_points((0.0f32, 0.0), (1.0, 0.0), (2.0, 0.1));
+        assert!(c.is_some());
+    }
+
+    #[test]
+    fn bounding_test() {
+        assert!(Circle::<f32>::bounding([]).is_none());
+        let c = Circle::bounding([point(1.0f32, 2.0)]).unwrap();
+        assert!(c == circle((1.0, 2.0), 0.0));
+        let c = Circle::bounding([point(-1.0f32, 0.0), point(1.0, 0.0)]).unwrap();
+        assert!(c == circle((0.0, 0.0), 1.0));
+        let points = [
+            point(0.0f32, 0.0),
+            point(10.0, 0.0),
+            point(10.0, 10.0),
+            point(0.0, 10.0),
+            point(5.0, 5.0),
+            point(3.0, 9.0),
+        ];
+        let c = Circle::bounding(points).unwrap();
+        assert!(points
+            .iter()
+            .all(|&p| c.center.distance(p) <= c.radius + 1e-4));
+    }
+
+    #[test]
+    fn area_circumference_test() {
+        let c = circle((1.0f64, 2.0), 2.0);
+        assert!(c.area() == core::f64::consts::PI * 4.0);
+        assert!(c.circumference() == core::f64::consts::PI * 4.0);
+        assert!(circle((0.0f32, 0.0), 0.0).area() == 0.0);
+    }
+
+    #[test]
+    fn point_at_test() {
+        let c = circle((10.0f32, 20.0), 5.0);
+        assert!(c.point_at(0.0) == point(15.0, 20.0));
+        let p = c.point_at(core::f32::consts::FRAC_PI_2);
+        assert!(p.approx_eq(&point(10.0, 25.0), 1e-5));
+        let p = c.point_at(core::f32::consts::PI);
+        assert!(p.approx_eq(&point(5.0, 20.0), 1e-5));
+    }
+
+    #[test]
+    fn closest_point_test() {
+        let c = circle((0.0f32, 0.0), 5.0);
+        assert!(c.closest_point((6.0, 8.0)) == point(3.0, 4.0));
+        assert!(c.closest_point((-20.0, 0.0)) == point(-5.0, 0.0));
+        assert!(c.closest_point((1.0, 2.0)) == point(1.0, 2.0));
+        assert!(c.closest_point((3.0, 4.0)) == point(3.0, 4.0));
+        assert!(c.closest_point(c.center) == c.center);
+    }
+
+    #[test]
+    fn bounding_rect_test() {
+        assert!(circle((5, 5), 2).bounding_rect() == rect((3, 3), (4, 4)));
+        let c = circle((0.5f32, -1.0), 1.5);
+        assert!(c.bounding_rect() == rect((-1.0, -2.5), (3.0, 3.0)));
+        assert!(c.bounding_rect().center() == c.center);
+    }
+
+    #[test]
+    fn scale_from_test() {
+        assert!(circle((10, 20), 3).scale_from((0, 0), 2) == circle((20, 40), 6));
+        assert!(circle((10u32, 20), 3).scale_from((12, 12), 2) == circle((8, 28), 6));
+        assert!(circle((10, 20), 3).scale_from((10, 20), 5) == circle((10, 20), 15));
+    }
+
     #[test]
     fn eq_test() {
         assert!(circle((10, 20), 3) == circle((10, 20), 3));
@@ -69,4 +480,17 @@ mod tests {
     fn scale_test() {
         assert!(circle((10, 20), 3).scale(2) == circle((10, 20), 6));
     }
+
+    #[test]
+    fn ops_test() {
+        let a = circle((10, 20), 3);
+        assert!(a + (1, 2) == a.translate((1, 2)));
+        assert!(a - vector(1, 2) == circle((9, 18), 3));
+        assert!(a * 2 == a.scale(2));
+        let mut b = a;
+        b += (1, 1);
+        b -= (2, 2);
+        b *= 3;
+        assert!(b == circle((9, 19), 9));
+    }
 }
@@ -1,5 +1,11 @@
 use crate::*;
+use core::cmp::Ordering;
 
+/// Collision tests between shapes.
+///
+/// The tests of circles reject shapes apart by more than the radii on an axis using only additions,
+/// and square the distances otherwise. The squares are computed in `Widen::Wide`, so they do not
+/// overflow for the integers up to 32 bits.
 pub trait Collision<T> {
     fn is_crossing(&self, rhs: &T) -> bool;
     fn contains(&self, v: &T) -> bool;
@@ -15,18 +21,64 @@ pub fn contains<T: Collision<U>, U>(outer: &T, inner: &U) -> bool {
     outer.contains(inner)
 }
 
-impl<T> Collision<Point<T>> for Circle<T>
-where
-    T: std::ops::Add<T, Output = T>
-        + std::ops::Sub<T, Output = T>
-        + std::ops::Mul<T, Output = T>
-        + PartialOrd
-        + Copy,
-{
+/// Borrowed shapes collide like the shapes, e.g. for the items of `iter()` in closures.
+///
+/// Only the left side is blanket implemented, since an impl for a borrowed right side would
+/// overlap with this one. Dereference the right side instead.
+impl<T: Collision<U> + ?Sized, U> Collision<U> for &T {
+    #[inline]
+    fn is_crossing(&self, rhs: &U) -> bool {
+        (**self).is_crossing(rhs)
+    }
+
+    #[inline]
+    fn contains(&self, v: &U) -> bool {
+        (**self).contains(v)
+    }
+}
+
+/// Implements `Collision<B> for A` from `Collision<A> for B`, as `is_crossing` is symmetric.
+///
+/// `contains` is not symmetric, so each impl gives it as a closure of `self` and `v`.
+macro_rules! mirror_collision {
+    ($(impl[$($bound:tt)+] Collision<$rhs:ident> for $lhs:ident => |$this:tt, $v:tt| $contains:expr;)*) => {
+        $(
+            impl<T> Collision<$rhs<T>> for $lhs<T>
+            where
+                T: $($bound)+,
+            {
+                #[inline]
+                fn is_crossing(&self, rhs: &$rhs<T>) -> bool {
+                    rhs.is_crossing(self)
+                }
+
+                #[inline]
+                fn contains(&self, v: &$rhs<T>) -> bool {
+                    let contains = |$this: &Self, $v: &$rhs<T>| $contains;
+                    contains(self, v)
+                }
+            }
+        )*
+    };
+}
+
+/// Returns `true` if `a` and `b` are farther apart than `r` on either axis.
+///
+/// `r` is added to the smaller coordinate, which overflows only near the maximum of `T`.
+#[inline]
+fn beyond<T: Scalar>(a: Point<T>, b: Point<T>, r: T) -> bool {
+    let apart = |a: T, b: T| if a > b { a > b + r } else { b > a + r };
+    apart(a.x, b.x) || apart(a.y, b.y)
+}
+
+impl<T: Widen> Collision<Point<T>> for Circle<T> {
     #[inline]
     fn is_crossing(&self, rhs: &Point<T>) -> bool {
-        let d = self.center - *rhs;
-        d.x * d.x + d.y * d.y <= self.radius * self.radius
+        let (c, p, r) = (self.center.widen(), rhs.widen(), self.radius.widen());
+        if beyond(c, p, r) {
+            return false;
+        }
+        c.distance_squared(p) <= r * r
     }
 
     #[inline]
@@ -35,179 +87,743 @@ where
     }
 }
 
-impl<T> Collision<Circle<T>> for Point<T>
+impl<T: Widen> Collision<Circle<T>> for Circle<T> {
+    #[inline]
+    fn is_crossing(&self, rhs: &Circle<T>) -> bool {
+        let (a, b) = (self.widen(), rhs.widen());
+        let r = a.radius + b.radius;
+        if beyond(a.center, b.center, r) {
+            return false;
+        }
+        a.center.distance_squared(b.center) <= r * r
+    }
+
+    #[inline]
+    fn contains(&self, v: &Circle<T>) -> bool {
+        if v.radius > self.radius {
+            return false;
+        }
+        let (a, b) = (self.widen(), v.widen());
+        let r = a.radius - b.radius;
+        if beyond(a.center, b.center, r) {
+            return false;
+        }
+        a.center.distance_squared(b.center) <= r * r
+    }
+}
+
+impl<T> Collision<Rect<T>> for Point<T>
 where
-    T: std::ops::Add<T, Output = T>
-        + std::ops::Sub<T, Output = T>
-        + std::ops::Mul<T, Output = T>
-        + PartialOrd
-        + Copy,
+    T: core::ops::Add<T, Output = T> + PartialOrd + Copy,
 {
     #[inline]
-    fn is_crossing(&self, rhs: &Circle<T>) -> bool {
-        rhs.is_crossing(self)
+    fn is_crossing(&self, rhs: &Rect<T>) -> bool {
+        let (lo, hi) = rhs.bounds();
+        self.x >= lo.x && self.x <= hi.x && self.y >= lo.y && self.y <= hi.y
     }
 
     #[inline]
-    fn contains(&self, _: &Circle<T>) -> bool {
+    fn contains(&self, _: &Rect<T>) -> bool {
         false
     }
 }
 
-impl<T> Collision<Circle<T>> for Circle<T>
+impl<T> Collision<Rect<T>> for Rect<T>
 where
-    T: std::ops::Add<T, Output = T>
-        + std::ops::Sub<T, Output = T>
-        + std::ops::Mul<T, Output = T>
-        + PartialOrd
-        + Copy,
+    T: core::ops::Add<T, Output = T> + PartialOrd + Copy,
 {
+    #[inline]
+    fn is_crossing(&self, rhs: &Rect<T>) -> bool {
+        let (lhs_lo, lhs_hi) = self.bounds();
+        let (rhs_lo, rhs_hi) = rhs.bounds();
+        lhs_lo.x <= rhs_hi.x && lhs_lo.y <= rhs_hi.y && lhs_hi.x >= rhs_lo.x && lhs_hi.y >= rhs_lo.y
+    }
+
+    #[inline]
+    fn contains(&self, v: &Rect<T>) -> bool {
+        let (self_lo, self_hi) = self.bounds();
+        let (v_lo, v_hi) = v.bounds();
+        self_lo.x <= v_lo.x && self_lo.y <= v_lo.y && self_hi.x >= v_hi.x && self_hi.y >= v_hi.y
+    }
+}
+
+impl<T: Widen> Collision<Circle<T>> for Rect<T> {
     #[inline]
     fn is_crossing(&self, rhs: &Circle<T>) -> bool {
-        let d = self.center - rhs.center;
-        let r = self.radius + rhs.radius;
-        d.x * d.x + d.y * d.y <= r * r
+        let nearest = self.clamp_point(rhs.center).widen();
+        let (c, r) = (rhs.center.widen(), rhs.radius.widen());
+        if beyond(nearest, c, r) {
+            return false;
+        }
+        nearest.distance_squared(c) <= r * r
     }
 
     #[inline]
     fn contains(&self, v: &Circle<T>) -> bool {
-        let d = self.center - v.center;
-        let r = self.radius - v.radius;
-        d.x * d.x + d.y * d.y <= r * r
+        let (lo, hi) = self.bounds();
+        let (c, r) = (v.center, v.radius);
+        c.x >= lo.x + r && c.x + r <= hi.x && c.y >= lo.y + r && c.y + r <= hi.y
     }
 }
 
-impl<T> Collision<Rect<T>> for Point<T>
-where
-    T: std::ops::Add<T, Output = T> + PartialOrd + Copy,
-{
+impl<T: Scalar + Zero> Collision<Point<T>> for Segment<T> {
+    #[inline]
+    fn is_crossing(&self, rhs: &Point<T>) -> bool {
+        self.contains_point(*rhs)
+    }
+
+    #[inline]
+    fn contains(&self, v: &Point<T>) -> bool {
+        self.contains_point(*v)
+    }
+}
+
+impl<T: Scalar + Zero> Collision<Segment<T>> for Segment<T> {
+    #[inline]
+    fn is_crossing(&self, rhs: &Segment<T>) -> bool {
+        self.crosses(rhs)
+    }
+
+    #[inline]
+    fn contains(&self, v: &Segment<T>) -> bool {
+        self.contains_point(v.start) && self.contains_point(v.end)
+    }
+}
+
+impl<T: Scalar + Zero> Collision<Segment<T>> for Rect<T> {
+    #[inline]
+    fn is_crossing(&self, rhs: &Segment<T>) -> bool {
+        if self.contains(&rhs.start) || self.contains(&rhs.end) {
+            return true;
+        }
+        let c = self.normalized().corners();
+        (0..4).any(|i| rhs.crosses(&Segment::from_start_end(c[i], c[(i + 1) % 4])))
+    }
+
+    #[inline]
+    fn contains(&self, v: &Segment<T>) -> bool {
+        self.contains(&v.start) && self.contains(&v.end)
+    }
+}
+
+impl<T: Widen + Zero> Collision<Segment<T>> for Circle<T> {
+    #[inline]
+    fn is_crossing(&self, rhs: &Segment<T>) -> bool {
+        rhs.cmp_distance(self.center, self.radius) != Ordering::Greater
+    }
+
+    #[inline]
+    fn contains(&self, v: &Segment<T>) -> bool {
+        self.contains(&v.start) && self.contains(&v.end)
+    }
+}
+
+impl<T: Scalar + Zero> Collision<Point<T>> for Triangle<T> {
+    #[inline]
+    fn is_crossing(&self, rhs: &Point<T>) -> bool {
+        self.contains_point(*rhs)
+    }
+
+    #[inline]
+    fn contains(&self, v: &Point<T>) -> bool {
+        self.contains_point(*v)
+    }
+}
+
+impl<T: Scalar + Zero> Collision<Triangle<T>> for Triangle<T> {
+    /// Uses SAT unless either triangle is degenerate, in which case the edges are tested.
+    #[inline]
+    fn is_crossing(&self, rhs: &Triangle<T>) -> bool {
+        if !self.is_degenerate() && !rhs.is_degenerate() {
+            return sat_overlaps(&self.vertices(), &rhs.vertices());
+        }
+        self.edges()
+            .iter()
+            .any(|e| rhs.edges().iter().any(|f| e.crosses(f)))
+            || self.contains_point(rhs.a)
+            || rhs.contains_point(self.a)
+    }
+
+    #[inline]
+    fn contains(&self, v: &Triangle<T>) -> bool {
+        v.vertices().iter().all(|&p| self.contains_point(p))
+    }
+}
+
+impl<T: Scalar + Zero> Collision<Rect<T>> for Triangle<T> {
+    /// Uses SAT unless the triangle is degenerate, in which case its edges are tested.
     #[inline]
     fn is_crossing(&self, rhs: &Rect<T>) -> bool {
-        let ep = rhs.endpoint();
-        self.x >= rhs.origin.x && self.x <= ep.x && self.y >= rhs.origin.y && self.y <= ep.y
+        if self.is_degenerate() {
+            return self.edges().iter().any(|e| rhs.is_crossing(e));
+        }
+        sat_overlaps(&self.vertices(), &rhs.normalized().corners())
     }
 
     #[inline]
-    fn contains(&self, _: &Rect<T>) -> bool {
-        false
+    fn contains(&self, v: &Rect<T>) -> bool {
+        v.corners().iter().all(|&p| self.contains_point(p))
+    }
+}
+
+impl<T: Widen + Zero> Collision<Circle<T>> for Triangle<T> {
+    #[inline]
+    fn is_crossing(&self, rhs: &Circle<T>) -> bool {
+        self.contains_point(rhs.center)
+            || self
+                .edges()
+                .iter()
+                .any(|e| e.cmp_distance(rhs.center, rhs.radius) != Ordering::Greater)
+    }
+
+    #[inline]
+    fn contains(&self, v: &Circle<T>) -> bool {
+        if self.is_degenerate() {
+            return v.radius == T::zero() && self.contains_point(v.center);
+        }
+        self.contains_point(v.center)
+            && self
+                .edges()
+                .iter()
+                .all(|e| e.cmp_distance(v.center, v.radius) != Ordering::Less)
     }
 }
 
-impl<T> Collision<Point<T>> for Rect<T>
+impl<T> Collision<Point<T>> for RoundedRect<T>
 where
-    T: std::ops::Add<T, Output = T> + PartialOrd + Copy,
+    T: Widen + core::ops::Div<T, Output = T> + One + Zero,
 {
     #[inline]
     fn is_crossing(&self, rhs: &Point<T>) -> bool {
-        rhs.is_crossing(self)
+        self.contains_point(*rhs)
     }
 
     #[inline]
     fn contains(&self, v: &Point<T>) -> bool {
-        self.is_crossing(v)
+        self.contains_point(*v)
     }
 }
 
-impl<T> Collision<Rect<T>> for Rect<T>
+impl<T> Collision<Circle<T>> for RoundedRect<T>
+where
+    T: Widen + core::ops::Div<T, Output = T> + One + Zero,
+{
+    #[inline]
+    fn is_crossing(&self, rhs: &Circle<T>) -> bool {
+        let (core, r) = self.core();
+        core.is_crossing(&Circle::new(rhs.center, rhs.radius + r))
+    }
+
+    /// A circle larger than the corners only has to be in the rect, since it cannot reach into a
+    /// corner.
+    #[inline]
+    fn contains(&self, v: &Circle<T>) -> bool {
+        let (core, r) = self.core();
+        if v.radius > r {
+            return self.rect.contains(v);
+        }
+        Circle::new(core.clamp_point(v.center), r).contains(v)
+    }
+}
+
+impl<T> Collision<Rect<T>> for RoundedRect<T>
 where
-    T: std::ops::Add<T, Output = T> + PartialOrd + Copy,
+    T: Widen + core::ops::Div<T, Output = T> + One + Zero,
 {
     #[inline]
     fn is_crossing(&self, rhs: &Rect<T>) -> bool {
-        let lhs_ep = self.endpoint();
-        let rhs_ep = rhs.endpoint();
-        self.origin.x <= rhs_ep.x
-            && self.origin.y <= rhs_ep.y
-            && lhs_ep.x >= rhs.origin.x
-            && lhs_ep.y >= rhs.origin.y
+        let (core, r) = self.core();
+        // the nearest points of `rhs` and `core` on each axis
+        let a = rhs.clamp_point(core.origin);
+        let b = core.clamp_point(a);
+        Circle::new(b, r).contains(&a)
     }
 
     #[inline]
     fn contains(&self, v: &Rect<T>) -> bool {
-        let self_ep = self.endpoint();
-        let v_ep = v.endpoint();
-        self.origin.x <= v.origin.x
-            && self.origin.y <= v.origin.y
-            && self_ep.x >= v_ep.x
-            && self_ep.y >= v_ep.y
+        v.corners().iter().all(|&p| self.contains_point(p))
+    }
+}
+
+impl<T: PartialEq> Collision<Point<T>> for Point<T> {
+    #[inline]
+    fn is_crossing(&self, rhs: &Point<T>) -> bool {
+        self == rhs
+    }
+
+    #[inline]
+    fn contains(&self, v: &Point<T>) -> bool {
+        self == v
+    }
+}
+
+impl<T: Scalar + Zero> Collision<Segment<T>> for Triangle<T> {
+    #[inline]
+    fn is_crossing(&self, rhs: &Segment<T>) -> bool {
+        self.contains_point(rhs.start)
+            || self.contains_point(rhs.end)
+            || self.edges().iter().any(|e| e.crosses(rhs))
     }
+
+    #[inline]
+    fn contains(&self, v: &Segment<T>) -> bool {
+        self.contains_point(v.start) && self.contains_point(v.end)
+    }
+}
+
+/// Returns `true` if `segment` is within `r` of the normalized `rect`.
+#[inline]
+fn segment_near_rect<T: Widen + Zero>(rect: &Rect<T>, segment: &Segment<T>, r: T) -> bool {
+    if rect.is_crossing(segment) {
+        return true;
+    }
+    // apart shapes are nearest at an endpoint of the segment or at a corner of the rect
+    let near = |p: Point<T>| Circle::new(rect.clamp_point(p), r).contains(&p);
+    near(segment.start)
+        || near(segment.end)
+        || rect
+            .corners()
+            .iter()
+            .any(|&c| segment.cmp_distance(c, r) != Ordering::Greater)
 }
 
-impl<T> Collision<Circle<T>> for Rect<T>
+impl<T> Collision<Segment<T>> for RoundedRect<T>
 where
-    T: std::ops::Add<T, Output = T>
-        + std::ops::Sub<T, Output = T>
-        + std::ops::Mul<T, Output = T>
-        + PartialOrd
-        + Copy,
+    T: Widen + core::ops::Div<T, Output = T> + One + Zero,
 {
     #[inline]
-    fn is_crossing(&self, rhs: &Circle<T>) -> bool {
-        let r = vector(rhs.radius, rhs.radius);
-        let center = rhs.center;
-        let origin = self.origin - r;
-        let ep = self.endpoint() + r;
-        if origin.x > center.x || origin.y > center.y || ep.x < center.x || ep.y < center.y {
-            return false;
-        }
-        let origin = self.origin;
-        let ep = self.endpoint();
-        let rr = rhs.radius * rhs.radius;
-        let dx = origin.x - center.x;
-        let dy = origin.y - center.y;
-        if origin.x > center.x && origin.y > center.y && dx * dx + dy * dy >= rr {
-            return false;
-        }
-        let dx = ep.x - center.x;
-        if ep.x < center.x && origin.y > center.y && dx * dx + dy * dy >= rr {
-            return false;
-        }
-        let dx = origin.x - center.x;
-        let dy = ep.y - center.y;
-        if origin.x > center.x && ep.y < center.y && dx * dx + dy * dy >= rr {
-            return false;
-        }
-        let dx = ep.x - center.x;
-        if ep.x < center.x && ep.y < center.y && dx * dx + dy * dy >= rr {
-            return false;
-        }
-        true
+    fn is_crossing(&self, rhs: &Segment<T>) -> bool {
+        let (core, r) = self.core();
+        segment_near_rect(&core, rhs, r)
     }
 
     #[inline]
-    fn contains(&self, v: &Circle<T>) -> bool {
-        let ep = self.endpoint();
-        let left = v.center.x - v.radius;
-        let right = v.center.x + v.radius;
-        let top = v.center.y - v.radius;
-        let bottom = v.center.y + v.radius;
-        left >= self.origin.x && right <= ep.x && top >= self.origin.y && bottom <= ep.y
+    fn contains(&self, v: &Segment<T>) -> bool {
+        self.contains_point(v.start) && self.contains_point(v.end)
     }
 }
 
-impl<T> Collision<Rect<T>> for Circle<T>
+impl<T> Collision<Triangle<T>> for RoundedRect<T>
 where
-    T: std::ops::Add<T, Output = T>
-        + std::ops::Sub<T, Output = T>
-        + std::ops::Mul<T, Output = T>
-        + PartialOrd
-        + Copy,
+    T: Widen + core::ops::Div<T, Output = T> + One + Zero,
 {
+    #[inline]
+    fn is_crossing(&self, rhs: &Triangle<T>) -> bool {
+        let (core, r) = self.core();
+        rhs.is_crossing(&core) || rhs.edges().iter().any(|e| segment_near_rect(&core, e, r))
+    }
+
+    #[inline]
+    fn contains(&self, v: &Triangle<T>) -> bool {
+        v.vertices().iter().all(|&p| self.contains_point(p))
+    }
+}
+
+impl<T> Collision<RoundedRect<T>> for RoundedRect<T>
+where
+    T: Widen + core::ops::Div<T, Output = T> + One + Zero,
+{
+    #[inline]
+    fn is_crossing(&self, rhs: &RoundedRect<T>) -> bool {
+        let (a, ra) = self.core();
+        let (b, rb) = rhs.core();
+        let p = b.clamp_point(a.origin);
+        let q = a.clamp_point(p);
+        Circle::new(q, ra + rb).contains(&p)
+    }
+
+    #[inline]
+    fn contains(&self, v: &RoundedRect<T>) -> bool {
+        let (core, r) = v.core();
+        core.corners()
+            .iter()
+            .all(|&c| self.contains(&Circle::new(c, r)))
+    }
+}
+
+mirror_collision! {
+    impl[Widen] Collision<Circle> for Point => |_, _| false;
+    impl[core::ops::Add<T, Output = T> + PartialOrd + Copy] Collision<Point> for Rect
+        => |this, v| this.is_crossing(v);
+    impl[Widen] Collision<Rect> for Circle
+        => |this, v| v.corners().iter().all(|p| this.is_crossing(p));
+    // a degenerate segment behaves like a point
+    impl[Scalar + Zero] Collision<Segment> for Point
+        => |this, v| *this == v.start && v.start == v.end;
+    impl[Scalar + Zero] Collision<Rect> for Segment => |_, _| false;
+    impl[Widen + Zero] Collision<Circle> for Segment => |_, _| false;
+    impl[Scalar + Zero] Collision<Triangle> for Point => |_, _| false;
+    impl[Scalar + Zero] Collision<Triangle> for Rect
+        => |this, v| v.vertices().iter().all(|p| this.contains(p));
+    impl[Widen + Zero] Collision<Triangle> for Circle
+        => |this, v| v.vertices().iter().all(|p| this.contains(p));
+    // only a degenerate triangle can be on a segment
+    impl[Scalar + Zero] Collision<Triangle> for Segment
+        => |this, v| v.vertices().iter().all(|&p| this.contains_point(p));
+    impl[Widen + core::ops::Div<T, Output = T> + One + Zero] Collision<RoundedRect> for Point
+        => |_, _| false;
+    impl[Widen + core::ops::Div<T, Output = T> + One + Zero] Collision<RoundedRect> for Circle
+        => |this, v| {
+            let (core, r) = v.core();
+            core.corners().iter().all(|&c| this.contains(&Circle::new(c, r)))
+        };
+    impl[Widen + core::ops::Div<T, Output = T> + One + Zero] Collision<RoundedRect> for Rect
+        => |this, v| this.contains(&v.rect);
+    impl[Widen + core::ops::Div<T, Output = T> + One + Zero] Collision<RoundedRect> for Segment
+        => |_, _| false;
+    impl[Widen + core::ops::Div<T, Output = T> + One + Zero] Collision<RoundedRect> for Triangle
+        => |this, v| {
+            let (core, r) = v.core();
+            core.corners().iter().all(|&c| this.contains(&Circle::new(c, r)))
+        };
+}
+
+/// Returns `true` if no axis perpendicular to an edge of the convex polygons `a` or `b` separates
+/// them. Both must have at least one vertex.
+pub(crate) fn sat_overlaps<T: Scalar + Zero>(a: &[Point<T>], b: &[Point<T>]) -> bool {
+    let project = |vertices: &[Point<T>], (nx, ny): (Signed<T>, Signed<T>)| {
+        let dot = |p: &Point<T>| {
+            nx.mul(Signed::from_value(p.x))
+                .add(ny.mul(Signed::from_value(p.y)))
+        };
+        let first = dot(&vertices[0]);
+        vertices[1..]
+            .iter()
+            .map(dot)
+            .fold((first, first), |(lo, hi), d| {
+                let lo = if d.cmp(lo) == Ordering::Less { d } else { lo };
+                let hi = if d.cmp(hi) == Ordering::Greater {
+                    d
+                } else {
+                    hi
+                };
+                (lo, hi)
+            })
+    };
+    let a_edges = a.iter().zip(a.iter().cycle().skip(1));
+    let b_edges = b.iter().zip(b.iter().cycle().skip(1));
+    a_edges.chain(b_edges).all(|(s, e)| {
+        let axis = (Signed::diff(e.y, s.y), Signed::diff(s.x, e.x));
+        let (a_lo, a_hi) = project(a, axis);
+        let (b_lo, b_hi) = project(b, axis);
+        a_hi.cmp(b_lo) != Ordering::Less && b_hi.cmp(a_lo) != Ordering::Less
+    })
+}
+
+/// Returns `true` if `p` is inside `polygon` and not on its boundary.
+#[cfg(feature = "alloc")]
+#[inline]
+fn strictly_inside<T: Scalar + Zero>(polygon: &Polygon<T>, p: Point<T>) -> bool {
+    polygon.contains_point(p) && !polygon.edges().any(|e| e.contains_point(p))
+}
+
+/// Convex pairs are tested by SAT. Otherwise the polygons cross if any edges cross, or if one
+/// polygon is inside the other.
+#[cfg(feature = "alloc")]
+fn polygons_crossing<T: Scalar + Zero>(a: &Polygon<T>, b: &Polygon<T>) -> bool {
+    let (Some(&pa), Some(&pb)) = (a.vertices().first(), b.vertices().first()) else {
+        return false;
+    };
+    if a.is_convex() && b.is_convex() {
+        return sat_overlaps(a.vertices(), b.vertices());
+    }
+    a.edges().any(|e| b.edges().any(|f| e.crosses(&f)))
+        || a.contains_point(pb)
+        || b.contains_point(pa)
+}
+
+/// All vertices of `inner` have to be in `outer`. A concave `outer` also has to have no vertex
+/// strictly inside `inner` and no edge properly crossing an edge of `inner`.
+#[cfg(feature = "alloc")]
+fn polygon_contains<T: Scalar + Zero>(outer: &Polygon<T>, inner: &Polygon<T>) -> bool {
+    if !inner.vertices().iter().all(|&p| outer.contains_point(p)) {
+        return false;
+    }
+    if outer.is_convex() {
+        return true;
+    }
+    let proper = |e: &Segment<T>, f: &Segment<T>| {
+        let o = [
+            e.orientation(f.start),
+            e.orientation(f.end),
+            f.orientation(e.start),
+            f.orientation(e.end),
+        ];
+        !o.contains(&Ordering::Equal) && o[0] != o[1] && o[2] != o[3]
+    };
+    !outer.vertices().iter().any(|&p| strictly_inside(inner, p))
+        && !outer.edges().any(|e| inner.edges().any(|f| proper(&e, &f)))
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Scalar + Zero> Collision<Polygon<T>> for Polygon<T> {
+    #[inline]
+    fn is_crossing(&self, rhs: &Polygon<T>) -> bool {
+        polygons_crossing(self, rhs)
+    }
+
+    #[inline]
+    fn contains(&self, v: &Polygon<T>) -> bool {
+        polygon_contains(self, v)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Scalar + Zero> Collision<Point<T>> for Polygon<T> {
+    #[inline]
+    fn is_crossing(&self, rhs: &Point<T>) -> bool {
+        self.contains_point(*rhs)
+    }
+
+    #[inline]
+    fn contains(&self, v: &Point<T>) -> bool {
+        self.contains_point(*v)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Scalar + Zero> Collision<Rect<T>> for Polygon<T> {
     #[inline]
     fn is_crossing(&self, rhs: &Rect<T>) -> bool {
-        rhs.is_crossing(self)
+        polygons_crossing(self, &Polygon::from_rect(rhs))
     }
 
     #[inline]
     fn contains(&self, v: &Rect<T>) -> bool {
-        self.is_crossing(&v.origin) && self.is_crossing(&v.endpoint())
+        polygon_contains(self, &Polygon::from_rect(v))
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Widen + Zero> Collision<Circle<T>> for Polygon<T> {
+    #[inline]
+    fn is_crossing(&self, rhs: &Circle<T>) -> bool {
+        self.contains_point(rhs.center)
+            || self
+                .edges()
+                .any(|e| e.cmp_distance(rhs.center, rhs.radius) != Ordering::Greater)
     }
+
+    #[inline]
+    fn contains(&self, v: &Circle<T>) -> bool {
+        self.contains_point(v.center)
+            && self
+                .edges()
+                .all(|e| e.cmp_distance(v.center, v.radius) != Ordering::Less)
+    }
+}
+
+#[cfg(feature = "alloc")]
+mirror_collision! {
+    impl[Scalar + Zero] Collision<Polygon> for Point => |_, _| false;
+    impl[Scalar + Zero] Collision<Polygon> for Rect
+        => |this, v| v.vertices().iter().all(|p| this.contains(p));
+    impl[Widen + Zero] Collision<Polygon> for Circle
+        => |this, v| v.vertices().iter().all(|p| this.contains(p));
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn circle_contains_rect_all_corners() {
+        let c = circle((0.0f32, 0.0), 1.0);
+        assert!(c.contains(&rect((-0.5, -0.5), (1.0, 1.0))));
+        // both ends of the diagonal are inside but the corner (-0.9, 0.9) is not
+        assert!(!c.contains(&rect((-0.9, 0.0), (0.9, 0.9))));
+        // containing the origin and the endpoint used to be enough
+        let c = circle((0, 0), 10);
+        assert!(c.contains(&rect((-6, -6), (12, 12))));
+        assert!(!c.contains(&rect((-9, 0), (9, 9))));
+        assert!(!c.contains(&rect((0, 9), (-9, -9))));
+        assert!(!contains(&c, &rect((0, 0), (9, 9))));
+    }
+
+    #[test]
+    fn unsigned_circle_circle() {
+        let a = circle((2u32, 2), 1);
+        let b = circle((3u32, 3), 5);
+        assert!(!a.contains(&b));
+        assert!(b.contains(&a));
+        assert!(is_crossing(&a, &b));
+        assert!(!is_crossing(&a, &circle((10, 0), 2)));
+        assert!(!contains(&circle((10u32, 0), 1), &circle((0, 0), 2)));
+    }
+
+    #[test]
+    fn unsigned_circle_near_origin() {
+        let c = circle((1u32, 1), 1);
+        assert!(is_crossing(&c, &point(0, 1)));
+        assert!(!is_crossing(&c, &point(5, 5)));
+        assert!(is_crossing(&rect((0u32, 0), (4, 4)), &c));
+        assert!(is_crossing(&rect((2u32, 0), (4, 4)), &c));
+        assert!(!is_crossing(&rect((3u32, 3), (4, 4)), &c));
+        assert!(!is_crossing(&rect((0u32, 5), (4, 4)), &c));
+        assert!(contains(&rect((0u32, 0), (4, 4)), &c));
+        assert!(!contains(&rect((1u32, 0), (4, 4)), &c));
+        assert!(!contains(&c, &rect((0u32, 0), (3, 3))));
+        assert!(contains(&circle((2u32, 2), 2), &rect((1u32, 1), (2, 2))));
+    }
+
+    #[test]
+    fn small_int_far_apart() {
+        let c = circle::<i16>((30000, 0), 10);
+        assert!(!is_crossing(&c, &point(0, 0)));
+        assert!(!is_crossing(&c, &point(-30000, 0)));
+        assert!(!is_crossing(&c, &circle((-30000, -30000), 100)));
+        assert!(!contains(&circle((-30000i16, 0), 20), &c));
+        assert!(!is_crossing(&rect((-30000i16, -30000), (10, 10)), &c));
+        assert!(is_crossing(&c, &point(29992, 6)));
+        assert!(is_crossing(
+            &circle::<i16>((0, 0), 100),
+            &circle((200, 0), 100)
+        ));
+        assert!(!is_crossing(
+            &circle::<i16>((0, 0), 100),
+            &circle((200, 1), 100)
+        ));
+        assert!(contains(
+            &circle::<i16>((0, 0), 300),
+            &circle((100, 0), 200)
+        ));
+        assert!(is_crossing(
+            &rect((-200i16, -200), (100, 100)),
+            &circle((0, 0), 200)
+        ));
+        let c = circle((10u8, 10), 10);
+        assert!(!is_crossing(&c, &point(250, 250)));
+        assert!(!is_crossing(&c, &circle((200, 10), 15)));
+        assert!(is_crossing(&c, &point(16, 18)));
+    }
+
+    #[test]
+    fn widen_no_overflow() {
+        let c = circle((200u8, 0), 50);
+        assert!(is_crossing(&c, &point(250, 0)));
+        assert!(is_crossing(&c, &point(230, 40)));
+        assert!(!is_crossing(&c, &point(0, 255)));
+        assert!(is_crossing(&c, &circle((0, 0), 150)));
+        assert!(!is_crossing(&c, &circle((0, 200), 150)));
+        assert!(is_crossing(&rect((240u8, 0), (15, 15)), &c));
+        let c = circle::<i16>((0, 0), 20000);
+        assert!(is_crossing(&c, &circle((30000, 0), 10000)));
+        assert!(!is_crossing(&c, &point(-32768, -32768)));
+        assert!(contains(&c, &rect((-10000, -10000), (20000, 20000))));
+    }
+
+    #[test]
+    fn segment_point() {
+        let s = segment((0, 0), (10, 5));
+        assert!(is_crossing(&s, &point(0, 0)));
+        assert!(is_crossing(&s, &point(10, 5)));
+        assert!(is_crossing(&s, &point(4, 2)));
+        assert!(!is_crossing(&s, &point(4, 3)));
+        assert!(!is_crossing(&s, &point(12, 6)));
+        assert!(is_crossing(&point(6, 3), &s));
+        assert!(contains(&s, &point(2, 1)));
+        assert!(!contains(&point(2, 1), &s));
+        let d = segment((3, 3), (3, 3));
+        assert!(is_crossing(&d, &point(3, 3)));
+        assert!(!is_crossing(&d, &point(3, 4)));
+        assert!(contains(&point(3, 3), &d));
+        assert!(!contains(&point(3, 4), &d));
+        assert!(!contains(&point(3, 3), &segment((3, 3), (4, 4))));
+    }
+
+    #[test]
+    fn segment_segment() {
+        let s = segment((0, 0), (10, 10));
+        assert!(is_crossing(&s, &segment((0, 10), (10, 0))));
+        assert!(!is_crossing(&s, &segment((0, 1), (9, 10))));
+        // touching endpoints and T-junctions
+        assert!(is_crossing(&s, &segment((10, 10), (20, 0))));
+        assert!(is_crossing(&s, &segment((5, 5), (10, 0))));
+        assert!(!is_crossing(&s, &segment((6, 5), (10, 0))));
+        // collinear
+        assert!(is_crossing(&s, &segment((5, 5), (15, 15))));
+        assert!(is_crossing(&s, &segment((15, 15), (-5, -5))));
+        assert!(is_crossing(&s, &segment((10, 10), (15, 15))));
+        assert!(!is_crossing(&s, &segment((11, 11), (15, 15))));
+        assert!(contains(&s, &segment((2, 2), (8, 8))));
+        assert!(!contains(&s, &segment((2, 2), (12, 12))));
+        // axis-parallel
+        let h = segment((0, 5), (10, 5));
+        assert!(is_crossing(&h, &segment((5, 0), (5, 10))));
+        assert!(is_crossing(&h, &segment((10, 0), (10, 5))));
+        assert!(!is_crossing(&h, &segment((11, 0), (11, 10))));
+        assert!(!is_crossing(&h, &segment((0, 6), (10, 6))));
+        // degenerate
+        assert!(is_crossing(&s, &segment((3, 3), (3, 3))));
+        assert!(!is_crossing(&s, &segment((3, 4), (3, 4))));
+        assert!(is_crossing(
+            &segment((3, 3), (3, 3)),
+            &segment((3, 3), (3, 3))
+        ));
+    }
+
+    #[test]
+    fn segment_segment_unsigned() {
+        let s = segment((10u32, 0), (0, 10));
+        assert!(is_crossing(&s, &segment((0, 0), (10, 10))));
+        assert!(!is_crossing(&s, &segment((0, 0), (4, 4))));
+        assert!(is_crossing(&s, &segment((0, 0), (5, 5))));
+    }
+
+    #[test]
+    fn segment_rect() {
+        let r = rect((10, 10), (10, 10));
+        assert!(is_crossing(&r, &segment((12, 12), (18, 18))));
+        assert!(contains(&r, &segment((12, 12), (18, 18))));
+        assert!(is_crossing(&r, &segment((0, 15), (30, 15))));
+        assert!(!contains(&r, &segment((0, 15), (30, 15))));
+        assert!(is_crossing(&r, &segment((0, 10), (30, 10))));
+        assert!(is_crossing(&r, &segment((0, 0), (10, 10))));
+        assert!(is_crossing(&r, &segment((20, 0), (20, 30))));
+        assert!(!is_crossing(&r, &segment((21, 0), (21, 30))));
+        assert!(!is_crossing(&r, &segment((0, 5), (30, 9))));
+        assert!(is_crossing(&segment((5, 25), (25, 5)), &r));
+        assert!(!contains(&segment((5, 25), (25, 5)), &r));
+        assert!(is_crossing(
+            &rect((20, 20), (-10, -10)),
+            &segment((0, 15), (30, 15))
+        ));
+    }
+
+    #[test]
+    fn segment_circle() {
+        let c = circle((0, 0), 5);
+        assert!(is_crossing(&c, &segment((-10, 5), (10, 5))));
+        assert!(!is_crossing(&c, &segment((-10, 6), (10, 6))));
+        assert!(is_crossing(&c, &segment((3, 4), (30, 40))));
+        assert!(!is_crossing(&c, &segment((4, 4), (30, 40))));
+        assert!(is_crossing(&c, &segment((-10, -10), (10, 10))));
+        assert!(!is_crossing(&c, &segment((6, 0), (20, 0))));
+        assert!(is_crossing(&segment((-1, 1), (1, 1)), &c));
+        assert!(contains(&c, &segment((-1, 1), (1, 1))));
+        assert!(!contains(&c, &segment((-1, 1), (10, 1))));
+        assert!(!contains(&segment((-1, 1), (1, 1)), &c));
+        assert!(is_crossing(&c, &segment((3, 4), (3, 4))));
+        let c = circle((10u32, 10), 3);
+        assert!(is_crossing(&c, &segment((0, 13), (20, 13))));
+        assert!(!is_crossing(&c, &segment((0, 14), (20, 14))));
+        assert!(is_crossing(&c, &segment((20, 0), (0, 20))));
+    }
+
+    #[test]
+    fn segment_distance_no_overflow() {
+        let s = segment((0, 0), (1000, 0));
+        assert!(!is_crossing(&circle((500, 100), 50), &s));
+        assert!(is_crossing(&circle((500, 100), 100), &s));
+        let t = triangle((0, 0), (1000, 0), (0, 1000));
+        assert!(!is_crossing(&t, &circle((1000, 1000), 707)));
+        assert!(is_crossing(&t, &circle((1000, 1000), 708)));
+        assert!(contains(&t, &circle((250, 250), 100)));
+        let r = rounded_rect(rect((0, 0), (1000, 1000)), 100);
+        assert!(is_crossing(&r, &segment((1941, 0), (0, 1941))));
+        assert!(!is_crossing(&r, &segment((1943, 0), (0, 1943))));
+    }
+
     #[test]
     fn circle_point_is_crossing() {
         let a = circle((10, 10), 5);
@@ -267,6 +883,29 @@ mod tests {
         assert!(is_crossing(&circle((20, 25), 5), &a));
     }
 
+    #[test]
+    fn rect_circle_corner_tangency() {
+        let a = rect((10, 10), (10, 10));
+        let cases = [
+            ((7, 6), (6, 6)),
+            ((23, 6), (24, 6)),
+            ((23, 24), (24, 24)),
+            ((7, 24), (6, 24)),
+        ];
+        for (touching, apart) in cases {
+            assert!(is_crossing(&a, &circle(touching, 5)));
+            assert!(is_crossing(&circle(touching, 5), &a));
+            assert!(!is_crossing(&a, &circle(apart, 5)));
+        }
+        // edge tangency is inclusive as well
+        assert!(is_crossing(&a, &circle((15, 5), 5)));
+        assert!(is_crossing(&a, &circle((25, 15), 5)));
+        assert!(!is_crossing(&a, &circle((26, 15), 5)));
+        let b = rect((0.0f32, 0.0), (1.0, 1.0));
+        assert!(is_crossing(&b, &circle((1.6, 1.8), 1.0)));
+        assert!(!is_crossing(&b, &circle((1.6, 1.81), 1.0)));
+    }
+
     #[test]
     fn circle_contains_point() {
         let a = circle((10, 10), 5);
@@ -333,4 +972,319 @@ mod tests {
         assert!(!contains(&a, &rect((5, 5), (3, 3))));
         assert!(!contains(&a, &rect((8, 8), (7, 7))));
     }
+
+    #[cfg(feature = "alloc")]
+    fn triangle_polygon() -> Polygon<i32> {
+        Polygon::new(alloc::vec![point(0, 0), point(10, 0), point(0, 10)])
+    }
+
+    #[cfg(feature = "alloc")]
+    fn pentagon() -> Polygon<i32> {
+        Polygon::new(alloc::vec![
+            point(0, -10),
+            point(10, -3),
+            point(6, 8),
+            point(-6, 8),
+            point(-10, -3),
+        ])
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn polygon_polygon() {
+        let t = triangle_polygon();
+        let p = pentagon();
+        assert!(is_crossing(&t, &p) && is_crossing(&p, &t));
+        // touching at (10, 0) on the hypotenuse only
+        let t2 = Polygon::new(alloc::vec![point(5, 5), point(10, 5), point(10, 10)]);
+        assert!(is_crossing(&t, &t2));
+        let t3 = Polygon::new(alloc::vec![point(6, 6), point(10, 6), point(10, 10)]);
+        assert!(!is_crossing(&t, &t3));
+        let small = Polygon::new(alloc::vec![point(-1, -1), point(1, -1), point(0, 1)]);
+        assert!(contains(&p, &small) && !contains(&small, &p));
+        assert!(!contains(&p, &t));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn concave_polygon() {
+        let l = Polygon::new(alloc::vec![
+            point(0, 0),
+            point(10, 0),
+            point(10, 4),
+            point(4, 4),
+            point(4, 10),
+            point(0, 10),
+        ]);
+        let notch = Polygon::from_rect(&rect((6, 6), (2, 2)));
+        assert!(!is_crossing(&l, &notch) && !is_crossing(&notch, &l));
+        assert!(is_crossing(&l, &Polygon::from_rect(&rect((3, 3), (2, 2)))));
+        // every vertex is inside the L but the rect covers the notch
+        let across = Polygon::new(alloc::vec![
+            point(1, 1),
+            point(9, 1),
+            point(9, 3),
+            point(1, 9)
+        ]);
+        assert!(!contains(&l, &across));
+        assert!(contains(&l, &Polygon::from_rect(&rect((1, 1), (8, 2)))));
+        assert!(contains(&l, &rect((1, 1), (2, 8))));
+        assert!(!contains(&l, &rect((1, 1), (5, 5))));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn polygon_rect() {
+        let p = pentagon();
+        assert!(is_crossing(&p, &rect((7, 5), (5, 5))));
+        assert!(!is_crossing(&p, &rect((8, 5), (5, 5))));
+        assert!(is_crossing(&rect((-20, -20), (40, 40)), &p));
+        assert!(contains(&rect((-10, -10), (20, 18)), &p));
+        assert!(!contains(&rect((-10, -10), (20, 17)), &p));
+        assert!(contains(&p, &rect((-2, -2), (4, 4))));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn polygon_circle() {
+        let t = triangle_polygon();
+        assert!(is_crossing(&t, &circle((2, 2), 1)));
+        assert!(is_crossing(&t, &circle((-3, 5), 3)));
+        assert!(!is_crossing(&t, &circle((-4, 5), 3)));
+        assert!(!is_crossing(&circle((8, 8), 4), &t));
+        assert!(contains(&t, &circle((3, 3), 2)));
+        assert!(!contains(&t, &circle((3, 3), 3)));
+        assert!(contains(&circle((0, 0), 10), &t));
+        assert!(!contains(&circle((0, 0), 9), &t));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn polygon_point_on_edge() {
+        let t = triangle_polygon();
+        assert!(is_crossing(&t, &point(5, 5)));
+        assert!(is_crossing(&point(5, 0), &t));
+        assert!(contains(&t, &point(0, 7)));
+        assert!(!is_crossing(&t, &point(6, 5)));
+        assert!(!contains(&point(0, 0), &t));
+        let p = pentagon();
+        assert!(is_crossing(&p, &point(8, 2)) && !is_crossing(&p, &point(9, 2)));
+    }
+
+    #[test]
+    fn triangle_triangle() {
+        let ccw = triangle((0, 0), (10, 0), (0, 10));
+        let cw = triangle((0, 0), (0, 10), (10, 0));
+        let cases = [
+            (triangle((5, 5), (10, 5), (10, 10)), true),
+            (triangle((6, 6), (10, 6), (10, 10)), false),
+            (triangle((2, 2), (3, 2), (2, 3)), true),
+            (triangle((-5, -5), (20, -5), (-5, 20)), true),
+            (triangle((11, 0), (20, 0), (11, 10)), false),
+            // degenerate: a segment across the hypotenuse, and one beside it
+            (triangle((4, 4), (8, 8), (6, 6)), true),
+            (triangle((6, 6), (8, 8), (7, 7)), false),
+        ];
+        for (t, expected) in cases {
+            for s in [ccw, cw] {
+                assert!(is_crossing(&s, &t) == expected);
+                assert!(is_crossing(&t, &s) == expected);
+            }
+        }
+        assert!(contains(&ccw, &triangle((1, 1), (5, 1), (1, 5))));
+        assert!(contains(&cw, &triangle((0, 0), (5, 5), (0, 10))));
+        assert!(!contains(&cw, &triangle((1, 1), (6, 5), (1, 5))));
+    }
+
+    #[test]
+    fn triangle_rect() {
+        for t in [
+            triangle((0, 0), (10, 0), (0, 10)),
+            triangle((0, 0), (0, 10), (10, 0)),
+        ] {
+            assert!(is_crossing(&t, &rect((5, 5), (3, 3))));
+            assert!(!is_crossing(&t, &rect((6, 5), (3, 3))));
+            assert!(is_crossing(&rect((-5, -5), (20, 20)), &t));
+            assert!(is_crossing(&t, &rect((8, 2), (-3, -3))));
+            assert!(contains(&t, &rect((1, 1), (4, 4))));
+            assert!(!contains(&t, &rect((1, 1), (5, 5))));
+            assert!(contains(&rect((0, 0), (10, 10)), &t));
+            assert!(!contains(&rect((0, 0), (9, 10)), &t));
+        }
+        let line = triangle((0, 0), (10, 10), (5, 5));
+        assert!(is_crossing(&line, &rect((3, 4), (1, 1))));
+        assert!(!is_crossing(&line, &rect((2, 5), (1, 1))));
+    }
+
+    #[test]
+    fn triangle_circle() {
+        for t in [
+            triangle((0, 0), (10, 0), (0, 10)),
+            triangle((0, 0), (0, 10), (10, 0)),
+        ] {
+            assert!(is_crossing(&t, &circle((2, 2), 1)));
+            assert!(is_crossing(&t, &circle((-3, 5), 3)));
+            assert!(!is_crossing(&circle((-4, 5), 3), &t));
+            assert!(contains(&t, &circle((3, 3), 2)));
+            assert!(!contains(&t, &circle((3, 3), 3)));
+            assert!(contains(&circle((0, 0), 10), &t));
+            assert!(!contains(&circle((0, 0), 9), &t));
+        }
+        let line = triangle((0, 0), (10, 0), (5, 0));
+        assert!(is_crossing(&line, &circle((5, 3), 3)));
+        assert!(!contains(&line, &circle((5, 0), 1)));
+        assert!(contains(&line, &circle((5, 0), 0)));
+    }
+
+    #[test]
+    fn triangle_point_on_edge() {
+        let t = triangle((0u32, 0), (10, 0), (0, 10));
+        assert!(is_crossing(&t, &point(5, 5)));
+        assert!(is_crossing(&point(5, 0), &t));
+        assert!(contains(&t, &point(0, 10)));
+        assert!(!is_crossing(&t, &point(6, 5)));
+        assert!(!contains(&point(0, 0), &t));
+    }
+
+    #[test]
+    fn rounded_rect_circle() {
+        let a = rounded_rect(((0, 0), (100, 50)), 10);
+        assert!(is_crossing(&a, &circle((50, 60), 10)));
+        assert!(!is_crossing(&a, &circle((50, 61), 10)));
+        // 45 degrees off the top-left corner circle at (10, 10)
+        assert!(is_crossing(&circle((-4, -4), 10), &a));
+        assert!(!is_crossing(&a, &circle((-5, -5), 10)));
+        assert!(contains(&a, &circle((50, 25), 25)));
+        assert!(!contains(&a, &circle((50, 25), 26)));
+        assert!(contains(&a, &circle((7, 7), 5)));
+        assert!(!contains(&a, &circle((6, 6), 5)));
+        assert!(contains(&circle((50, 25), 53), &a));
+        assert!(!contains(&circle((50, 25), 52), &a));
+    }
+
+    #[test]
+    fn rounded_rect_rect() {
+        let a = rounded_rect(((0u32, 0), (100, 50)), 10);
+        assert!(is_crossing(&a, &rect((3, 3), (2, 2))));
+        assert!(!is_crossing(&a, &rect((0, 0), (2, 2))));
+        assert!(is_crossing(&rect((100, 20), (5, 5)), &a));
+        assert!(!is_crossing(&rect((101, 20), (5, 5)), &a));
+        assert!(is_crossing(&a, &rect((40, 10), (10, 10))));
+        assert!(is_crossing(&a, &rect((0, 0), (200, 200))));
+        assert!(contains(&a, &rect((3, 10), (90, 30))));
+        assert!(!contains(&a, &rect((2, 2), (90, 30))));
+        assert!(contains(&rect((0, 0), (100, 50)), &a));
+        assert!(!contains(&rect((1, 0), (100, 50)), &a));
+    }
+
+    #[test]
+    fn rounded_rect_point() {
+        let a = rounded_rect(((0, 0), (20, 20)), 5);
+        assert!(is_crossing(&a, &point(2, 2)));
+        assert!(!is_crossing(&point(1, 1), &a));
+        assert!(contains(&a, &point(0, 10)));
+        assert!(!contains(&point(10, 10), &a));
+    }
+
+    #[test]
+    fn point_point() {
+        assert!(is_crossing(&point(1, 2), &point(1, 2)));
+        assert!(contains(&point(1, 2), &point(1, 2)));
+        assert!(!is_crossing(&point(1, 2), &point(2, 1)));
+    }
+
+    #[test]
+    fn segment_triangle() {
+        let t = triangle((0, 0), (10, 0), (0, 10));
+        assert!(is_crossing(&t, &segment((2, 2), (3, 3))));
+        assert!(is_crossing(&segment((-5, 5), (15, 5)), &t));
+        assert!(is_crossing(&t, &segment((5, 5), (10, 10))));
+        assert!(!is_crossing(&t, &segment((6, 6), (10, 10))));
+        assert!(contains(&t, &segment((0, 0), (5, 5))));
+        assert!(!contains(&t, &segment((1, 1), (6, 6))));
+        assert!(!contains(&segment((0, 0), (10, 0)), &t));
+        assert!(contains(
+            &segment((0, 0), (10, 0)),
+            &triangle((1, 0), (5, 0), (3, 0))
+        ));
+    }
+
+    #[test]
+    fn rounded_rect_segment() {
+        let a = rounded_rect(((0, 0), (20, 20)), 5);
+        assert!(is_crossing(&a, &segment((-10, 10), (30, 10))));
+        assert!(is_crossing(&a, &segment((2, 2), (3, 3))));
+        assert!(is_crossing(&segment((0, 10), (0, 30)), &a));
+        assert!(!is_crossing(&a, &segment((-1, 10), (-1, 30))));
+        // the corner arc is at x + y = 2.93 on the diagonal
+        assert!(is_crossing(&a, &segment((4, -1), (-1, 4))));
+        assert!(!is_crossing(&a, &segment((3, -1), (-1, 3))));
+        assert!(contains(&a, &segment((0, 10), (20, 10))));
+        assert!(!contains(&a, &segment((0, 0), (20, 20))));
+        assert!(!contains(&segment((0, 0), (20, 20)), &a));
+    }
+
+    #[test]
+    fn rounded_rect_triangle() {
+        let a = rounded_rect(((0, 0), (20, 20)), 5);
+        assert!(is_crossing(&a, &triangle((8, -5), (-5, 8), (-5, -5))));
+        assert!(!is_crossing(&triangle((7, -5), (-5, 7), (-5, -5)), &a));
+        assert!(is_crossing(&a, &triangle((5, 5), (6, 5), (5, 6))));
+        assert!(is_crossing(&a, &triangle((-10, -10), (50, -10), (-10, 50))));
+        assert!(contains(&a, &triangle((10, 0), (20, 10), (2, 2))));
+        assert!(!contains(&a, &triangle((10, 0), (20, 10), (1, 1))));
+        assert!(contains(&triangle((0, 0), (60, 0), (0, 60)), &a));
+        assert!(!contains(&triangle((0, 0), (30, 0), (0, 30)), &a));
+    }
+
+    #[test]
+    fn rounded_rect_rounded_rect() {
+        let a = rounded_rect(((0, 0), (20, 20)), 5);
+        // the corner circles are 9.9 and 11.3 apart
+        let b = rounded_rect(((17, 17), (20, 20)), 5);
+        assert!(is_crossing(&a, &b) && is_crossing(&b, &a));
+        let c = rounded_rect(((18, 18), (20, 20)), 5);
+        assert!(!is_crossing(&a, &c));
+        assert!(is_crossing(&a, &rounded_rect(((20, 5), (5, 5)), 2)));
+        assert!(contains(&a, &rounded_rect(((0, 0), (20, 20)), 5)));
+        assert!(contains(&a, &rounded_rect(((1, 1), (18, 18)), 5)));
+        assert!(!contains(&a, &rounded_rect(((0, 0), (20, 20)), 4)));
+    }
+
+    #[test]
+    fn reference_test() {
+        let r = rect((0, 0), (10, 10));
+        let c = circle((12, 5), 3);
+        assert!(is_crossing(&&r, &c));
+        assert!(contains(&&&r, &point(5, 5)));
+        // `A` is inferred as a reference
+        fn by_value<A: Collision<B>, B>(a: A, b: &B) -> bool {
+            a.is_crossing(b)
+        }
+        let (r_ref, c_ref) = (&r, &c);
+        assert!(by_value(r_ref, &c) && by_value(c_ref, &r));
+        let rects = [r, rect((20, 20), (5, 5)), rect((11, 0), (2, 2))];
+        let hits = rects.iter().filter(|r| is_crossing(r, &c)).count();
+        assert!(hits == 2);
+        assert!(rects.iter().filter(|r| contains(r, &point(12, 1))).count() == 1);
+        let refs = [&r, &rects[1]];
+        assert!(refs.iter().filter(|r| is_crossing(*r, &c)).count() == 1);
+    }
+
+    #[test]
+    fn mirrored_test() {
+        let r = rect((0, 0), (10, 10));
+        let c = circle((12, 5), 3);
+        let s = segment((-5, 5), (15, 5));
+        let t = triangle((0, 0), (10, 0), (0, 10));
+        let rr = rounded_rect(((0, 0), (10, 10)), 3);
+        assert!(is_crossing(&c, &r) == is_crossing(&r, &c));
+        assert!(is_crossing(&s, &r) == is_crossing(&r, &s));
+        assert!(is_crossing(&s, &c) == is_crossing(&c, &s));
+        assert!(is_crossing(&point(1, 1), &t) == is_crossing(&t, &point(1, 1)));
+        assert!(is_crossing(&point(1, 1), &rr) == is_crossing(&rr, &point(1, 1)));
+        assert!(is_crossing(&s, &t) && is_crossing(&s, &rr) && is_crossing(&t, &rr));
+        assert!(contains(&r, &t) && contains(&r, &rr) && !contains(&s, &rr));
+        assert!(contains(&circle((5, 5), 8), &r) && !contains(&point(5, 5), &c));
+    }
 }
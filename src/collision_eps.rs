@@ -0,0 +1,398 @@
+use crate::*;
+
+/// `Collision` with a tolerance, for float shapes whose exact tests flicker when they are
+/// tangent.
+///
+/// `is_crossing_eps` is `true` if the shapes are at most `epsilon` apart, and `contains_eps` if
+/// `v` is inside `self` grown by `epsilon` in every direction. `epsilon` is a distance in the
+/// units of the coordinates, not a squared one, and should not be negative.
+///
+/// Growing a point gives a disk, so unlike `contains`, a point contains the shapes within
+/// `epsilon` of it. For a concave polygon `contains_eps` only tests the vertices and circles of
+/// `v`, so an edge of `v` passing over a notch of the polygon is not detected.
+pub trait CollisionEps<T>: Collision<T> {
+    type Epsilon;
+
+    fn is_crossing_eps(&self, rhs: &T, epsilon: Self::Epsilon) -> bool;
+    fn contains_eps(&self, v: &T, epsilon: Self::Epsilon) -> bool;
+}
+
+#[inline]
+pub fn is_crossing_eps<T: CollisionEps<U>, U>(lhs: &T, rhs: &U, epsilon: T::Epsilon) -> bool {
+    lhs.is_crossing_eps(rhs, epsilon)
+}
+
+#[inline]
+pub fn contains_eps<T: CollisionEps<U>, U>(outer: &T, inner: &U, epsilon: T::Epsilon) -> bool {
+    outer.contains_eps(inner, epsilon)
+}
+
+impl<T: CollisionEps<U> + ?Sized, U> CollisionEps<U> for &T {
+    type Epsilon = T::Epsilon;
+
+    #[inline]
+    fn is_crossing_eps(&self, rhs: &U, epsilon: T::Epsilon) -> bool {
+        (**self).is_crossing_eps(rhs, epsilon)
+    }
+
+    #[inline]
+    fn contains_eps(&self, v: &U, epsilon: T::Epsilon) -> bool {
+        (**self).contains_eps(v, epsilon)
+    }
+}
+
+/// A shape as a polygonal core grown by a radius.
+trait Outline<T> {
+    /// Returns `true` if `f` returns `true` for any edge of the core. A core of a single point
+    /// has one edge of zero length.
+    fn any_edge(&self, f: &mut dyn FnMut(Segment<T>) -> bool) -> bool;
+
+    fn radius(&self) -> T;
+
+    /// Returns `true` if the core has an area containing `p`.
+    fn core_contains_point(&self, _: Point<T>) -> bool {
+        false
+    }
+
+    /// Returns `true` if the core has an area containing `c`.
+    fn core_contains_circle(&self, _: &Circle<T>) -> bool {
+        false
+    }
+}
+
+impl<T: Float> Outline<T> for Point<T> {
+    #[inline]
+    fn any_edge(&self, f: &mut dyn FnMut(Segment<T>) -> bool) -> bool {
+        f(Segment::from_start_end(*self, *self))
+    }
+
+    #[inline]
+    fn radius(&self) -> T {
+        T::zero()
+    }
+}
+
+impl<T: Float> Outline<T> for Circle<T> {
+    #[inline]
+    fn any_edge(&self, f: &mut dyn FnMut(Segment<T>) -> bool) -> bool {
+        f(Segment::from_start_end(self.center, self.center))
+    }
+
+    #[inline]
+    fn radius(&self) -> T {
+        self.radius
+    }
+}
+
+impl<T: Float> Outline<T> for Segment<T> {
+    #[inline]
+    fn any_edge(&self, f: &mut dyn FnMut(Segment<T>) -> bool) -> bool {
+        f(*self)
+    }
+
+    #[inline]
+    fn radius(&self) -> T {
+        T::zero()
+    }
+}
+
+/// Returns `true` if `f` returns `true` for any edge of `rect`.
+#[inline]
+fn any_rect_edge<T: Float>(rect: &Rect<T>, f: &mut dyn FnMut(Segment<T>) -> bool) -> bool {
+    let c = rect.normalized().corners();
+    (0..4).any(|i| f(Segment::from_start_end(c[i], c[(i + 1) % 4])))
+}
+
+impl<T: Float + Widen> Outline<T> for Rect<T> {
+    #[inline]
+    fn any_edge(&self, f: &mut dyn FnMut(Segment<T>) -> bool) -> bool {
+        any_rect_edge(self, f)
+    }
+
+    #[inline]
+    fn radius(&self) -> T {
+        T::zero()
+    }
+
+    #[inline]
+    fn core_contains_point(&self, p: Point<T>) -> bool {
+        self.contains(&p)
+    }
+
+    #[inline]
+    fn core_contains_circle(&self, c: &Circle<T>) -> bool {
+        self.contains(c)
+    }
+}
+
+impl<T: Float + Widen> Outline<T> for RoundedRect<T> {
+    #[inline]
+    fn any_edge(&self, f: &mut dyn FnMut(Segment<T>) -> bool) -> bool {
+        any_rect_edge(&self.core().0, f)
+    }
+
+    #[inline]
+    fn radius(&self) -> T {
+        self.corner_radius()
+    }
+
+    #[inline]
+    fn core_contains_point(&self, p: Point<T>) -> bool {
+        self.core().0.contains(&p)
+    }
+
+    #[inline]
+    fn core_contains_circle(&self, c: &Circle<T>) -> bool {
+        self.core().0.contains(c)
+    }
+}
+
+impl<T: Float + Widen> Outline<T> for Triangle<T> {
+    #[inline]
+    fn any_edge(&self, f: &mut dyn FnMut(Segment<T>) -> bool) -> bool {
+        self.edges().into_iter().any(f)
+    }
+
+    #[inline]
+    fn radius(&self) -> T {
+        T::zero()
+    }
+
+    #[inline]
+    fn core_contains_point(&self, p: Point<T>) -> bool {
+        self.contains_point(p)
+    }
+
+    #[inline]
+    fn core_contains_circle(&self, c: &Circle<T>) -> bool {
+        self.contains(c)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: Float + Widen> Outline<T> for Polygon<T> {
+    #[inline]
+    fn any_edge(&self, f: &mut dyn FnMut(Segment<T>) -> bool) -> bool {
+        self.edges().any(f)
+    }
+
+    #[inline]
+    fn radius(&self) -> T {
+        T::zero()
+    }
+
+    #[inline]
+    fn core_contains_point(&self, p: Point<T>) -> bool {
+        self.contains_point(p)
+    }
+
+    #[inline]
+    fn core_contains_circle(&self, c: &Circle<T>) -> bool {
+        self.contains(c)
+    }
+}
+
+/// Shapes which do not cross are apart by the distance between the nearest edges of their cores
+/// less their radii.
+fn crossing_eps<T, A, B>(a: &A, b: &B, epsilon: T) -> bool
+where
+    T: Float,
+    A: Collision<B> + Outline<T>,
+    B: Outline<T>,
+{
+    if a.is_crossing(b) {
+        return true;
+    }
+    let d = a.radius() + b.radius() + epsilon;
+    a.any_edge(&mut |e| b.any_edge(&mut |f| e.distance_to_segment(&f) <= d))
+}
+
+/// `b` is in `a` grown by `epsilon` if the circle of its radius around every vertex of its core
+/// is. Exact for a convex `a` as the grown shape is convex then.
+fn containing_eps<T, A, B>(a: &A, b: &B, epsilon: T) -> bool
+where
+    T: Float,
+    A: Outline<T>,
+    B: Outline<T>,
+{
+    let grown = a.radius() + epsilon;
+    let r = b.radius();
+    !b.any_edge(&mut |e| {
+        let v = e.start;
+        let inside = if r <= grown {
+            a.core_contains_point(v) || a.any_edge(&mut |f| f.distance_to_point(v) <= grown - r)
+        } else {
+            a.core_contains_circle(&Circle::new(v, r - grown))
+        };
+        !inside
+    })
+}
+
+macro_rules! impl_collision_eps {
+    ($($lhs:ident => $rhs:ident),* $(,)?) => {
+        $(
+            impl<T: Float + Widen> CollisionEps<$rhs<T>> for $lhs<T> {
+                type Epsilon = T;
+
+                #[inline]
+                fn is_crossing_eps(&self, rhs: &$rhs<T>, epsilon: T) -> bool {
+                    crossing_eps(self, rhs, epsilon)
+                }
+
+                #[inline]
+                fn contains_eps(&self, v: &$rhs<T>, epsilon: T) -> bool {
+                    containing_eps(self, v, epsilon)
+                }
+            }
+        )*
+    };
+}
+
+impl_collision_eps!(
+    Circle => Point, Point => Circle, Circle => Circle,
+    Point => Rect, Rect => Point, Rect => Rect, Rect => Circle, Circle => Rect,
+    Segment => Point, Point => Segment, Segment => Segment,
+    Rect => Segment, Segment => Rect, Circle => Segment, Segment => Circle,
+    Triangle => Point, Point => Triangle, Triangle => Triangle,
+    Triangle => Rect, Rect => Triangle, Triangle => Circle, Circle => Triangle,
+    RoundedRect => Point, Point => RoundedRect, RoundedRect => Circle, Circle => RoundedRect,
+    RoundedRect => Rect, Rect => RoundedRect, Point => Point, Triangle => Segment,
+    Segment => Triangle, RoundedRect => Segment, Segment => RoundedRect,
+    RoundedRect => Triangle, Triangle => RoundedRect, RoundedRect => RoundedRect,
+);
+
+#[cfg(feature = "alloc")]
+impl_collision_eps!(
+    Polygon => Polygon, Polygon => Point, Point => Polygon, Polygon => Rect, Rect => Polygon,
+    Polygon => Circle, Circle => Polygon,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tangent_circles_test() {
+        // tangent at an angle of 0.3, but the squared distance rounds up past 4
+        let a = circle((0.0f32, 0.0), 1.0);
+        let b = circle((1.910673f32, 0.59104043), 1.0);
+        assert!(!is_crossing(&a, &b));
+        assert!(is_crossing_eps(&a, &b, 1e-5));
+        assert!(is_crossing_eps(&b, &a, 1e-5));
+        assert!(!is_crossing_eps(&a, &circle((2.001f32, 0.0), 1.0), 1e-5));
+        assert!(is_crossing_eps(&a, &circle((2.001f32, 0.0), 1.0), 1e-3));
+    }
+
+    #[test]
+    fn reference_test() {
+        let c = circle((0.0f32, 0.0), 1.0);
+        let circles = [circle((2.000001f32, 0.0), 1.0), circle((3.0, 0.0), 1.0)];
+        let hits = circles
+            .iter()
+            .filter(|b| is_crossing_eps(b, &c, 1e-5))
+            .count();
+        assert!(hits == 1);
+    }
+
+    #[test]
+    fn zero_epsilon_test() {
+        let r = rect((0.0f32, 0.0), (10.0, 10.0));
+        let shapes = [
+            circle((12.0f32, 5.0), 2.0),
+            circle((12.0f32, 12.0), 2.0),
+            circle((5.0f32, 5.0), 5.0),
+            circle((5.0f32, 5.0), 6.0),
+            circle((-3.0f32, 3.0), 2.9),
+        ];
+        for c in shapes {
+            assert!(is_crossing_eps(&r, &c, 0.0) == is_crossing(&r, &c));
+            assert!(contains_eps(&r, &c, 0.0) == contains(&r, &c));
+            assert!(is_crossing_eps(&c, &r, 0.0) == is_crossing(&c, &r));
+            assert!(contains_eps(&c, &r, 0.0) == contains(&c, &r));
+        }
+    }
+
+    #[test]
+    fn rect_eps_test() {
+        let a = rect((0.0f32, 0.0), (10.0, 10.0));
+        assert!(is_crossing_eps(&a, &rect((10.5, 0.0), (5.0, 5.0)), 0.5));
+        assert!(!is_crossing_eps(&a, &rect((10.5, 0.0), (5.0, 5.0)), 0.4));
+        // apart by 0.5 on both axes, so the corners are 0.71 apart
+        assert!(!is_crossing_eps(&a, &rect((10.5, 10.5), (5.0, 5.0)), 0.6));
+        assert!(is_crossing_eps(&a, &rect((10.5, 10.5), (5.0, 5.0)), 0.75));
+        assert!(contains_eps(&a, &rect((-0.5, 0.0), (10.5, 10.0)), 0.5));
+        assert!(!contains_eps(&a, &rect((-0.5, -0.5), (11.0, 11.0)), 0.5));
+        assert!(contains_eps(&a, &circle((5.0, 5.0), 5.5), 0.5));
+        assert!(!contains_eps(&a, &circle((5.0, 5.0), 5.5), 0.4));
+        assert!(contains_eps(&a, &point(10.0, 10.0001), 1e-3));
+    }
+
+    #[test]
+    fn segment_eps_test() {
+        let s = segment((0.0f32, 0.0), (10.0, 0.0));
+        assert!(!is_crossing(&s, &point(5.0, 1e-4)));
+        assert!(is_crossing_eps(&s, &point(5.0, 1e-4), 1e-3));
+        assert!(is_crossing_eps(&point(5.0, 1e-4), &s, 1e-3));
+        assert!(is_crossing_eps(
+            &s,
+            &segment((11.0, -1.0), (11.0, 1.0)),
+            1.0
+        ));
+        assert!(!is_crossing_eps(
+            &s,
+            &segment((11.0, -1.0), (11.0, 1.0)),
+            0.9
+        ));
+        assert!(contains_eps(&s, &segment((1.0, 0.5), (9.0, -0.5)), 0.5));
+        assert!(!contains_eps(&s, &circle((5.0, 0.0), 1.0), 0.5));
+        assert!(contains_eps(&circle((5.0, 0.0), 4.9), &s, 0.1));
+        assert!(!contains_eps(&circle((5.0, 0.0), 4.8), &s, 0.1));
+    }
+
+    #[test]
+    fn triangle_eps_test() {
+        let t = triangle((0.0f32, 0.0), (10.0, 0.0), (0.0, 10.0));
+        let p = point(5.0f32, 5.0 + 1e-4);
+        assert!(!is_crossing(&t, &p));
+        assert!(is_crossing_eps(&t, &p, 1e-3));
+        assert!(contains_eps(&t, &p, 1e-3));
+        assert!(contains_eps(&t, &circle((3.0, 3.0), 3.0), 0.2));
+        assert!(!contains_eps(&t, &circle((3.0, 3.0), 3.0), 0.1));
+        assert!(contains_eps(&t, &circle((0.0, 0.0), 0.5), 0.5));
+        assert!(!contains_eps(&t, &circle((0.0, 0.0), 0.5), 0.4));
+        let far = triangle((6.0f32, 6.0), (10.0, 6.0), (10.0, 10.0));
+        assert!(!is_crossing_eps(&t, &far, 1.4));
+        assert!(is_crossing_eps(&t, &far, 1.5));
+    }
+
+    #[test]
+    fn rounded_rect_eps_test() {
+        let a = rounded_rect(((0.0f32, 0.0), (10.0, 10.0)), 2.0);
+        let c = circle((-1.0f32, -1.0), 0.5);
+        assert!(!is_crossing(&a, &c));
+        let gap = 3.0 * 2.0f32.sqrt() - 2.0 - 0.5;
+        assert!(is_crossing_eps(&a, &c, gap + 1e-5));
+        assert!(!is_crossing_eps(&a, &c, gap - 1e-3));
+        assert!(contains_eps(&a, &rect((0.0, 0.0), (10.0, 10.0)), 0.83));
+        assert!(!contains_eps(&a, &rect((0.0, 0.0), (10.0, 10.0)), 0.8));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn polygon_eps_test() {
+        let p = Polygon::new(alloc::vec![
+            point(0.0f32, 0.0),
+            point(10.0, 0.0),
+            point(10.0, 4.0),
+            point(4.0, 4.0),
+            point(4.0, 10.0),
+            point(0.0, 10.0),
+        ]);
+        let c = circle((7.0f32, 7.0), 2.0);
+        assert!(!is_crossing(&p, &c));
+        assert!(is_crossing_eps(&p, &c, 1.0 + 1e-5));
+        assert!(!is_crossing_eps(&p, &c, 0.9));
+        assert!(contains_eps(&p, &rect((0.0, 0.0), (4.5, 10.0)), 0.5));
+        assert!(!contains_eps(&p, &rect((0.0, 0.0), (4.5, 10.0)), 0.4));
+    }
+}
@@ -1,7 +1,10 @@
 use crate::*;
 
+mod names;
+
 pub trait Values: Sized {
     fn values(value: u32, alpha: Self) -> Rgba<Self>;
+    fn values_argb(value: u32) -> Rgba<Self>;
 }
 
 impl Values for u8 {
@@ -14,33 +17,117 @@ impl Values for u8 {
             alpha,
         )
     }
+
+    #[inline]
+    fn values_argb(value: u32) -> Rgba<u8> {
+        Self::values(value, (value >> 24) as u8)
+    }
 }
 
 impl Values for f32 {
     #[inline]
     fn values(value: u32, alpha: f32) -> Rgba<f32> {
         Rgba::new(
-            ((value >> 16) & 0xff) as f32 / std::u8::MAX as f32,
-            ((value >> 8) & 0xff) as f32 / std::u8::MAX as f32,
-            (value & 0xff) as f32 / std::u8::MAX as f32,
+            ((value >> 16) & 0xff) as f32 / u8::MAX as f32,
+            ((value >> 8) & 0xff) as f32 / u8::MAX as f32,
+            (value & 0xff) as f32 / u8::MAX as f32,
             alpha,
         )
     }
+
+    #[inline]
+    fn values_argb(value: u32) -> Rgba<f32> {
+        Self::values(value, (value >> 24) as f32 / u8::MAX as f32)
+    }
 }
 
 impl Values for f64 {
     #[inline]
     fn values(value: u32, alpha: f64) -> Rgba<f64> {
         Rgba::new(
-            ((value >> 16) & 0xff) as f64 / std::u8::MAX as f64,
-            ((value >> 8) & 0xff) as f64 / std::u8::MAX as f64,
-            (value & 0xff) as f64 / std::u8::MAX as f64,
+            ((value >> 16) & 0xff) as f64 / u8::MAX as f64,
+            ((value >> 8) & 0xff) as f64 / u8::MAX as f64,
+            (value & 0xff) as f64 / u8::MAX as f64,
             alpha,
         )
     }
+
+    #[inline]
+    fn values_argb(value: u32) -> Rgba<f64> {
+        Self::values(value, (value >> 24) as f64 / u8::MAX as f64)
+    }
+}
+
+/// The displayable range of a color channel.
+///
+/// Integer channels range over `0..=MAX` of the type, float channels over `0.0..=1.0`.
+/// A separate trait is used instead of `num::Bounded` because the bounds of a float type
+/// are not the bounds of a float channel.
+pub trait Channel: Copy {
+    fn channel_min() -> Self;
+    fn channel_max() -> Self;
+
+    /// Multiplies two channels as if both were in `0.0..=1.0`.
+    ///
+    /// Integer channels compute `(a * b + MAX / 2) / MAX` in a wider type, which rounds to nearest.
+    fn channel_mul(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_channel_int {
+    ($($t:ty => $w:ty),*) => {
+        $(
+            impl Channel for $t {
+                #[inline]
+                fn channel_min() -> Self {
+                    0
+                }
+
+                #[inline]
+                fn channel_max() -> Self {
+                    <$t>::MAX
+                }
+
+                #[inline]
+                fn channel_mul(self, rhs: Self) -> Self {
+                    let max = <$t>::MAX as $w;
+                    ((self as $w * rhs as $w + max / 2) / max) as $t
+                }
+            }
+        )*
+    };
+}
+
+impl_channel_int!(u8 => u16, u16 => u32, u32 => u64, u64 => u128);
+
+macro_rules! impl_channel_float {
+    ($($t:ty),*) => {
+        $(
+            impl Channel for $t {
+                #[inline]
+                fn channel_min() -> Self {
+                    0.0
+                }
+
+                #[inline]
+                fn channel_max() -> Self {
+                    1.0
+                }
+
+                #[inline]
+                fn channel_mul(self, rhs: Self) -> Self {
+                    self * rhs
+                }
+            }
+        )*
+    };
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+impl_channel_float!(f32, f64);
+
+/// A color with red, green, blue and alpha channels.
+///
+/// The `Default` value has all channels zero, which is transparent black rather than opaque black.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Debug)]
 #[repr(C)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Rgba<T> {
@@ -52,7 +139,7 @@ pub struct Rgba<T> {
 
 impl<T> Rgba<T> {
     #[inline]
-    pub fn new(r: T, g: T, b: T, a: T) -> Self {
+    pub const fn new(r: T, g: T, b: T, a: T) -> Self {
         Self { r, g, b, a }
     }
 
@@ -68,6 +155,90 @@ impl<T> Rgba<T> {
     {
         <T as Values>::values(value, alpha)
     }
+
+    /// Extracts all four channels from `0xAARRGGBB`.
+    #[inline]
+    pub fn values_argb(value: u32) -> Rgba<T>
+    where
+        T: Values,
+    {
+        <T as Values>::values_argb(value)
+    }
+
+    #[inline]
+    pub fn with_alpha(self, a: T) -> Self {
+        Self { a, ..self }
+    }
+
+    /// Iterates references to the channels in r, g, b, a order.
+    #[inline]
+    pub fn iter(&self) -> core::array::IntoIter<&T, 4> {
+        [&self.r, &self.g, &self.b, &self.a].into_iter()
+    }
+}
+
+impl<T: Scalar + Zero + One> Rgba<T> {
+    /// Interpolates each component linearly. `t` outside of `0..=1` extrapolates.
+    ///
+    /// # Panics
+    ///
+    /// For unsigned `T`, panics on overflow in debug builds if an extrapolated component would be
+    /// negative.
+    #[inline]
+    pub fn lerp(self, other: impl Into<Self>, t: T) -> Self {
+        let other = other.into();
+        Self::new(
+            lerp(self.r, other.r, t),
+            lerp(self.g, other.g, t),
+            lerp(self.b, other.b, t),
+            lerp(self.a, other.a, t),
+        )
+    }
+
+    /// Same as `lerp` but `t` is clamped to `0..=1`.
+    #[inline]
+    pub fn lerp_clamped(self, other: impl Into<Self>, t: T) -> Self {
+        self.lerp(other, clamp_unit(t))
+    }
+}
+
+impl<T: Float> Rgba<T> {
+    /// Returns `true` if every component differs from the one of `other` by at most `epsilon`.
+    #[inline]
+    pub fn approx_eq(&self, other: &Self, epsilon: T) -> bool {
+        self.iter()
+            .zip(other.iter())
+            .all(|(a, b)| (*a - *b).abs() <= epsilon)
+    }
+}
+
+impl<T: Channel> Rgba<T> {
+    /// Creates a color whose alpha is `T::channel_max()`, i.e. `255` for `u8` and `1.0` for floats.
+    #[inline]
+    pub fn opaque(r: T, g: T, b: T) -> Self {
+        Self::new(r, g, b, T::channel_max())
+    }
+
+    /// Creates a color whose channels are all `T::channel_min()`.
+    #[inline]
+    pub fn transparent() -> Self {
+        let v = T::channel_min();
+        Self::new(v, v, v, v)
+    }
+}
+
+impl<T> Rgba<T> {
+    #[inline]
+    pub fn as_array(&self) -> &[T; 4] {
+        // SAFETY: `Rgba<T>` is `repr(C)` with four fields of `T`, which has the same layout as `[T; 4]`.
+        unsafe { &*(self as *const Self as *const [T; 4]) }
+    }
+
+    #[inline]
+    pub fn as_array_mut(&mut self) -> &mut [T; 4] {
+        // SAFETY: `Rgba<T>` is `repr(C)` with four fields of `T`, which has the same layout as `[T; 4]`.
+        unsafe { &mut *(self as *mut Self as *mut [T; 4]) }
+    }
 }
 
 impl<T: ToPrimitive> Rgba<T> {
@@ -80,6 +251,504 @@ impl<T: ToPrimitive> Rgba<T> {
             U::from(self.a)?,
         ))
     }
+
+    #[inline]
+    pub fn try_cast<U: NumCast>(self) -> Result<Rgba<U>, CastError>
+    where
+        T: Copy,
+    {
+        Ok(Rgba::new(
+            cast_component(self.r, "Rgba", "r")?,
+            cast_component(self.g, "Rgba", "g")?,
+            cast_component(self.b, "Rgba", "b")?,
+            cast_component(self.a, "Rgba", "a")?,
+        ))
+    }
+}
+
+impl<T: PartialOrd + Copy> Rgba<T> {
+    /// Returns the channel-wise minimum, alpha included.
+    #[inline]
+    pub fn min(self, rhs: impl Into<Self>) -> Self {
+        let rhs = rhs.into();
+        Rgba::new(
+            partial_min(self.r, rhs.r),
+            partial_min(self.g, rhs.g),
+            partial_min(self.b, rhs.b),
+            partial_min(self.a, rhs.a),
+        )
+    }
+
+    /// Returns the channel-wise maximum, alpha included.
+    #[inline]
+    pub fn max(self, rhs: impl Into<Self>) -> Self {
+        let rhs = rhs.into();
+        Rgba::new(
+            partial_max(self.r, rhs.r),
+            partial_max(self.g, rhs.g),
+            partial_max(self.b, rhs.b),
+            partial_max(self.a, rhs.a),
+        )
+    }
+
+    /// Clamps each channel, alpha included, to `lo..=hi`.
+    ///
+    /// When a channel of `lo` is greater than the one of `hi`, that channel becomes `hi`.
+    #[inline]
+    pub fn clamp(self, lo: impl Into<Self>, hi: impl Into<Self>) -> Self {
+        self.max(lo).min(hi)
+    }
+}
+
+impl Rgba<u8> {
+    /// Scales each channel from `0..=255` to `0.0..=1.0`.
+    #[inline]
+    pub fn to_f32(self) -> Rgba<f32> {
+        self.map(|v| v as f32 / u8::MAX as f32)
+    }
+
+    /// Scales each channel from `0..=255` to `0.0..=1.0`.
+    #[inline]
+    pub fn to_f64(self) -> Rgba<f64> {
+        self.map(|v| v as f64 / u8::MAX as f64)
+    }
+
+    /// Parses `#rgb`, `#rgba`, `#rrggbb` or `#rrggbbaa`. The leading `#` is optional.
+    ///
+    /// A missing alpha is treated as `0xff`.
+    pub fn from_hex(s: &str) -> Option<Self> {
+        parse_hex(s.strip_prefix('#').unwrap_or(s)).ok()
+    }
+
+    /// Looks up a CSS named color case-insensitively. The alpha of the result is `0xff`.
+    pub fn from_name(name: &str) -> Option<Self> {
+        let name = name.bytes().map(|c| c.to_ascii_lowercase());
+        names::NAMES
+            .binary_search_by(|(n, _)| n.bytes().cmp(name.clone()))
+            .ok()
+            .map(|i| Rgba::values(names::NAMES[i].1, 0xff))
+    }
+
+    /// Unpacks `RRRRRGGGGGGBBBBB`. The high bits are replicated into the low bits, so the
+    /// maximum of each field maps to 255. The alpha of the result is 255.
+    #[inline]
+    pub fn from_rgb565(value: u16) -> Self {
+        let r = ((value >> 11) & 0x1f) as u8;
+        let g = ((value >> 5) & 0x3f) as u8;
+        let b = (value & 0x1f) as u8;
+        Rgba::new(
+            (r << 3) | (r >> 2),
+            (g << 2) | (g >> 4),
+            (b << 3) | (b >> 2),
+            0xff,
+        )
+    }
+
+    /// Packs into `RRRRRGGGGGGBBBBB` by truncating the low bits. Alpha is dropped.
+    #[inline]
+    pub fn to_rgb565(self) -> u16 {
+        ((self.r as u16 >> 3) << 11) | ((self.g as u16 >> 2) << 5) | (self.b as u16 >> 3)
+    }
+
+    /// Unpacks `RRRRGGGGBBBBAAAA`. Each field is scaled by 17, so 15 maps to 255.
+    #[inline]
+    pub fn from_rgba4444(value: u16) -> Self {
+        let f = |shift: u16| ((value >> shift) & 0xf) as u8 * 0x11;
+        Rgba::new(f(12), f(8), f(4), f(0))
+    }
+
+    /// Packs into `RRRRGGGGBBBBAAAA` by truncating the low bits.
+    #[inline]
+    pub fn to_rgba4444(self) -> u16 {
+        let f = |v: u8, shift: u16| (v as u16 >> 4) << shift;
+        f(self.r, 12) | f(self.g, 8) | f(self.b, 4) | f(self.a, 0)
+    }
+
+    /// Unpacks `ARRRRRGGGGGBBBBB`. The color fields are expanded like `from_rgb565` and
+    /// the alpha bit maps to 0 or 255.
+    #[inline]
+    pub fn from_argb1555(value: u16) -> Self {
+        let f = |shift: u16| {
+            let v = ((value >> shift) & 0x1f) as u8;
+            (v << 3) | (v >> 2)
+        };
+        let a = if value & 0x8000 != 0 { 0xff } else { 0 };
+        Rgba::new(f(10), f(5), f(0), a)
+    }
+
+    /// Packs into `ARRRRRGGGGGBBBBB` by truncating the low bits. The alpha bit is set when
+    /// alpha is 128 or more.
+    #[inline]
+    pub fn to_argb1555(self) -> u16 {
+        let f = |v: u8, shift: u16| (v as u16 >> 3) << shift;
+        ((self.a as u16 >> 7) << 15) | f(self.r, 10) | f(self.g, 5) | f(self.b, 0)
+    }
+
+    /// Formats the color as `#rrggbbaa`.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    pub fn to_hex(&self) -> alloc::string::String {
+        alloc::format!("#{:02x}{:02x}{:02x}{:02x}", self.r, self.g, self.b, self.a)
+    }
+}
+
+impl Rgba<f32> {
+    /// Clamps each channel to `0.0..=1.0` and scales it to `0..=255` with rounding.
+    #[inline]
+    pub fn to_u8(self) -> Rgba<u8> {
+        self.map(|v| (v.clamp(0.0, 1.0) * u8::MAX as f32).round() as u8)
+    }
+}
+
+impl Rgba<f64> {
+    /// Clamps each channel to `0.0..=1.0` and scales it to `0..=255` with rounding.
+    #[inline]
+    pub fn to_u8(self) -> Rgba<u8> {
+        self.map(|v| (v.clamp(0.0, 1.0) * u8::MAX as f64).round() as u8)
+    }
+}
+
+#[inline]
+pub(crate) fn srgb_to_linear<T: Float>(c: T) -> T {
+    if c <= T::from(0.04045).unwrap() {
+        c / T::from(12.92).unwrap()
+    } else {
+        ((c + T::from(0.055).unwrap()) / T::from(1.055).unwrap()).powf(T::from(2.4).unwrap())
+    }
+}
+
+#[inline]
+pub(crate) fn linear_to_srgb<T: Float>(c: T) -> T {
+    if c <= T::from(0.0031308).unwrap() {
+        c * T::from(12.92).unwrap()
+    } else {
+        T::from(1.055).unwrap() * c.powf(T::one() / T::from(2.4).unwrap()) - T::from(0.055).unwrap()
+    }
+}
+
+#[inline]
+fn relative_luminance<T: Float>(c: &Rgba<T>) -> T {
+    T::from(0.2126).unwrap() * srgb_to_linear(c.r)
+        + T::from(0.7152).unwrap() * srgb_to_linear(c.g)
+        + T::from(0.0722).unwrap() * srgb_to_linear(c.b)
+}
+
+macro_rules! impl_relative_luminance {
+    ($($t:ty),*) => {
+        $(
+            impl Rgba<$t> {
+                /// Returns the WCAG 2.x relative luminance of the sRGB color. Alpha is ignored.
+                #[inline]
+                pub fn relative_luminance(&self) -> $t {
+                    relative_luminance(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_relative_luminance!(f32, f64);
+
+impl Rgba<u8> {
+    /// Returns the WCAG 2.x relative luminance of the sRGB color. Alpha is ignored.
+    #[inline]
+    pub fn relative_luminance(&self) -> f32 {
+        relative_luminance(&self.to_f32())
+    }
+}
+
+#[inline]
+fn distance_squared_rgb<T: Float>(a: &Rgba<T>, b: &Rgba<T>) -> T {
+    let (dr, dg, db) = (a.r - b.r, a.g - b.g, a.b - b.b);
+    dr * dr + dg * dg + db * db
+}
+
+#[inline]
+fn redmean_distance<T: Float>(a: &Rgba<T>, b: &Rgba<T>) -> T {
+    let two = T::from(2.0).unwrap();
+    let rmean = (a.r + b.r) / two;
+    let (dr, dg, db) = (a.r - b.r, a.g - b.g, a.b - b.b);
+    ((two + rmean) * dr * dr
+        + T::from(4.0).unwrap() * dg * dg
+        + (T::from(3.0).unwrap() - rmean) * db * db)
+        .sqrt()
+}
+
+macro_rules! impl_distance {
+    ($($t:ty),*) => {
+        $(
+            impl Rgba<$t> {
+                /// Returns the redmean-weighted distance between r, g and b in `0.0..=1.0`.
+                /// Alpha is ignored.
+                #[inline]
+                pub fn distance(&self, rhs: impl Into<Self>) -> $t {
+                    redmean_distance(self, &rhs.into())
+                }
+
+                /// Returns the squared Euclidean distance between r, g and b. Alpha is ignored.
+                #[inline]
+                pub fn distance_squared_rgb(&self, rhs: impl Into<Self>) -> $t {
+                    distance_squared_rgb(self, &rhs.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_distance!(f32, f64);
+
+impl Rgba<u8> {
+    /// Returns the redmean-weighted distance of the colors scaled to `0.0..=1.0`.
+    /// Alpha is ignored.
+    #[inline]
+    pub fn distance(&self, rhs: impl Into<Self>) -> f32 {
+        redmean_distance(&self.to_f32(), &rhs.into().to_f32())
+    }
+
+    /// Returns the squared Euclidean distance between r, g and b. Alpha is ignored.
+    #[inline]
+    pub fn distance_squared_rgb(&self, rhs: impl Into<Self>) -> u32 {
+        let rhs = rhs.into();
+        let d = |a: u8, b: u8| (a as i32 - b as i32).pow(2) as u32;
+        d(self.r, rhs.r) + d(self.g, rhs.g) + d(self.b, rhs.b)
+    }
+}
+
+fn average<T: Float>(colors: &[Rgba<T>]) -> Option<Rgba<T>> {
+    if colors.is_empty() {
+        return None;
+    }
+    let n = T::from(colors.len()).unwrap();
+    let zero = Rgba::new(T::zero(), T::zero(), T::zero(), T::zero());
+    let sum = colors
+        .iter()
+        .fold(zero, |s, c| s + c.with_alpha(T::one()) * c.a);
+    if sum.a == T::zero() {
+        let sum = colors.iter().fold(zero, |s, c| s + *c);
+        return Some(sum / n);
+    }
+    Some(Rgba::new(
+        sum.r / sum.a,
+        sum.g / sum.a,
+        sum.b / sum.a,
+        sum.a / n,
+    ))
+}
+
+macro_rules! impl_average {
+    ($($t:ty),*) => {
+        $(
+            impl Rgba<$t> {
+                /// Returns the mean of `colors` with r, g and b weighted by alpha.
+                ///
+                /// Returns `None` for an empty slice. When every alpha is zero, r, g and b are
+                /// the plain mean and alpha is zero.
+                #[inline]
+                pub fn average(colors: &[Self]) -> Option<Self> {
+                    average(colors)
+                }
+            }
+        )*
+    };
+}
+
+impl_average!(f32, f64);
+
+impl Rgba<u8> {
+    /// Returns the mean of `colors` with r, g and b weighted by alpha, rounded to nearest.
+    ///
+    /// Returns `None` for an empty slice. When every alpha is zero, r, g and b are
+    /// the plain mean and alpha is zero. Sums are accumulated in `u64`.
+    pub fn average(colors: &[Self]) -> Option<Self> {
+        if colors.is_empty() {
+            return None;
+        }
+        let n = colors.len() as u64;
+        let a = colors.iter().map(|c| c.a as u64).sum::<u64>();
+        let (weight, d): (fn(&Self) -> u64, u64) = if a == 0 {
+            (|_| 1, n)
+        } else {
+            (|c| c.a as u64, a)
+        };
+        let f = |ch: fn(&Self) -> u8| {
+            let sum = colors.iter().map(|c| ch(c) as u64 * weight(c)).sum::<u64>();
+            ((sum + d / 2) / d) as u8
+        };
+        Some(Rgba::new(
+            f(|c| c.r),
+            f(|c| c.g),
+            f(|c| c.b),
+            ((a + n / 2) / n) as u8,
+        ))
+    }
+}
+
+/// Returns the WCAG 2.x contrast ratio `(L1 + 0.05) / (L2 + 0.05)` where `L1` is the brighter one.
+#[inline]
+pub fn contrast_ratio<T: Float>(a: Rgba<T>, b: Rgba<T>) -> T {
+    let a = relative_luminance(&a);
+    let b = relative_luminance(&b);
+    let (l1, l2) = if a > b { (a, b) } else { (b, a) };
+    let k = T::from(0.05).unwrap();
+    (l1 + k) / (l2 + k)
+}
+
+impl<T: Channel + PartialOrd> Rgba<T> {
+    /// Clamps each channel to `T::channel_min()..=T::channel_max()`.
+    ///
+    /// NaN channels become `T::channel_min()`. This is a no-op for integer channels.
+    #[inline]
+    pub fn clamp_valid(self) -> Self {
+        self.map(|v| {
+            if v > T::channel_max() {
+                T::channel_max()
+            } else if v >= T::channel_min() {
+                v
+            } else {
+                T::channel_min()
+            }
+        })
+    }
+
+    /// Returns `true` if every channel is in `T::channel_min()..=T::channel_max()`.
+    #[inline]
+    pub fn is_valid(&self) -> bool {
+        let f = |v: T| v >= T::channel_min() && v <= T::channel_max();
+        f(self.r) && f(self.g) && f(self.b) && f(self.a)
+    }
+}
+
+impl<T: Channel> Rgba<T> {
+    /// Multiplies each channel pair with `Channel::channel_mul`.
+    ///
+    /// For `u8` this is `(a * b + 127) / 255` computed in `u16`.
+    #[inline]
+    pub fn modulate(self, rhs: impl Into<Self>) -> Self {
+        let rhs = rhs.into();
+        Rgba::new(
+            self.r.channel_mul(rhs.r),
+            self.g.channel_mul(rhs.g),
+            self.b.channel_mul(rhs.b),
+            self.a.channel_mul(rhs.a),
+        )
+    }
+}
+
+impl<T> Rgba<T>
+where
+    T: Channel + core::ops::Sub<T, Output = T>,
+{
+    /// Inverts r, g and b against `T::channel_max()`. Alpha is left untouched.
+    #[inline]
+    pub fn invert(self) -> Self {
+        let max = T::channel_max();
+        Rgba::new(max - self.r, max - self.g, max - self.b, self.a)
+    }
+}
+
+impl<T: Float + Channel> Rgba<T> {
+    /// Applies `channel.powf(1 / gamma)` to r, g and b. Alpha is left untouched.
+    ///
+    /// A gamma greater than 1 brightens the color, less than 1 darkens it.
+    #[inline]
+    pub fn gamma(self, gamma: T) -> Self {
+        let e = T::one() / gamma;
+        Rgba::new(self.r.powf(e), self.g.powf(e), self.b.powf(e), self.a)
+    }
+
+    /// Multiplies r, g and b by `1 + factor` and clamps them to the channel range.
+    #[inline]
+    pub fn brighten(self, factor: T) -> Self {
+        self.scale_rgb(T::one() + factor)
+    }
+
+    /// Multiplies r, g and b by `1 - factor` and clamps them to the channel range.
+    #[inline]
+    pub fn darken(self, factor: T) -> Self {
+        self.scale_rgb(T::one() - factor)
+    }
+
+    #[inline]
+    fn scale_rgb(self, s: T) -> Self {
+        let f = |v: T| (v * s).max(T::channel_min()).min(T::channel_max());
+        Rgba::new(f(self.r), f(self.g), f(self.b), self.a)
+    }
+}
+
+impl<T: num::traits::SaturatingAdd> Rgba<T> {
+    #[inline]
+    pub fn saturating_add(self, rhs: impl Into<Self>) -> Self {
+        let rhs = rhs.into();
+        Rgba::new(
+            self.r.saturating_add(&rhs.r),
+            self.g.saturating_add(&rhs.g),
+            self.b.saturating_add(&rhs.b),
+            self.a.saturating_add(&rhs.a),
+        )
+    }
+}
+
+impl<T: num::traits::SaturatingSub> Rgba<T> {
+    #[inline]
+    pub fn saturating_sub(self, rhs: impl Into<Self>) -> Self {
+        let rhs = rhs.into();
+        Rgba::new(
+            self.r.saturating_sub(&rhs.r),
+            self.g.saturating_sub(&rhs.g),
+            self.b.saturating_sub(&rhs.b),
+            self.a.saturating_sub(&rhs.a),
+        )
+    }
+}
+
+impl<T: num::traits::SaturatingMul> Rgba<T> {
+    #[inline]
+    pub fn saturating_mul_scalar(self, rhs: T) -> Self {
+        Rgba::new(
+            self.r.saturating_mul(&rhs),
+            self.g.saturating_mul(&rhs),
+            self.b.saturating_mul(&rhs),
+            self.a.saturating_mul(&rhs),
+        )
+    }
+}
+
+impl<T: CheckedAdd> Rgba<T> {
+    #[inline]
+    pub fn checked_add(self, rhs: impl Into<Self>) -> Option<Self> {
+        let rhs = rhs.into();
+        Some(Rgba::new(
+            self.r.checked_add(&rhs.r)?,
+            self.g.checked_add(&rhs.g)?,
+            self.b.checked_add(&rhs.b)?,
+            self.a.checked_add(&rhs.a)?,
+        ))
+    }
+}
+
+impl<T: CheckedSub> Rgba<T> {
+    #[inline]
+    pub fn checked_sub(self, rhs: impl Into<Self>) -> Option<Self> {
+        let rhs = rhs.into();
+        Some(Rgba::new(
+            self.r.checked_sub(&rhs.r)?,
+            self.g.checked_sub(&rhs.g)?,
+            self.b.checked_sub(&rhs.b)?,
+            self.a.checked_sub(&rhs.a)?,
+        ))
+    }
+}
+
+impl<T: CheckedMul> Rgba<T> {
+    #[inline]
+    pub fn checked_mul_scalar(self, rhs: T) -> Option<Self> {
+        Some(Rgba::new(
+            self.r.checked_mul(&rhs)?,
+            self.g.checked_mul(&rhs)?,
+            self.b.checked_mul(&rhs)?,
+            self.a.checked_mul(&rhs)?,
+        ))
+    }
 }
 
 impl<T> From<(T, T, T, T)> for Rgba<T> {
@@ -96,6 +765,147 @@ impl<T: Copy> From<[T; 4]> for Rgba<T> {
     }
 }
 
+impl<T> core::ops::Index<usize> for Rgba<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: usize) -> &T {
+        match index {
+            0 => &self.r,
+            1 => &self.g,
+            2 => &self.b,
+            3 => &self.a,
+            _ => panic!("Rgba index out of range: {}", index),
+        }
+    }
+}
+
+impl<T> core::ops::IndexMut<usize> for Rgba<T> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        match index {
+            0 => &mut self.r,
+            1 => &mut self.g,
+            2 => &mut self.b,
+            3 => &mut self.a,
+            _ => panic!("Rgba index out of range: {}", index),
+        }
+    }
+}
+
+/// Iterates the channels in r, g, b, a order.
+impl<T> IntoIterator for Rgba<T> {
+    type Item = T;
+    type IntoIter = core::array::IntoIter<T, 4>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        [self.r, self.g, self.b, self.a].into_iter()
+    }
+}
+
+/// Formats as CSS `rgba(r, g, b, a)` with alpha as a decimal in `0..=1` rounded to 3 places.
+///
+/// The alternate flag (`{:#}`) formats as `#rrggbbaa` instead.
+impl core::fmt::Display for Rgba<u8> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        if f.alternate() {
+            return write!(
+                f,
+                "#{:02x}{:02x}{:02x}{:02x}",
+                self.r, self.g, self.b, self.a
+            );
+        }
+        let a = (self.a as f32 / u8::MAX as f32 * 1000.0).round() / 1000.0;
+        write!(f, "rgba({}, {}, {}, {})", self.r, self.g, self.b, a)
+    }
+}
+
+macro_rules! impl_display_float {
+    ($($t:ty),*) => {
+        $(
+            /// Formats as CSS `rgba(r, g, b, a)` where r, g and b are scaled to `0..=255` and
+            /// rounded, and alpha is written as is.
+            ///
+            /// The alternate flag (`{:#}`) formats as `#rrggbbaa` of `Rgba::to_u8` instead.
+            impl core::fmt::Display for Rgba<$t> {
+                fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                    let c = self.to_u8();
+                    if f.alternate() {
+                        return write!(f, "{:#}", c);
+                    }
+                    write!(f, "rgba({}, {}, {}, {})", c.r, c.g, c.b, self.a)
+                }
+            }
+        )*
+    };
+}
+
+impl_display_float!(f32, f64);
+
+/// Samples r, g and b uniformly. The alpha is always 255.
+#[cfg(feature = "rand")]
+impl rand::distributions::Distribution<Rgba<u8>> for rand::distributions::Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Rgba<u8> {
+        Rgba::opaque(rng.gen(), rng.gen(), rng.gen())
+    }
+}
+
+/// Samples r, g and b uniformly in `0.0..1.0`. The alpha is always 1.0.
+#[cfg(feature = "rand")]
+impl rand::distributions::Distribution<Rgba<f32>> for rand::distributions::Standard {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Rgba<f32> {
+        Rgba::opaque(rng.gen(), rng.gen(), rng.gen())
+    }
+}
+
+/// Parses the digits of `Rgba::from_hex`, naming the channel which failed.
+///
+/// The digits per channel are decided by the length, one up to 4 digits and two above. A channel
+/// cut short or with a non-hex digit is `Invalid`.
+fn parse_hex(s: &str) -> Result<Rgba<u8>, ParseError> {
+    let err = |kind, component| ParseError::new("Rgba", component, kind);
+    let width = if s.len() <= 4 { 1 } else { 2 };
+    let mut values = [0xff; 4];
+    for (i, name) in ["r", "g", "b", "a"].into_iter().enumerate() {
+        let start = i * width;
+        if start >= s.len() {
+            if i < 3 {
+                return Err(err(ParseErrorKind::Missing, Some(name)));
+            }
+            break;
+        }
+        let part = s
+            .get(start..s.len().min(start + width))
+            .filter(|p| p.len() == width && p.bytes().all(|c| c.is_ascii_hexdigit()))
+            .ok_or(err(ParseErrorKind::Invalid, Some(name)))?;
+        let v = u8::from_str_radix(part, 16).unwrap();
+        values[i] = if width == 1 { v * 0x11 } else { v };
+    }
+    if s.len() > 4 * width {
+        return Err(err(ParseErrorKind::Trailing, None));
+    }
+    let [r, g, b, a] = values;
+    Ok(Rgba::new(r, g, b, a))
+}
+
+/// Parses a hex color starting with `#` (see `Rgba::from_hex`) or a CSS named color.
+///
+/// An unknown name is `ParseErrorKind::Invalid` without a component.
+impl core::str::FromStr for Rgba<u8> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        match s.strip_prefix('#') {
+            Some(hex) => parse_hex(hex),
+            None => {
+                Rgba::from_name(s).ok_or(ParseError::new("Rgba", None, ParseErrorKind::Invalid))
+            }
+        }
+    }
+}
+
 impl<T> PartialEq<(T, T, T, T)> for Rgba<T>
 where
     T: PartialEq,
@@ -136,9 +946,9 @@ where
     }
 }
 
-impl<T, U> std::ops::Add<U> for Rgba<T>
+impl<T, U> core::ops::Add<U> for Rgba<T>
 where
-    T: std::ops::Add<T, Output = T>,
+    T: core::ops::Add<T, Output = T>,
     U: Into<Self>,
 {
     type Output = Self;
@@ -155,9 +965,9 @@ where
     }
 }
 
-impl<T, U> std::ops::Sub<U> for Rgba<T>
+impl<T, U> core::ops::Sub<U> for Rgba<T>
 where
-    T: std::ops::Sub<T, Output = T>,
+    T: core::ops::Sub<T, Output = T>,
     U: Into<Self>,
 {
     type Output = Self;
@@ -174,9 +984,9 @@ where
     }
 }
 
-impl<T> std::ops::Mul<T> for Rgba<T>
+impl<T> core::ops::Mul<T> for Rgba<T>
 where
-    T: std::ops::Mul<T, Output = T> + Copy,
+    T: core::ops::Mul<T, Output = T> + Copy,
 {
     type Output = Self;
 
@@ -186,9 +996,9 @@ where
     }
 }
 
-impl<T> std::ops::Div<T> for Rgba<T>
+impl<T> core::ops::Div<T> for Rgba<T>
 where
-    T: std::ops::Div<T, Output = T> + Copy,
+    T: core::ops::Div<T, Output = T> + Copy,
 {
     type Output = Self;
 
@@ -198,9 +1008,9 @@ where
     }
 }
 
-impl<T, U> std::ops::AddAssign<U> for Rgba<T>
+impl<T, U> core::ops::AddAssign<U> for Rgba<T>
 where
-    T: std::ops::AddAssign<T>,
+    T: core::ops::AddAssign<T>,
     U: Into<Self>,
 {
     fn add_assign(&mut self, rhs: U) {
@@ -212,9 +1022,9 @@ where
     }
 }
 
-impl<T, U> std::ops::SubAssign<U> for Rgba<T>
+impl<T, U> core::ops::SubAssign<U> for Rgba<T>
 where
-    T: std::ops::SubAssign<T>,
+    T: core::ops::SubAssign<T>,
     U: Into<Self>,
 {
     fn sub_assign(&mut self, rhs: U) {
@@ -226,9 +1036,9 @@ where
     }
 }
 
-impl<T> std::ops::MulAssign<T> for Rgba<T>
+impl<T> core::ops::MulAssign<T> for Rgba<T>
 where
-    T: std::ops::MulAssign<T> + Copy,
+    T: core::ops::MulAssign<T> + Copy,
 {
     fn mul_assign(&mut self, rhs: T) {
         self.r *= rhs;
@@ -238,9 +1048,9 @@ where
     }
 }
 
-impl<T> std::ops::DivAssign<T> for Rgba<T>
+impl<T> core::ops::DivAssign<T> for Rgba<T>
 where
-    T: std::ops::DivAssign<T> + Copy,
+    T: core::ops::DivAssign<T> + Copy,
 {
     fn div_assign(&mut self, rhs: T) {
         self.r /= rhs;
@@ -250,8 +1060,51 @@ where
     }
 }
 
+impl_left_scalar_mul!(Rgba);
+
+/// Serializes `Rgba<u8>` as a `#rrggbbaa` string.
+///
+/// Deserialization accepts the hex forms of `Rgba::from_hex` as well as the struct form.
+///
+/// ```
+/// #[derive(serde::Serialize, serde::Deserialize)]
+/// struct Config {
+///     #[serde(with = "gecl::color::serde_hex")]
+///     tint: gecl::Rgba<u8>,
+/// }
+///
+/// let config: Config = serde_json::from_str(r##"{"tint":"#ff8800"}"##).unwrap();
+/// assert!(config.tint == gecl::rgba(0xff, 0x88, 0x00, 0xff));
+/// assert!(serde_json::to_string(&config).unwrap() == r##"{"tint":"#ff8800ff"}"##);
+/// ```
+#[cfg(all(feature = "serde", feature = "alloc"))]
+pub mod serde_hex {
+    use super::Rgba;
+    use alloc::string::String;
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Repr {
+        Hex(String),
+        Struct(Rgba<u8>),
+    }
+
+    pub fn serialize<S: Serializer>(value: &Rgba<u8>, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!("{:#}", value))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Rgba<u8>, D::Error> {
+        match Repr::deserialize(deserializer)? {
+            Repr::Hex(s) => Rgba::from_hex(&s)
+                .ok_or_else(|| D::Error::custom(format_args!("invalid hex color: {}", s))),
+            Repr::Struct(v) => Ok(v),
+        }
+    }
+}
+
 #[inline]
-pub fn rgba<T>(r: T, g: T, b: T, a: T) -> Rgba<T> {
+pub const fn rgba<T>(r: T, g: T, b: T, a: T) -> Rgba<T> {
     Rgba::new(r, g, b, a)
 }
 
@@ -259,6 +1112,52 @@ pub fn rgba<T>(r: T, g: T, b: T, a: T) -> Rgba<T> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn try_cast_test() {
+        assert!(rgba(1, 2, 3, 4).try_cast::<u8>() == Ok(rgba(1, 2, 3, 4)));
+        let e = rgba(-1, 0, 0, 0).try_cast::<u8>().unwrap_err();
+        assert!(e.type_name() == "Rgba" && e.component() == "r");
+        let e = rgba(0, 256, 0, 0).try_cast::<u8>().unwrap_err();
+        assert!(e.type_name() == "Rgba" && e.component() == "g");
+        let e = rgba(0, 0, -1, 0).try_cast::<u8>().unwrap_err();
+        assert!(e.type_name() == "Rgba" && e.component() == "b");
+        let e = rgba(0, 0, 0, 1000).try_cast::<u8>().unwrap_err();
+        assert!(e.type_name() == "Rgba" && e.component() == "a");
+        assert!(rgba(1, 2, 3, 4).cast::<u8>().is_some());
+    }
+
+    #[test]
+    fn const_test() {
+        static PALETTE: [Rgba<u8>; 2] = [Rgba::new(0, 0, 0, 0xff), rgba(0xff, 0x80, 0, 0xff)];
+        assert!(PALETTE[0] == (0, 0, 0, 0xff));
+        assert!(PALETTE[1] == (0xff, 0x80, 0, 0xff));
+    }
+
+    #[test]
+    fn lerp_test() {
+        let a = rgba(0.1f32, 0.2, 0.3, 1.0);
+        let b = rgba(0.9f32, 0.6, 0.7, 0.0);
+        assert!(a.lerp(b, 0.0) == a && a.lerp(b, 1.0) == b);
+        let c = rgba(0.0f32, 0.0, 1.0, 1.0).lerp((1.0, 0.5, 0.0, 0.0), 0.5);
+        assert!(c == (0.5, 0.25, 0.5, 0.5));
+        assert!(a.lerp_clamped(b, 3.0) == b);
+    }
+
+    #[test]
+    fn left_mul_test() {
+        assert!(0.5f32 * rgba(1.0f32, 0.5, 0.0, 1.0) == rgba(1.0, 0.5, 0.0, 1.0) * 0.5);
+        assert!(2u8 * rgba(1u8, 2, 3, 4) == (2, 4, 6, 8));
+    }
+
+    #[test]
+    fn approx_eq_test() {
+        let a = rgba(0.5f32.sqrt().powi(2), 0.5, 0.5, 1.0);
+        let b = rgba(0.5f32, 0.5, 0.5, 1.0);
+        assert!(a != b);
+        assert!(a.approx_eq(&b, f32::EPSILON));
+        assert!(!a.approx_eq(&rgba(0.5, 0.5, 0.5, 0.9), f32::EPSILON));
+    }
+
     #[test]
     fn map_test() {
         assert!(rgba(1, 2, 3, 4).map(|x| x + 1) == rgba(2, 3, 4, 5));
@@ -278,6 +1177,456 @@ mod tests {
         assert!(Rgba::values(0x010203, 255u8) == (1, 2, 3, 255));
     }
 
+    #[test]
+    fn values_argb_test() {
+        assert!(Rgba::<u8>::values_argb(0x04010203) == (1, 2, 3, 4));
+        assert!(Rgba::<u8>::values_argb(0xff010203) == Rgba::values(0x010203, 255u8));
+        assert!(Rgba::<f32>::values_argb(0xff010203) == Rgba::values(0x010203, 1.0f32));
+        assert!(Rgba::<f64>::values_argb(0xff010203) == Rgba::values(0x010203, 1.0f64));
+        assert!(Rgba::<f32>::values_argb(0x00ff0000) == (1.0, 0.0, 0.0, 0.0));
+        assert!(Rgba::<f64>::values_argb(0x33000000).a == 0.2);
+    }
+
+    #[test]
+    fn default_test() {
+        assert!(Rgba::<u8>::default() == (0, 0, 0, 0));
+        assert!(Rgba::<f32>::default() == Rgba::transparent());
+    }
+
+    #[test]
+    fn opaque_test() {
+        assert!(Rgba::opaque(1u8, 2, 3) == (1, 2, 3, 255));
+        assert!(Rgba::opaque(0.1f32, 0.2, 0.3) == (0.1, 0.2, 0.3, 1.0));
+    }
+
+    #[test]
+    fn transparent_test() {
+        assert!(Rgba::<u8>::transparent() == (0, 0, 0, 0));
+        assert!(Rgba::<f32>::transparent() == (0.0, 0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn with_alpha_test() {
+        assert!(rgba(1u8, 2, 3, 4).with_alpha(128) == (1, 2, 3, 128));
+        assert!(rgba(0.1f32, 0.2, 0.3, 1.0).with_alpha(0.5) == (0.1, 0.2, 0.3, 0.5));
+    }
+
+    #[test]
+    fn as_array_test() {
+        let mut a = rgba(1, 2, 3, 4);
+        assert!(*a.as_array() == [1, 2, 3, 4]);
+        a.as_array_mut()[2] = 10;
+        assert!(a.b == 10);
+    }
+
+    #[test]
+    fn index_test() {
+        let mut a = rgba(1, 2, 3, 4);
+        assert!(a[0] == 1 && a[1] == 2 && a[2] == 3 && a[3] == 4);
+        a[3] = 10;
+        assert!(a.a == 10);
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_out_of_range_test() {
+        let a = rgba(1, 2, 3, 4);
+        let _ = a[4];
+    }
+
+    #[test]
+    fn into_iter_test() {
+        let a = rgba(1, 2, 3, 4);
+        let v = a.into_iter().collect::<Vec<_>>();
+        assert!(v == [1, 2, 3, 4]);
+        assert!(v == a.as_array());
+        assert!(a.into_iter().len() == 4);
+        assert!(a.iter().len() == 4);
+        assert!(a.iter().copied().collect::<Vec<_>>() == v);
+    }
+
+    #[test]
+    fn min_max_test() {
+        let a = rgba(1, 20, 3, 40);
+        let b = rgba(10, 2, 30, 4);
+        assert!(a.min(b) == (1, 2, 3, 4));
+        assert!(a.max(b) == (10, 20, 30, 40));
+        assert!(a.min((5, 5, 5, 5)) == (1, 5, 3, 5));
+    }
+
+    #[test]
+    fn clamp_test() {
+        let a = rgba(0.5f32, -0.5, 1.5, 1.0);
+        assert!(a.clamp((0.0, 0.0, 0.0, 0.0), (1.0, 1.0, 1.0, 1.0)) == (0.5, 0.0, 1.0, 1.0));
+        assert!(rgba(5, 5, 5, 5).clamp((0, 6, 0, 0), (10, 10, 4, 10)) == (5, 6, 4, 5));
+        assert!(rgba(5, 5, 5, 5).clamp((8, 0, 0, 0), (2, 10, 10, 10)) == (2, 5, 5, 5));
+    }
+
+    #[test]
+    fn from_hex_test() {
+        assert!(Rgba::from_hex("#ff8800cc") == Some(rgba(0xff, 0x88, 0x00, 0xcc)));
+        assert!(Rgba::from_hex("ff8800") == Some(rgba(0xff, 0x88, 0x00, 0xff)));
+        assert!(Rgba::from_hex("#f80") == Some(rgba(0xff, 0x88, 0x00, 0xff)));
+        assert!(Rgba::from_hex("#f80c") == Some(rgba(0xff, 0x88, 0x00, 0xcc)));
+        assert!(Rgba::from_hex("#ff880").is_none());
+        assert!(Rgba::from_hex("#gg8800").is_none());
+        assert!(Rgba::from_hex("#+f8800").is_none());
+        assert!(Rgba::from_hex("").is_none());
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn random_test() {
+        use rand::{rngs::StdRng, Rng, SeedableRng};
+
+        let a = StdRng::seed_from_u64(42).gen::<Rgba<u8>>();
+        let b = StdRng::seed_from_u64(42).gen::<Rgba<u8>>();
+        assert!(a == b);
+        assert!(a.a == 255);
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..100 {
+            let c = rng.gen::<Rgba<f32>>();
+            assert!(c.is_valid());
+            assert!(c.a == 1.0);
+        }
+    }
+
+    #[test]
+    fn average_test() {
+        let a = rgba(0.25f32, 0.5, 0.75, 0.5);
+        assert!(Rgba::<f32>::average(&[a, a, a]) == Some(a));
+        let red = rgba(1.0f32, 0.0, 0.0, 0.5);
+        let blue = rgba(0.0f32, 0.0, 1.0, 1.0);
+        let c = Rgba::<f32>::average(&[red, blue]).unwrap();
+        assert!(c.b > c.r);
+        assert!((c.r - 1.0 / 3.0).abs() < 1e-6 && (c.b - 2.0 / 3.0).abs() < 1e-6);
+        assert!(c.a == 0.75);
+        assert!(Rgba::<f64>::average(&[]).is_none());
+        let t = [rgba(1.0f64, 0.0, 0.0, 0.0), rgba(0.0, 0.0, 1.0, 0.0)];
+        assert!(Rgba::<f64>::average(&t) == Some(rgba(0.5, 0.0, 0.5, 0.0)));
+    }
+
+    #[test]
+    fn average_u8_test() {
+        let a = rgba(10u8, 20, 30, 40);
+        assert!(Rgba::<u8>::average(&[a, a, a]) == Some(a));
+        let red = rgba(255u8, 0, 0, 128);
+        let blue = rgba(0u8, 0, 255, 255);
+        assert!(Rgba::<u8>::average(&[red, blue]) == Some(rgba(85, 0, 170, 192)));
+        assert!(Rgba::<u8>::average(&[]).is_none());
+        let t = [rgba(255u8, 0, 0, 0), rgba(0, 0, 255, 0)];
+        assert!(Rgba::<u8>::average(&t) == Some(rgba(128, 0, 128, 0)));
+    }
+
+    #[test]
+    fn display_test() {
+        assert!(rgba(255u8, 136, 0, 204).to_string() == "rgba(255, 136, 0, 0.8)");
+        assert!(rgba(0u8, 0, 0, 255).to_string() == "rgba(0, 0, 0, 1)");
+        assert!(rgba(1u8, 2, 3, 128).to_string() == "rgba(1, 2, 3, 0.502)");
+        assert!(format!("{:#}", rgba(255u8, 136, 0, 204)) == "#ff8800cc");
+        assert!(rgba(1.0f32, 0.5, 0.0, 0.8).to_string() == "rgba(255, 128, 0, 0.8)");
+        assert!(rgba(0.2f64, 0.0, 1.5, 1.0).to_string() == "rgba(51, 0, 255, 1)");
+        assert!(format!("{:#}", rgba(1.0f32, 0.5, 0.0, 0.8)) == "#ff8000cc");
+    }
+
+    #[test]
+    fn names_sorted_test() {
+        assert!(names::NAMES.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn from_name_test() {
+        let table = [
+            ("black", 0x000000),
+            ("white", 0xffffff),
+            ("red", 0xff0000),
+            ("lime", 0x00ff00),
+            ("blue", 0x0000ff),
+            ("rebeccapurple", 0x663399),
+            ("cornflowerblue", 0x6495ed),
+            ("aliceblue", 0xf0f8ff),
+            ("yellowgreen", 0x9acd32),
+            ("gray", 0x808080),
+            ("grey", 0x808080),
+            ("lightgoldenrodyellow", 0xfafad2),
+        ];
+        for (name, value) in table {
+            assert!(Rgba::from_name(name) == Some(Rgba::values(value, 0xff)));
+        }
+        assert!(Rgba::from_name("RebeccaPurple") == Some(rgba(0x66, 0x33, 0x99, 0xff)));
+        assert!(Rgba::from_name("CORNFLOWERBLUE") == Some(rgba(0x64, 0x95, 0xed, 0xff)));
+        assert!(Rgba::from_name("notacolor").is_none());
+    }
+
+    #[test]
+    fn from_str_test() {
+        assert!("#ff8800".parse::<Rgba<u8>>() == Ok(rgba(0xff, 0x88, 0x00, 0xff)));
+        assert!(" Tomato ".parse::<Rgba<u8>>() == Ok(rgba(0xff, 0x63, 0x47, 0xff)));
+        assert!("#abc".parse::<Rgba<u8>>() == Ok(rgba(0xaa, 0xbb, 0xcc, 0xff)));
+        assert!("#ff880080".parse::<Rgba<u8>>() == Ok(rgba(0xff, 0x88, 0x00, 0x80)));
+        let e = "notacolor".parse::<Rgba<u8>>().unwrap_err();
+        assert!(e.kind() == ParseErrorKind::Invalid && e.component().is_none());
+        assert!(e.to_string() == "invalid Rgba");
+        let e = "#f".parse::<Rgba<u8>>().unwrap_err();
+        assert!(e.kind() == ParseErrorKind::Missing && e.component() == Some("g"));
+        let e = "#".parse::<Rgba<u8>>().unwrap_err();
+        assert!(e.kind() == ParseErrorKind::Missing && e.component() == Some("r"));
+        let e = "#ff8x00".parse::<Rgba<u8>>().unwrap_err();
+        assert!(e.kind() == ParseErrorKind::Invalid && e.component() == Some("g"));
+        assert!(e.type_name() == "Rgba");
+        let e = "#ff880".parse::<Rgba<u8>>().unwrap_err();
+        assert!(e.kind() == ParseErrorKind::Invalid && e.component() == Some("b"));
+        let e = "#ff88000".parse::<Rgba<u8>>().unwrap_err();
+        assert!(e.kind() == ParseErrorKind::Invalid && e.component() == Some("a"));
+        let e = "#+f8800".parse::<Rgba<u8>>().unwrap_err();
+        assert!(e.kind() == ParseErrorKind::Invalid && e.component() == Some("r"));
+        let e = "#ff88000000".parse::<Rgba<u8>>().unwrap_err();
+        assert!(e.kind() == ParseErrorKind::Trailing);
+        assert!("ff8800".parse::<Rgba<u8>>().is_err());
+    }
+
+    #[test]
+    #[allow(clippy::unusual_byte_groupings)]
+    fn rgb565_test() {
+        assert!(Rgba::from_rgb565(0xffff) == (255, 255, 255, 255));
+        assert!(Rgba::from_rgb565(0) == (0, 0, 0, 255));
+        assert!(Rgba::from_rgb565(0b11111_000000_00000) == (255, 0, 0, 255));
+        assert!(Rgba::from_rgb565(0b00000_111111_00000) == (0, 255, 0, 255));
+        assert!(Rgba::from_rgb565(0b00000_000000_11111) == (0, 0, 255, 255));
+        assert!(Rgba::from_rgb565(0b10000_100000_10000) == (132, 130, 132, 255));
+        assert!(rgba(255u8, 255, 255, 0).to_rgb565() == 0xffff);
+        assert!(rgba(0x84u8, 0x82, 0x84, 0).to_rgb565() == 0b10000_100000_10000);
+        for v in 0..=u16::MAX {
+            assert!(Rgba::from_rgb565(v).to_rgb565() == v);
+        }
+    }
+
+    #[test]
+    fn rgba4444_test() {
+        assert!(Rgba::from_rgba4444(0xf00f) == (255, 0, 0, 255));
+        assert!(Rgba::from_rgba4444(0x1234) == (0x11, 0x22, 0x33, 0x44));
+        assert!(rgba(0x1fu8, 0x2f, 0x3f, 0x4f).to_rgba4444() == 0x1234);
+        for v in 0..=u16::MAX {
+            assert!(Rgba::from_rgba4444(v).to_rgba4444() == v);
+        }
+    }
+
+    #[test]
+    fn argb1555_test() {
+        assert!(Rgba::from_argb1555(0xffff) == (255, 255, 255, 255));
+        assert!(Rgba::from_argb1555(0x7c00) == (255, 0, 0, 0));
+        assert!(Rgba::from_argb1555(0x801f) == (0, 0, 255, 255));
+        assert!(rgba(255u8, 0, 0, 127).to_argb1555() == 0x7c00);
+        assert!(rgba(255u8, 0, 0, 128).to_argb1555() == 0xfc00);
+        for v in 0..=u16::MAX {
+            assert!(Rgba::from_argb1555(v).to_argb1555() == v);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn to_hex_test() {
+        assert!(rgba(0xff, 0x88, 0x00, 0xcc).to_hex() == "#ff8800cc");
+        let c = rgba(0x12, 0x34, 0x56, 0x78);
+        assert!(c.to_hex().parse::<Rgba<u8>>() == Ok(c));
+    }
+
+    #[cfg(all(feature = "serde", feature = "alloc"))]
+    #[test]
+    fn serde_hex_test() {
+        #[derive(serde::Serialize, serde::Deserialize)]
+        struct Config {
+            #[serde(with = "serde_hex")]
+            tint: Rgba<u8>,
+        }
+
+        let c = Config {
+            tint: rgba(0xff, 0x88, 0x00, 0xcc),
+        };
+        assert!(serde_json::to_string(&c).unwrap() == r##"{"tint":"#ff8800cc"}"##);
+        let c: Config = serde_json::from_str(r##"{"tint":"#ff8800cc"}"##).unwrap();
+        assert!(c.tint == (0xff, 0x88, 0x00, 0xcc));
+        let c: Config = serde_json::from_str(r##"{"tint":"#f80"}"##).unwrap();
+        assert!(c.tint == (0xff, 0x88, 0x00, 0xff));
+        let c: Config = serde_json::from_str(r#"{"tint":{"r":1,"g":2,"b":3,"a":4}}"#).unwrap();
+        assert!(c.tint == (1, 2, 3, 4));
+        assert!(serde_json::from_str::<Config>(r#"{"tint":"orange"}"#).is_err());
+    }
+
+    #[test]
+    fn modulate_test() {
+        let a = rgba(0.2f32, 0.4, 0.6, 0.8);
+        assert!(a.modulate((1.0, 1.0, 1.0, 1.0)) == a);
+        assert!(a.modulate((0.0, 0.0, 0.0, 0.0)) == (0.0, 0.0, 0.0, 0.0));
+        assert!(a.modulate((0.5, 0.5, 0.5, 0.5)) == (0.1, 0.2, 0.3, 0.4));
+        let a = rgba(12u8, 34, 200, 255);
+        assert!(a.modulate((255, 255, 255, 255)) == a);
+        assert!(a.modulate((0, 0, 0, 0)) == (0, 0, 0, 0));
+        assert!(rgba(128u8, 128, 255, 1).modulate((128, 127, 1, 128)) == (64, 64, 1, 1));
+        assert!(rgba(100u8, 200, 3, 10).modulate((100, 200, 85, 13)) == (39, 157, 1, 1));
+    }
+
+    #[test]
+    fn relative_luminance_test() {
+        assert!(rgba(0u8, 0, 0, 255).relative_luminance() == 0.0);
+        assert!((rgba(255u8, 255, 255, 255).relative_luminance() - 1.0).abs() < 1e-6);
+        assert!((rgba(1.0f64, 0.0, 0.0, 1.0).relative_luminance() - 0.2126).abs() < 1e-12);
+    }
+
+    #[test]
+    fn contrast_ratio_test() {
+        let black = rgba(0.0f32, 0.0, 0.0, 1.0);
+        let white = rgba(1.0f32, 1.0, 1.0, 1.0);
+        assert!((contrast_ratio(black, white) - 21.0).abs() < 1e-4);
+        assert!((contrast_ratio(white, black) - 21.0).abs() < 1e-4);
+        assert!(contrast_ratio(white, white) == 1.0);
+        let gray = rgba(0x76u8, 0x76, 0x76, 0xff).to_f32();
+        assert!((contrast_ratio(gray, white) - 4.54).abs() < 0.01);
+    }
+
+    #[test]
+    fn clamp_valid_test() {
+        let a = rgba(-0.5f32, 1.5, 0.5, 2.0);
+        assert!(!a.is_valid());
+        assert!(a.clamp_valid() == (0.0, 1.0, 0.5, 1.0));
+        assert!(a.clamp_valid().is_valid());
+        let a = rgba(f32::NAN, 0.5, 0.5, 0.5);
+        assert!(!a.is_valid());
+        assert!(a.clamp_valid() == (0.0, 0.5, 0.5, 0.5));
+        let a = rgba(0u8, 128, 255, 3);
+        assert!(a.is_valid());
+        assert!(a.clamp_valid() == a);
+    }
+
+    #[test]
+    fn distance_test() {
+        let black = rgba(0.0f32, 0.0, 0.0, 1.0);
+        let blue = rgba(0.0f32, 0.0, 0.5, 1.0);
+        let red = rgba(0.55f32, 0.0, 0.0, 0.0);
+        assert!(black.distance_squared_rgb(blue) < black.distance_squared_rgb(red));
+        assert!(black.distance(blue) > black.distance(red));
+        assert!(black.distance(black) == 0.0);
+        assert!(black.distance(blue) == f32::sqrt(3.0 * 0.25));
+        let white = rgba(1.0f64, 1.0, 1.0, 1.0);
+        let a = rgba(1.0f64, 1.0, 0.5, 1.0);
+        let b = rgba(0.55f64, 1.0, 1.0, 1.0);
+        assert!(white.distance_squared_rgb(a) > white.distance_squared_rgb(b));
+        assert!(white.distance(a) < white.distance(b));
+        let c = rgba(1.0f64, 0.55, 1.0, 1.0);
+        assert!(white.distance_squared_rgb(b) == white.distance_squared_rgb(c));
+        assert!(white.distance(b) < white.distance(c));
+    }
+
+    #[test]
+    fn distance_u8_test() {
+        let black = rgba(0u8, 0, 0, 255);
+        assert!(black.distance_squared_rgb((0, 0, 128, 0)) == 128 * 128);
+        assert!(black.distance_squared_rgb((255, 255, 255, 0)) == 3 * 255 * 255);
+        assert!(black.distance((0, 0, 128, 255)) > black.distance((140, 0, 0, 255)));
+        assert!(
+            black.distance_squared_rgb((0, 0, 128, 255))
+                < black.distance_squared_rgb((140, 0, 0, 255))
+        );
+    }
+
+    #[test]
+    fn to_float_test() {
+        assert!(rgba(0u8, 255, 51, 255).to_f32() == (0.0, 1.0, 0.2, 1.0));
+        assert!(rgba(0u8, 255, 51, 255).to_f64() == (0.0, 1.0, 0.2, 1.0));
+    }
+
+    #[test]
+    fn to_u8_test() {
+        assert!(rgba(0.0f32, 1.0, 0.2, 1.0).to_u8() == (0, 255, 51, 255));
+        assert!(rgba(-0.5f32, 1.5, -10.0, 10.0).to_u8() == (0, 255, 0, 255));
+        assert!(
+            rgba(0.5f32 / 255.0, 1.49 / 255.0, 1.51 / 255.0, 254.5 / 255.0).to_u8()
+                == (1, 1, 2, 255)
+        );
+        assert!(rgba(0.0f64, 1.0, 0.2, -1.0).to_u8() == (0, 255, 51, 0));
+        let a = rgba(12u8, 34, 56, 78);
+        assert!(a.to_f32().to_u8() == a);
+        assert!(a.to_f64().to_u8() == a);
+    }
+
+    #[test]
+    fn invert_test() {
+        let a = rgba(0u8, 100, 255, 128);
+        assert!(a.invert() == (255, 155, 0, 128));
+        assert!(a.invert().invert() == a);
+        let a = rgba(0.0f32, 0.25, 1.0, 0.5);
+        assert!(a.invert() == (1.0, 0.75, 0.0, 0.5));
+        assert!(a.invert().invert() == a);
+    }
+
+    #[test]
+    fn gamma_test() {
+        let a = rgba(0.25f32, 0.5, 1.0, 0.3);
+        assert!(a.gamma(1.0) == a);
+        assert!(a.gamma(2.0) == (0.5, 0.70710677, 1.0, 0.3));
+        assert!(a.gamma(0.5) == (0.0625, 0.25, 1.0, 0.3));
+    }
+
+    #[test]
+    fn brighten_darken_test() {
+        let a = rgba(0.25f32, 0.5, 0.75, 0.3);
+        assert!(a.brighten(0.5) == (0.375, 0.75, 1.0, 0.3));
+        assert!(a.brighten(0.0) == a);
+        assert!(a.darken(0.5) == (0.125, 0.25, 0.375, 0.3));
+        assert!(a.darken(2.0) == (0.0, 0.0, 0.0, 0.3));
+    }
+
+    #[test]
+    fn saturating_add_test() {
+        let a = rgba(200u8, 200, 200, 255);
+        assert!(a.saturating_add(rgba(100, 0, 0, 0)) == (255, 200, 200, 255));
+        assert!(a.saturating_add((0, 100, 0, 0)) == (200, 255, 200, 255));
+        assert!(a.saturating_add((0, 0, 100, 0)) == (200, 200, 255, 255));
+        assert!(a.saturating_add((0, 0, 0, 1)) == (200, 200, 200, 255));
+        assert!(a.saturating_add((10, 20, 30, 0)) == (210, 220, 230, 255));
+    }
+
+    #[test]
+    fn saturating_sub_test() {
+        let a = rgba(10u8, 20, 30, 40);
+        assert!(a.saturating_sub((11, 0, 0, 0)) == (0, 20, 30, 40));
+        assert!(a.saturating_sub((0, 21, 0, 0)) == (10, 0, 30, 40));
+        assert!(a.saturating_sub((0, 0, 31, 0)) == (10, 20, 0, 40));
+        assert!(a.saturating_sub((0, 0, 0, 41)) == (10, 20, 30, 0));
+        assert!(a.saturating_sub((1, 2, 3, 4)) == (9, 18, 27, 36));
+    }
+
+    #[test]
+    fn saturating_mul_scalar_test() {
+        assert!(rgba(10u8, 100, 200, 255).saturating_mul_scalar(2) == (20, 200, 255, 255));
+    }
+
+    #[test]
+    fn checked_add_test() {
+        let a = rgba(200u8, 200, 200, 200);
+        assert!(a.checked_add((55, 55, 55, 55)) == Some(rgba(255, 255, 255, 255)));
+        assert!(a.checked_add((56, 0, 0, 0)).is_none());
+        assert!(a.checked_add((0, 56, 0, 0)).is_none());
+        assert!(a.checked_add((0, 0, 56, 0)).is_none());
+        assert!(a.checked_add((0, 0, 0, 56)).is_none());
+    }
+
+    #[test]
+    fn checked_sub_test() {
+        let a = rgba(10u8, 10, 10, 10);
+        assert!(a.checked_sub((10, 10, 10, 10)) == Some(rgba(0, 0, 0, 0)));
+        assert!(a.checked_sub((11, 0, 0, 0)).is_none());
+        assert!(a.checked_sub((0, 11, 0, 0)).is_none());
+        assert!(a.checked_sub((0, 0, 11, 0)).is_none());
+        assert!(a.checked_sub((0, 0, 0, 11)).is_none());
+    }
+
+    #[test]
+    fn checked_mul_scalar_test() {
+        assert!(rgba(1u8, 2, 3, 127).checked_mul_scalar(2) == Some(rgba(2, 4, 6, 254)));
+        assert!(rgba(1u8, 2, 3, 128).checked_mul_scalar(2).is_none());
+    }
+
     #[test]
     fn add_test() {
         let a = rgba(1, 2, 3, 4);
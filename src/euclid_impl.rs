@@ -0,0 +1,100 @@
+use crate::*;
+
+impl<T, U> From<euclid::Point2D<T, U>> for Point<T> {
+    #[inline]
+    fn from(src: euclid::Point2D<T, U>) -> Self {
+        Point::new(src.x, src.y)
+    }
+}
+
+impl<T, U> From<Point<T>> for euclid::Point2D<T, U> {
+    #[inline]
+    fn from(src: Point<T>) -> Self {
+        euclid::Point2D::new(src.x, src.y)
+    }
+}
+
+impl<T, U> From<euclid::Vector2D<T, U>> for Vector<T> {
+    #[inline]
+    fn from(src: euclid::Vector2D<T, U>) -> Self {
+        Vector::new(src.x, src.y)
+    }
+}
+
+impl<T, U> From<Vector<T>> for euclid::Vector2D<T, U> {
+    #[inline]
+    fn from(src: Vector<T>) -> Self {
+        euclid::Vector2D::new(src.x, src.y)
+    }
+}
+
+impl<T, U> From<euclid::Size2D<T, U>> for Size<T> {
+    #[inline]
+    fn from(src: euclid::Size2D<T, U>) -> Self {
+        Size::new(src.width, src.height)
+    }
+}
+
+impl<T, U> From<Size<T>> for euclid::Size2D<T, U> {
+    #[inline]
+    fn from(src: Size<T>) -> Self {
+        euclid::Size2D::new(src.width, src.height)
+    }
+}
+
+/// Both types are an origin and a size, so the conversion is lossless.
+impl<T, U> From<euclid::Rect<T, U>> for Rect<T> {
+    #[inline]
+    fn from(src: euclid::Rect<T, U>) -> Self {
+        Rect::new(src.origin, src.size)
+    }
+}
+
+/// Both types are an origin and a size, so the conversion is lossless.
+impl<T, U> From<Rect<T>> for euclid::Rect<T, U> {
+    #[inline]
+    fn from(src: Rect<T>) -> Self {
+        euclid::Rect::new(src.origin.into(), src.size.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use euclid::{default, UnknownUnit};
+
+    struct WorldSpace;
+
+    #[test]
+    fn point_test() {
+        let p = euclid::Point2D::<i32, WorldSpace>::from(point(1, 2));
+        assert!(p.x == 1 && p.y == 2);
+        assert!(Point::from(p) == (1, 2));
+        let p: default::Point2D<f32> = point(1.5, 2.5).into();
+        assert!(Point::from(p) == (1.5, 2.5));
+    }
+
+    #[test]
+    fn vector_test() {
+        let v = euclid::Vector2D::<i32, WorldSpace>::from(vector(-1, 2));
+        assert!(v.x == -1 && v.y == 2);
+        assert!(Vector::from(v) == (-1, 2));
+    }
+
+    #[test]
+    fn size_test() {
+        let s = euclid::Size2D::<u32, UnknownUnit>::from(size(3, 4));
+        assert!(s.width == 3 && s.height == 4);
+        assert!(Size::from(s) == (3, 4));
+    }
+
+    #[test]
+    fn rect_test() {
+        let rc = rect((-10, 20), (30, 40));
+        let e = euclid::Rect::<i32, WorldSpace>::from(rc);
+        assert!(e.origin.x == -10 && e.origin.y == 20);
+        assert!(e.size.width == 30 && e.size.height == 40);
+        assert!(e.max_x() == rc.endpoint().x && e.max_y() == rc.endpoint().y);
+        assert!(Rect::from(e) == rc);
+    }
+}
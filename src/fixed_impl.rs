@@ -0,0 +1,31 @@
+use crate::*;
+use fixed::types::extra::{LeEqU128, LeEqU16, LeEqU32, LeEqU64, LeEqU8};
+use fixed::*;
+
+macro_rules! impl_widen_fixed {
+    ($($t:ident<$le:ident> => $wide:ident<$wide_le:ident>, $bits:ty);*) => {
+        $(
+            impl<Frac: $le + $wide_le> Widen for $t<Frac> {
+                type Wide = $wide<Frac>;
+
+                #[inline]
+                fn widen(self) -> $wide<Frac> {
+                    $wide::from_bits(self.to_bits() as $bits)
+                }
+            }
+        )*
+    };
+}
+
+impl_widen_fixed!(
+    FixedI8<LeEqU8> => FixedI16<LeEqU16>, i16;
+    FixedI16<LeEqU16> => FixedI32<LeEqU32>, i32;
+    FixedI32<LeEqU32> => FixedI64<LeEqU64>, i64;
+    FixedI64<LeEqU64> => FixedI128<LeEqU128>, i128;
+    FixedI128<LeEqU128> => FixedI128<LeEqU128>, i128;
+    FixedU8<LeEqU8> => FixedU16<LeEqU16>, u16;
+    FixedU16<LeEqU16> => FixedU32<LeEqU32>, u32;
+    FixedU32<LeEqU32> => FixedU64<LeEqU64>, u64;
+    FixedU64<LeEqU64> => FixedU128<LeEqU128>, u128;
+    FixedU128<LeEqU128> => FixedU128<LeEqU128>, u128
+);
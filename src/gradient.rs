@@ -0,0 +1,133 @@
+use crate::*;
+use alloc::vec::Vec;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum GradientError {
+    Empty,
+    Unsorted,
+}
+
+impl core::fmt::Display for GradientError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "a gradient needs at least one stop"),
+            Self::Unsorted => write!(f, "gradient stops must be sorted by position"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for GradientError {}
+
+/// A color gradient defined by `(position, color)` stops.
+///
+/// Stops must be sorted by position. Two stops may share a position to make a hard edge.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Gradient<T> {
+    stops: Vec<(T, Rgba<T>)>,
+}
+
+impl<T: Float> Gradient<T> {
+    pub fn new(stops: Vec<(T, Rgba<T>)>) -> Result<Self, GradientError> {
+        if stops.is_empty() {
+            return Err(GradientError::Empty);
+        }
+        if stops.iter().any(|s| s.0.is_nan()) || stops.windows(2).any(|w| w[0].0 > w[1].0) {
+            return Err(GradientError::Unsorted);
+        }
+        Ok(Self { stops })
+    }
+
+    #[inline]
+    pub fn stops(&self) -> &[(T, Rgba<T>)] {
+        &self.stops
+    }
+
+    /// Returns the color at `t`, clamped to the first and the last stops.
+    pub fn sample(&self, t: T) -> Rgba<T> {
+        let i = self.stops.partition_point(|s| s.0 <= t);
+        if i == 0 {
+            return self.stops[0].1;
+        }
+        if i == self.stops.len() {
+            return self.stops[i - 1].1;
+        }
+        let (p0, c0) = self.stops[i - 1];
+        let (p1, c1) = self.stops[i];
+        let u = (t - p0) / (p1 - p0);
+        c0 + (c1 - c0) * u
+    }
+
+    /// Returns `n` samples evenly spaced from the first stop to the last stop.
+    pub fn sample_iter(&self, n: usize) -> impl Iterator<Item = Rgba<T>> + '_ {
+        let first = self.stops[0].0;
+        let last = self.stops[self.stops.len() - 1].0;
+        let d = if n > 1 {
+            (last - first) / T::from(n - 1).unwrap()
+        } else {
+            T::zero()
+        };
+        (0..n).map(move |i| self.sample(first + d * T::from(i).unwrap()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_test() {
+        assert!(Gradient::<f32>::new(vec![]) == Err(GradientError::Empty));
+        let a = rgba(0.0, 0.0, 0.0, 1.0);
+        assert!(Gradient::new(vec![(1.0, a), (0.0, a)]) == Err(GradientError::Unsorted));
+        assert!(Gradient::new(vec![(f32::NAN, a)]) == Err(GradientError::Unsorted));
+        assert!(Gradient::new(vec![(0.0, a), (0.5, a), (0.5, a), (1.0, a)]).is_ok());
+    }
+
+    #[test]
+    fn two_stops_test() {
+        let a = rgba(0.0f32, 0.0, 0.0, 1.0);
+        let b = rgba(1.0f32, 0.5, 0.25, 0.0);
+        let g = Gradient::new(vec![(0.0, a), (1.0, b)]).unwrap();
+        assert!(g.sample(0.0) == a);
+        assert!(g.sample(1.0) == b);
+        assert!(g.sample(0.5) == a + (b - a) * 0.5);
+        assert!(g.sample(0.25) == a + (b - a) * 0.25);
+    }
+
+    #[test]
+    fn three_stops_test() {
+        let a = rgba(1.0f32, 0.0, 0.0, 1.0);
+        let b = rgba(0.0f32, 1.0, 0.0, 1.0);
+        let c = rgba(0.0f32, 0.0, 1.0, 1.0);
+        let g = Gradient::new(vec![(0.0, a), (0.5, b), (1.0, c)]).unwrap();
+        assert!(g.sample(0.0) == a);
+        assert!(g.sample(0.5) == b);
+        assert!(g.sample(1.0) == c);
+        assert!(g.sample(0.25) == (0.5, 0.5, 0.0, 1.0));
+        assert!(g.sample(0.75) == (0.0, 0.5, 0.5, 1.0));
+    }
+
+    #[test]
+    fn clamp_test() {
+        let a = rgba(1.0f32, 0.0, 0.0, 1.0);
+        let b = rgba(0.0f32, 1.0, 0.0, 1.0);
+        let g = Gradient::new(vec![(0.0, a), (1.0, b)]).unwrap();
+        assert!(g.sample(-1.0) == a);
+        assert!(g.sample(2.0) == b);
+    }
+
+    #[test]
+    fn sample_iter_test() {
+        let a = rgba(0.0f32, 0.0, 0.0, 1.0);
+        let b = rgba(1.0f32, 1.0, 1.0, 1.0);
+        let g = Gradient::new(vec![(0.0, a), (1.0, b)]).unwrap();
+        let v = g.sample_iter(5).collect::<Vec<_>>();
+        assert!(v.len() == 5);
+        assert!(v[0] == a);
+        assert!(v[2] == (0.5, 0.5, 0.5, 1.0));
+        assert!(v[4] == b);
+        assert!(g.sample_iter(1).collect::<Vec<_>>() == [a]);
+        assert!(g.sample_iter(0).count() == 0);
+    }
+}
@@ -0,0 +1,122 @@
+use crate::*;
+use color::Values;
+use half::f16;
+
+impl Values for f16 {
+    #[inline]
+    fn values(value: u32, alpha: f16) -> Rgba<f16> {
+        let c = <f32 as Values>::values(value, 0.0);
+        Rgba::new(
+            f16::from_f32(c.r),
+            f16::from_f32(c.g),
+            f16::from_f32(c.b),
+            alpha,
+        )
+    }
+
+    #[inline]
+    fn values_argb(value: u32) -> Rgba<f16> {
+        <f32 as Values>::values_argb(value).to_f16()
+    }
+}
+
+impl Widen for f16 {
+    type Wide = f16;
+
+    #[inline]
+    fn widen(self) -> f16 {
+        self
+    }
+}
+
+impl Rgba<f32> {
+    /// Narrows each channel to `f16`, rounding to the nearest representable value.
+    #[inline]
+    pub fn to_f16(self) -> Rgba<f16> {
+        self.map(f16::from_f32)
+    }
+
+    #[inline]
+    pub fn from_f16(src: Rgba<f16>) -> Self {
+        src.map(f16::to_f32)
+    }
+}
+
+impl Vector<f32> {
+    /// Narrows each component to `f16`, rounding to the nearest representable value.
+    #[inline]
+    pub fn to_f16(self) -> Vector<f16> {
+        self.map(f16::from_f32)
+    }
+
+    #[inline]
+    pub fn from_f16(src: Vector<f16>) -> Self {
+        src.map(f16::to_f32)
+    }
+}
+
+impl Point<f32> {
+    /// Narrows each component to `f16`, rounding to the nearest representable value.
+    #[inline]
+    pub fn to_f16(self) -> Point<f16> {
+        self.map(f16::from_f32)
+    }
+
+    #[inline]
+    pub fn from_f16(src: Point<f16>) -> Self {
+        src.map(f16::to_f32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn values_test() {
+        let c = Rgba::<f16>::values(0xff8000, f16::ONE);
+        assert!(c == (f16::ONE, f16::from_f32(128.0 / 255.0), f16::ZERO, f16::ONE));
+        let c = Rgba::<f16>::values_argb(0x80ff0000);
+        assert!(c.r == f16::ONE && c.a == f16::from_f32(128.0 / 255.0));
+    }
+
+    #[test]
+    fn rgba_test() {
+        let a = rgba(1.0f32, 0.5, 0.25, 0.0);
+        assert!(Rgba::from_f16(a.to_f16()) == a);
+        let a = rgba(0.1f32, 1.0 / 3.0, 0.0, 1.0);
+        let b = Rgba::from_f16(a.to_f16());
+        assert!(b != a);
+        assert!((b.r - a.r).abs() < 1e-3 && (b.g - a.g).abs() < 1e-3);
+        assert!(b.b == 0.0 && b.a == 1.0);
+    }
+
+    #[test]
+    fn vector_point_test() {
+        let v = vector(2049.0f32, -0.5);
+        assert!(Vector::from_f16(v.to_f16()) == (2048.0, -0.5));
+        let v = vector(65520.0f32, 1e-8);
+        assert!(v.to_f16() == (f16::INFINITY, f16::ZERO));
+        let p = point(-3.0f32, 1023.5);
+        assert!(Point::from_f16(p.to_f16()) == (-3.0, 1023.5));
+        assert!(Point::from_f16(point(1024.5f32, 1025.5).to_f16()) == (1024.0, 1026.0));
+        assert!(Point::from_f16(point(0.1f32, 0.0).to_f16()) == (0.099975586, 0.0));
+    }
+
+    #[test]
+    fn cast_test() {
+        let a = rgba(1.0f32, 0.5, 0.25, 0.0).cast::<f16>().unwrap();
+        assert!(a == rgba(1.0f32, 0.5, 0.25, 0.0).to_f16());
+        assert!(a.cast::<f32>() == Some(rgba(1.0, 0.5, 0.25, 0.0)));
+        assert!(vector(3.0f32, 4.0).cast::<f16>().unwrap().cast::<i32>() == Some(vector(3, 4)));
+    }
+
+    #[test]
+    fn repr_test() {
+        assert!(core::mem::size_of::<Rgba<f16>>() == 8);
+        assert!(core::mem::align_of::<Rgba<f16>>() == 2);
+        assert!(core::mem::size_of::<Vector<f16>>() == 4);
+        assert!(core::mem::size_of::<Point<f16>>() == 4);
+        assert!(core::mem::size_of::<[Rgba<f16>; 3]>() == 24);
+    }
+}
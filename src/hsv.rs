@@ -0,0 +1,207 @@
+use crate::*;
+
+/// A color in the HSV color space with alpha.
+///
+/// `h` is the hue in degrees in `0.0..360.0`, `s`, `v` and `a` are in `0.0..=1.0`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Hsva<T> {
+    pub h: T,
+    pub s: T,
+    pub v: T,
+    pub a: T,
+}
+
+impl<T> Hsva<T> {
+    #[inline]
+    pub const fn new(h: T, s: T, v: T, a: T) -> Self {
+        Self { h, s, v, a }
+    }
+}
+
+impl<T: Float> Hsva<T> {
+    pub fn from_rgba(src: Rgba<T>) -> Self {
+        let max = src.r.max(src.g).max(src.b);
+        let min = src.r.min(src.g).min(src.b);
+        let d = max - min;
+        let s = if max == T::zero() { T::zero() } else { d / max };
+        let h = if d == T::zero() {
+            T::zero()
+        } else if max == src.r {
+            (src.g - src.b) / d
+        } else if max == src.g {
+            (src.b - src.r) / d + T::from(2.0).unwrap()
+        } else {
+            (src.r - src.g) / d + T::from(4.0).unwrap()
+        };
+        Self::new(normalize_hue(h * T::from(60.0).unwrap()), s, max, src.a)
+    }
+
+    pub fn to_rgba(self) -> Rgba<T> {
+        let c = self.v * self.s;
+        let h = normalize_hue(self.h) / T::from(60.0).unwrap();
+        let x = c * (T::one() - (h % T::from(2.0).unwrap() - T::one()).abs());
+        let m = self.v - c;
+        let z = T::zero();
+        let (r, g, b) = match h.to_u8().unwrap_or(0) {
+            0 => (c, x, z),
+            1 => (x, c, z),
+            2 => (z, c, x),
+            3 => (z, x, c),
+            4 => (x, z, c),
+            _ => (c, z, x),
+        };
+        Rgba::new(r + m, g + m, b + m, self.a)
+    }
+}
+
+#[inline]
+fn normalize_hue<T: Float>(h: T) -> T {
+    let full = T::from(360.0).unwrap();
+    let h = h % full;
+    if h < T::zero() {
+        h + full
+    } else {
+        h
+    }
+}
+
+impl<T: Float> From<Rgba<T>> for Hsva<T> {
+    #[inline]
+    fn from(src: Rgba<T>) -> Self {
+        Self::from_rgba(src)
+    }
+}
+
+impl<T: Float> From<Hsva<T>> for Rgba<T> {
+    #[inline]
+    fn from(src: Hsva<T>) -> Self {
+        src.to_rgba()
+    }
+}
+
+impl<T: Float> Rgba<T> {
+    #[inline]
+    pub fn to_hsva(self) -> Hsva<T> {
+        Hsva::from_rgba(self)
+    }
+
+    /// Rotates the hue by `degrees` through HSV. Gray colors are returned unchanged.
+    #[inline]
+    pub fn rotate_hue(self, degrees: T) -> Self {
+        let mut hsva = self.to_hsva();
+        if hsva.s == T::zero() {
+            return self;
+        }
+        hsva.h = normalize_hue(hsva.h + degrees);
+        hsva.to_rgba()
+    }
+
+    /// Multiplies the HSV saturation by `1 + factor`, clamped to `0.0..=1.0`.
+    #[inline]
+    pub fn saturate(self, factor: T) -> Self {
+        self.scale_saturation(T::one() + factor)
+    }
+
+    /// Multiplies the HSV saturation by `1 - factor`, clamped to `0.0..=1.0`.
+    #[inline]
+    pub fn desaturate(self, factor: T) -> Self {
+        self.scale_saturation(T::one() - factor)
+    }
+
+    #[inline]
+    fn scale_saturation(self, f: T) -> Self {
+        let mut hsva = self.to_hsva();
+        hsva.s = (hsva.s * f).max(T::zero()).min(T::one());
+        hsva.to_rgba()
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<T: Float> Rgba<T> {
+    /// Returns an opaque color of a uniformly random hue with full saturation and value.
+    #[inline]
+    pub fn random_hue<R: rand::Rng + ?Sized>(rng: &mut R) -> Self {
+        let h = T::from(rng.gen_range(0.0..360.0)).unwrap();
+        Hsva::new(h, T::one(), T::one(), T::one()).to_rgba()
+    }
+}
+
+#[inline]
+pub const fn hsva<T>(h: T, s: T, v: T, a: T) -> Hsva<T> {
+    Hsva::new(h, s, v, a)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn const_test() {
+        const A: Hsva<f32> = hsva(120.0, 1.0, 1.0, 1.0);
+        assert!(A == Hsva::new(120.0, 1.0, 1.0, 1.0));
+    }
+
+    fn near(a: Rgba<f32>, b: Rgba<f32>) -> bool {
+        let d = a - b;
+        d.r.abs() < 1e-5 && d.g.abs() < 1e-5 && d.b.abs() < 1e-5 && d.a.abs() < 1e-5
+    }
+
+    #[test]
+    fn from_rgba_test() {
+        assert!(Hsva::from_rgba(rgba(1.0f32, 0.0, 0.0, 1.0)) == hsva(0.0, 1.0, 1.0, 1.0));
+        assert!(Hsva::from_rgba(rgba(0.0f32, 1.0, 0.0, 1.0)) == hsva(120.0, 1.0, 1.0, 1.0));
+        assert!(Hsva::from_rgba(rgba(0.0f32, 0.0, 1.0, 0.5)) == hsva(240.0, 1.0, 1.0, 0.5));
+        assert!(Hsva::from_rgba(rgba(1.0f32, 0.0, 1.0, 1.0)) == hsva(300.0, 1.0, 1.0, 1.0));
+        assert!(Hsva::from_rgba(rgba(0.5f32, 0.5, 0.5, 1.0)) == hsva(0.0, 0.0, 0.5, 1.0));
+    }
+
+    #[test]
+    fn to_rgba_test() {
+        assert!(hsva(0.0f32, 1.0, 1.0, 1.0).to_rgba() == (1.0, 0.0, 0.0, 1.0));
+        assert!(hsva(120.0f32, 1.0, 1.0, 1.0).to_rgba() == (0.0, 1.0, 0.0, 1.0));
+        assert!(hsva(240.0f32, 1.0, 1.0, 1.0).to_rgba() == (0.0, 0.0, 1.0, 1.0));
+        assert!(hsva(360.0f32, 1.0, 1.0, 1.0).to_rgba() == (1.0, 0.0, 0.0, 1.0));
+        assert!(hsva(-120.0f32, 1.0, 1.0, 1.0).to_rgba() == (0.0, 0.0, 1.0, 1.0));
+        let a = rgba(0.2f32, 0.4, 0.9, 0.7);
+        assert!(near(a.to_hsva().to_rgba(), a));
+    }
+
+    #[test]
+    fn rotate_hue_test() {
+        let a = rgba(1.0f32, 0.0, 0.0, 1.0);
+        assert!(near(a.rotate_hue(120.0), rgba(0.0, 1.0, 0.0, 1.0)));
+        assert!(near(a.rotate_hue(-120.0), rgba(0.0, 0.0, 1.0, 1.0)));
+        let a = rgba(0.2f32, 0.4, 0.9, 0.7);
+        assert!(near(a.rotate_hue(360.0), a));
+        let gray = rgba(0.3f32, 0.3, 0.3, 0.5);
+        assert!(gray.rotate_hue(77.0) == gray);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn random_hue_test() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let a = Rgba::<f32>::random_hue(&mut StdRng::seed_from_u64(7));
+        let b = Rgba::<f32>::random_hue(&mut StdRng::seed_from_u64(7));
+        assert!(a == b);
+        let mut rng = StdRng::seed_from_u64(7);
+        for _ in 0..100 {
+            let c = Rgba::<f64>::random_hue(&mut rng);
+            let hsva = c.to_hsva();
+            assert!((hsva.s - 1.0).abs() < 1e-9 && (hsva.v - 1.0).abs() < 1e-9);
+            assert!(c.a == 1.0);
+        }
+    }
+
+    #[test]
+    fn saturate_test() {
+        let a = rgba(1.0f32, 0.5, 0.5, 1.0);
+        assert!(near(a.desaturate(0.5), rgba(1.0, 0.75, 0.75, 1.0)));
+        assert!(near(a.desaturate(1.0), rgba(1.0, 1.0, 1.0, 1.0)));
+        assert!(near(a.saturate(1.0), rgba(1.0, 0.0, 0.0, 1.0)));
+        assert!(near(a.saturate(2.0), rgba(1.0, 0.0, 0.0, 1.0)));
+    }
+}
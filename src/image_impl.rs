@@ -0,0 +1,106 @@
+use crate::*;
+use image::RgbaImage;
+
+impl From<image::Rgba<u8>> for Rgba<u8> {
+    #[inline]
+    fn from(src: image::Rgba<u8>) -> Self {
+        let [r, g, b, a] = src.0;
+        Rgba::new(r, g, b, a)
+    }
+}
+
+impl From<Rgba<u8>> for image::Rgba<u8> {
+    #[inline]
+    fn from(src: Rgba<u8>) -> Self {
+        image::Rgba([src.r, src.g, src.b, src.a])
+    }
+}
+
+/// The alpha of the result is `0xff`.
+impl From<image::Rgb<u8>> for Rgba<u8> {
+    #[inline]
+    fn from(src: image::Rgb<u8>) -> Self {
+        let [r, g, b] = src.0;
+        Rgba::new(r, g, b, 0xff)
+    }
+}
+
+/// The alpha is dropped.
+impl From<Rgba<u8>> for image::Rgb<u8> {
+    #[inline]
+    fn from(src: Rgba<u8>) -> Self {
+        image::Rgb([src.r, src.g, src.b])
+    }
+}
+
+impl From<image::math::Rect> for Rect<u32> {
+    #[inline]
+    fn from(src: image::math::Rect) -> Self {
+        Rect::new((src.x, src.y), (src.width, src.height))
+    }
+}
+
+impl From<Rect<u32>> for image::math::Rect {
+    #[inline]
+    fn from(src: Rect<u32>) -> Self {
+        image::math::Rect {
+            x: src.origin.x,
+            y: src.origin.y,
+            width: src.size.width,
+            height: src.size.height,
+        }
+    }
+}
+
+/// Panics if `pt` is out of `img` as `RgbaImage::get_pixel` does.
+#[inline]
+pub fn get_pixel(img: &RgbaImage, pt: Point<u32>) -> Rgba<u8> {
+    (*img.get_pixel(pt.x, pt.y)).into()
+}
+
+/// Panics if `pt` is out of `img` as `RgbaImage::put_pixel` does.
+#[inline]
+pub fn put_pixel(img: &mut RgbaImage, pt: Point<u32>, color: Rgba<u8>) {
+    img.put_pixel(pt.x, pt.y, color.into());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pixel_test() {
+        let c: image::Rgba<u8> = rgba(1, 2, 3, 4).into();
+        assert!(c == image::Rgba([1, 2, 3, 4]));
+        assert!(Rgba::from(c) == (1, 2, 3, 4));
+        let c: image::Rgb<u8> = rgba(1, 2, 3, 4).into();
+        assert!(c == image::Rgb([1, 2, 3]));
+        assert!(Rgba::from(c) == (1, 2, 3, 0xff));
+    }
+
+    #[test]
+    fn rect_test() {
+        let rc: image::math::Rect = rect((1, 2), (3, 4)).into();
+        assert!(rc.x == 1 && rc.y == 2 && rc.width == 3 && rc.height == 4);
+        assert!(Rect::from(rc) == rect((1, 2), (3, 4)));
+    }
+
+    #[test]
+    fn image_test() {
+        let mut img = RgbaImage::new(8, 8);
+        let rc = rect((2u32, 3), (4, 2));
+        let color = rgba(10, 20, 30, 40);
+        for y in rc.origin.y..rc.endpoint().y {
+            for x in rc.origin.x..rc.endpoint().x {
+                put_pixel(&mut img, point(x, y), color);
+            }
+        }
+        assert!(get_pixel(&img, point(2, 3)) == color);
+        assert!(get_pixel(&img, point(5, 4)) == color);
+        assert!(get_pixel(&img, point(6, 4)) == (0, 0, 0, 0));
+        assert!(get_pixel(&img, point(2, 5)) == (0, 0, 0, 0));
+        let r = image::math::Rect::from(rc);
+        let view = image::GenericImageView::view(&img, r.x, r.y, r.width, r.height);
+        assert!(image::GenericImageView::pixels(&*view).all(|(_, _, p)| Rgba::from(p) == color));
+    }
+}
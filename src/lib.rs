@@ -1,17 +1,161 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(all(test, not(feature = "std")))]
+#[macro_use]
+extern crate std;
+
+#[macro_use]
+mod macros;
+
+mod align;
+#[cfg(feature = "approx")]
+mod approx_impl;
+mod blend;
+#[cfg(feature = "bytemuck")]
+mod bytemuck_impl;
+mod cast;
 mod circle;
 mod collision;
-mod color;
+mod collision_eps;
+pub mod color;
+#[cfg(feature = "euclid")]
+mod euclid_impl;
+#[cfg(feature = "fixed")]
+mod fixed_impl;
+#[cfg(feature = "alloc")]
+mod gradient;
+#[cfg(feature = "half")]
+mod half_impl;
+mod hsv;
+#[cfg(feature = "image")]
+mod image_impl;
+mod oklab;
+mod parse;
+mod penetration;
 mod point;
+mod point3;
+#[cfg(feature = "alloc")]
+mod polygon;
+mod ray;
 mod rect;
+mod rounded_rect;
+mod scalar;
+#[cfg(feature = "sdl2")]
+mod sdl2_impl;
+mod segment;
+mod shape;
 mod size;
+mod size3;
+#[cfg(feature = "proptest")]
+pub mod strategies;
+mod sweep;
+mod transform;
+mod triangle;
 mod vector;
+mod vector3;
+#[cfg(feature = "windows")]
+mod windows_impl;
+#[cfg(feature = "winit")]
+mod winit_impl;
 
+pub use align::Align;
+pub use blend::*;
+pub(crate) use cast::cast_component;
+pub use cast::CastError;
 pub use circle::*;
 pub use collision::*;
-pub use color::{rgba, Rgba};
+pub use collision_eps::*;
+pub use color::{contrast_ratio, rgba, Channel, Rgba};
+#[cfg(feature = "alloc")]
+pub use gradient::*;
+pub use hsv::*;
+#[cfg(feature = "image")]
+pub use image_impl::{get_pixel, put_pixel};
+pub use oklab::*;
+pub(crate) use parse::parse_components;
+pub use parse::{ParseError, ParseErrorKind};
+pub use penetration::*;
 pub use point::*;
+pub use point3::*;
+#[cfg(feature = "alloc")]
+pub use polygon::*;
+pub use ray::*;
 pub use rect::*;
+pub use rounded_rect::*;
+pub(crate) use scalar::Signed;
+pub use scalar::{FloatScalar, Scalar, TotalCmpKey, Widen};
+#[cfg(feature = "sdl2")]
+pub use sdl2_impl::Sdl2RangeError;
+pub use segment::*;
+pub use shape::Shape;
 pub use size::*;
+pub use size3::*;
+pub use sweep::*;
+pub use transform::*;
+pub use triangle::*;
 pub use vector::*;
+pub use vector3::*;
+#[cfg(feature = "winit")]
+pub use winit_impl::inner_rect;
 
 use num::*;
+#[cfg(all(test, not(feature = "std")))]
+use std::{string::ToString, vec::Vec};
+
+#[inline]
+pub(crate) fn partial_min<T: PartialOrd>(a: T, b: T) -> T {
+    if b < a {
+        b
+    } else {
+        a
+    }
+}
+
+#[inline]
+pub(crate) fn partial_max<T: PartialOrd>(a: T, b: T) -> T {
+    if b > a {
+        b
+    } else {
+        a
+    }
+}
+
+/// `|a - b|` that does not underflow for unsigned types.
+#[inline]
+pub(crate) fn abs_diff<T: Scalar>(a: T, b: T) -> T {
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+/// `a * (1 - t) + b * t`, which is exactly `a` at `t == 0` and exactly `b` at `t == 1`.
+///
+/// `1 - t` underflows for unsigned types when `t > 1`, so that case is computed as
+/// `a + (b - a) * t` with `scale_about`, which only underflows if the result is negative.
+#[inline]
+pub(crate) fn lerp<T: Scalar + One>(a: T, b: T, t: T) -> T {
+    if t > T::one() {
+        scale_about(b, a, t)
+    } else {
+        a * (T::one() - t) + b * t
+    }
+}
+
+#[inline]
+pub(crate) fn clamp_unit<T: PartialOrd + Zero + One>(t: T) -> T {
+    partial_max(partial_min(t, T::one()), T::zero())
+}
+
+/// `anchor + (v - anchor) * s` that does not underflow for unsigned types.
+#[inline]
+pub(crate) fn scale_about<T: Scalar>(v: T, anchor: T, s: T) -> T {
+    if v >= anchor {
+        anchor + (v - anchor) * s
+    } else {
+        anchor - (anchor - v) * s
+    }
+}
@@ -0,0 +1,83 @@
+/// Implements componentwise `Mul`, `Div`, `MulAssign` and `DivAssign` for `$t<T>` with each of
+/// the right-hand side types.
+///
+/// Every right-hand side is spelled out because a blanket `impl<U: Into<Self>>` would overlap with
+/// the scalar `Mul<T>` impls.
+macro_rules! impl_componentwise_ops {
+    ($t:ident $fields:tt, $($rhs:ty),+) => {
+        $(impl_componentwise_ops!(@impl $t $fields $rhs);)+
+    };
+    (@impl $t:ident { $($field:ident),+ } $rhs:ty) => {
+        impl<T> core::ops::Mul<$rhs> for $t<T>
+        where
+            T: core::ops::Mul<T, Output = T> + Copy,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, rhs: $rhs) -> Self {
+                let rhs: Self = rhs.into();
+                Self::new($(self.$field * rhs.$field),+)
+            }
+        }
+
+        impl<T> core::ops::Div<$rhs> for $t<T>
+        where
+            T: core::ops::Div<T, Output = T> + Copy,
+        {
+            type Output = Self;
+
+            #[inline]
+            fn div(self, rhs: $rhs) -> Self {
+                let rhs: Self = rhs.into();
+                Self::new($(self.$field / rhs.$field),+)
+            }
+        }
+
+        impl<T> core::ops::MulAssign<$rhs> for $t<T>
+        where
+            T: core::ops::MulAssign + Copy,
+        {
+            #[inline]
+            fn mul_assign(&mut self, rhs: $rhs) {
+                let rhs: Self = rhs.into();
+                $(self.$field *= rhs.$field;)+
+            }
+        }
+
+        impl<T> core::ops::DivAssign<$rhs> for $t<T>
+        where
+            T: core::ops::DivAssign + Copy,
+        {
+            #[inline]
+            fn div_assign(&mut self, rhs: $rhs) {
+                let rhs: Self = rhs.into();
+                $(self.$field /= rhs.$field;)+
+            }
+        }
+    };
+}
+
+/// Implements `Mul<$t<P>> for P` for every primitive numeric type `P`, so that `2 * v == v * 2`.
+///
+/// A blanket `impl<T> Mul<$t<T>> for T` is not allowed by the orphan rules.
+macro_rules! impl_left_scalar_mul {
+    ($t:ident) => {
+        impl_left_scalar_mul!(
+            @impl $t,
+            i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64
+        );
+    };
+    (@impl $t:ident, $($p:ty),+) => {
+        $(
+            impl core::ops::Mul<$t<$p>> for $p {
+                type Output = $t<$p>;
+
+                #[inline]
+                fn mul(self, rhs: $t<$p>) -> $t<$p> {
+                    rhs * self
+                }
+            }
+        )+
+    };
+}
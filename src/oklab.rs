@@ -0,0 +1,154 @@
+use crate::color::{linear_to_srgb, srgb_to_linear};
+use crate::*;
+
+/// A color in the OKLab color space.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Oklab<T> {
+    pub l: T,
+    pub a: T,
+    pub b: T,
+}
+
+impl<T> Oklab<T> {
+    #[inline]
+    pub const fn new(l: T, a: T, b: T) -> Self {
+        Self { l, a, b }
+    }
+}
+
+impl<T: Float> Oklab<T> {
+    /// Converts a color whose r, g and b are linear sRGB. Alpha is dropped.
+    pub fn from_linear_rgba(src: Rgba<T>) -> Self {
+        let c = |v: f64| T::from(v).unwrap();
+        let l = c(0.4122214708) * src.r + c(0.5363325363) * src.g + c(0.0514459929) * src.b;
+        let m = c(0.2119034982) * src.r + c(0.6806995451) * src.g + c(0.1073969566) * src.b;
+        let s = c(0.0883024619) * src.r + c(0.2817188376) * src.g + c(0.6299787005) * src.b;
+        let (l, m, s) = (l.cbrt(), m.cbrt(), s.cbrt());
+        Self::new(
+            c(0.2104542553) * l + c(0.7936177850) * m - c(0.0040720468) * s,
+            c(1.9779984951) * l - c(2.4285922050) * m + c(0.4505937099) * s,
+            c(0.0259040371) * l + c(0.7827717662) * m - c(0.8086757660) * s,
+        )
+    }
+
+    /// Converts to linear sRGB with the given alpha.
+    pub fn to_linear_rgba(self, alpha: T) -> Rgba<T> {
+        let c = |v: f64| T::from(v).unwrap();
+        let l = self.l + c(0.3963377774) * self.a + c(0.2158037573) * self.b;
+        let m = self.l - c(0.1055613458) * self.a - c(0.0638541728) * self.b;
+        let s = self.l - c(0.0894841775) * self.a - c(1.2914855480) * self.b;
+        let (l, m, s) = (l * l * l, m * m * m, s * s * s);
+        Rgba::new(
+            c(4.0767416621) * l - c(3.3077115913) * m + c(0.2309699292) * s,
+            c(-1.2684380046) * l + c(2.6097574011) * m - c(0.3413193965) * s,
+            c(-0.0041960863) * l - c(0.7034186147) * m + c(1.7076147010) * s,
+            alpha,
+        )
+    }
+
+    /// Converts a color whose r, g and b are gamma-encoded sRGB. Alpha is dropped.
+    #[inline]
+    pub fn from_rgba(src: Rgba<T>) -> Self {
+        Self::from_linear_rgba(Rgba::new(
+            srgb_to_linear(src.r),
+            srgb_to_linear(src.g),
+            srgb_to_linear(src.b),
+            src.a,
+        ))
+    }
+
+    /// Converts to gamma-encoded sRGB with the given alpha.
+    #[inline]
+    pub fn to_rgba(self, alpha: T) -> Rgba<T> {
+        let c = self.to_linear_rgba(alpha);
+        Rgba::new(
+            linear_to_srgb(c.r),
+            linear_to_srgb(c.g),
+            linear_to_srgb(c.b),
+            c.a,
+        )
+    }
+
+    #[inline]
+    pub fn lerp(self, other: impl Into<Self>, t: T) -> Self {
+        let other = other.into();
+        Self::new(
+            self.l + (other.l - self.l) * t,
+            self.a + (other.a - self.a) * t,
+            self.b + (other.b - self.b) * t,
+        )
+    }
+}
+
+impl<T> From<(T, T, T)> for Oklab<T> {
+    #[inline]
+    fn from(src: (T, T, T)) -> Oklab<T> {
+        Oklab::new(src.0, src.1, src.2)
+    }
+}
+
+impl<T: Copy> From<[T; 3]> for Oklab<T> {
+    #[inline]
+    fn from(src: [T; 3]) -> Oklab<T> {
+        Oklab::new(src[0], src[1], src[2])
+    }
+}
+
+#[inline]
+pub const fn oklab<T>(l: T, a: T, b: T) -> Oklab<T> {
+    Oklab::new(l, a, b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn const_test() {
+        const A: Oklab<f32> = oklab(0.5, 0.1, -0.1);
+        assert!(A == Oklab::new(0.5, 0.1, -0.1));
+    }
+
+    fn near(a: Oklab<f64>, b: Oklab<f64>, eps: f64) -> bool {
+        (a.l - b.l).abs() < eps && (a.a - b.a).abs() < eps && (a.b - b.b).abs() < eps
+    }
+
+    #[test]
+    fn from_rgba_test() {
+        let white = Oklab::from_rgba(rgba(1.0, 1.0, 1.0, 1.0));
+        assert!(near(white, oklab(1.0, 0.0, 0.0), 1e-4));
+        let red = Oklab::from_rgba(rgba(1.0, 0.0, 0.0, 1.0));
+        assert!(near(red, oklab(0.627955, 0.224863, 0.125846), 1e-4));
+        let gray = Oklab::from_rgba(rgba(0.5, 0.5, 0.5, 1.0));
+        assert!(near(gray, oklab(0.598181, 0.0, 0.0), 1e-4));
+        let linear_gray = Oklab::from_linear_rgba(rgba(0.18, 0.18, 0.18, 1.0));
+        assert!(near(linear_gray, oklab(0.18f64.cbrt(), 0.0, 0.0), 1e-4));
+    }
+
+    #[test]
+    fn round_trip_test() {
+        let mut seed = 12345u32;
+        let mut next = || {
+            seed = seed.wrapping_mul(1664525).wrapping_add(1013904223);
+            (seed >> 8) as f64 / (1u32 << 24) as f64
+        };
+        for _ in 0..1000 {
+            let c = rgba(next(), next(), next(), next());
+            let d = Oklab::from_rgba(c).to_rgba(c.a) - c;
+            assert!(d.r.abs() < 1e-5 && d.g.abs() < 1e-5 && d.b.abs() < 1e-5 && d.a == 0.0);
+            let d = Oklab::from_linear_rgba(c).to_linear_rgba(c.a) - c;
+            assert!(d.r.abs() < 1e-5 && d.g.abs() < 1e-5 && d.b.abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn lerp_test() {
+        let a = oklab(0.0f32, 0.5, -0.5);
+        let b = oklab(1.0f32, -0.5, 0.5);
+        assert!(a.lerp(b, 0.0) == a);
+        assert!(a.lerp(b, 1.0) == b);
+        assert!(a.lerp(b, 0.5) == oklab(0.5, 0.0, 0.0));
+    }
+}
@@ -0,0 +1,107 @@
+/// What went wrong in a `ParseError`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ParseErrorKind {
+    /// The component is absent or empty.
+    Missing,
+    /// The component could not be parsed as the scalar type.
+    Invalid,
+    /// There is input after the last component.
+    Trailing,
+}
+
+/// The error of `FromStr` for the geometry types and `Rgba<u8>`, naming the component that
+/// failed.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct ParseError {
+    type_name: &'static str,
+    component: Option<&'static str>,
+    kind: ParseErrorKind,
+}
+
+impl ParseError {
+    #[inline]
+    pub(crate) fn new(
+        type_name: &'static str,
+        component: Option<&'static str>,
+        kind: ParseErrorKind,
+    ) -> Self {
+        Self {
+            type_name,
+            component,
+            kind,
+        }
+    }
+
+    /// The name of the geometry type, e.g. `"Rect"`.
+    #[inline]
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+
+    /// The name of the component, e.g. `"width"`, or `None` for `ParseErrorKind::Trailing` and
+    /// an unknown color name.
+    #[inline]
+    pub fn component(&self) -> Option<&'static str> {
+        self.component
+    }
+
+    #[inline]
+    pub fn kind(&self) -> ParseErrorKind {
+        self.kind
+    }
+}
+
+impl core::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        match (self.kind, self.component) {
+            (ParseErrorKind::Missing, Some(c)) => write!(f, "missing {}.{}", self.type_name, c),
+            (ParseErrorKind::Invalid, Some(c)) => write!(f, "invalid {}.{}", self.type_name, c),
+            (ParseErrorKind::Invalid, None) => write!(f, "invalid {}", self.type_name),
+            _ => write!(f, "unexpected input after {}", self.type_name),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
+
+/// Parses `N` components separated by any of `seps`, ignoring whitespace around each component.
+pub(crate) fn parse_components<T: core::str::FromStr, const N: usize>(
+    s: &str,
+    seps: &[char],
+    type_name: &'static str,
+    names: [&'static str; N],
+) -> Result<[T; N], ParseError> {
+    let err = |kind, component| ParseError::new(type_name, component, kind);
+    let mut parts = s.split(seps).map(str::trim);
+    let mut values: [Option<T>; N] = [(); N].map(|_| None);
+    for (value, name) in values.iter_mut().zip(names) {
+        let part = parts
+            .next()
+            .filter(|p| !p.is_empty())
+            .ok_or(err(ParseErrorKind::Missing, Some(name)))?;
+        *value = Some(
+            part.parse()
+                .map_err(|_| err(ParseErrorKind::Invalid, Some(name)))?,
+        );
+    }
+    if parts.next().is_some() {
+        return Err(err(ParseErrorKind::Trailing, None));
+    }
+    Ok(values.map(|v| v.unwrap()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn display_test() {
+        let e = "10".parse::<Point<i32>>().unwrap_err();
+        assert!(e.to_string() == "missing Point.y");
+        let e = "1, 2, x, 4".parse::<Rect<i32>>().unwrap_err();
+        assert!(e.to_string() == "invalid Rect.size.width");
+        let e = "1x2x3".parse::<Size<u32>>().unwrap_err();
+        assert!(e.to_string() == "unexpected input after Size");
+    }
+}
@@ -0,0 +1,154 @@
+use crate::*;
+
+/// The minimum translation vector of overlapping shapes.
+///
+/// `a.penetration(&b)` returns the shortest vector that separates the shapes when added to the
+/// position of `b`, so `b` is pushed out of `a`. Use its negation to move `a` instead. Shapes which
+/// only touch do not overlap and give `None`.
+///
+/// When no direction is shorter than another, as for concentric circles or identical rects, the
+/// x axis is preferred over the y axis and the positive direction over the negative one.
+pub trait Penetration<T> {
+    type Output;
+
+    fn penetration(&self, rhs: &T) -> Option<Self::Output>;
+}
+
+#[inline]
+pub fn penetration<T: Penetration<U>, U>(lhs: &T, rhs: &U) -> Option<T::Output> {
+    lhs.penetration(rhs)
+}
+
+/// Returns the depth and the sign of the direction of pushing `b` out of `a` on an axis.
+#[inline]
+fn axis_overlap<T: Float>(a_lo: T, a_hi: T, b_lo: T, b_hi: T) -> (T, T) {
+    let depth = a_hi.min(b_hi) - a_lo.max(b_lo);
+    let sign = if b_lo + b_hi < a_lo + a_hi {
+        -T::one()
+    } else {
+        T::one()
+    };
+    (depth, sign)
+}
+
+impl<T: Float> Penetration<Circle<T>> for Circle<T> {
+    type Output = Vector<T>;
+
+    fn penetration(&self, rhs: &Circle<T>) -> Option<Vector<T>> {
+        let d = rhs.center - self.center;
+        let dist = d.abs();
+        let depth = self.radius + rhs.radius - dist;
+        if depth <= T::zero() {
+            return None;
+        }
+        Some(d.normalize_or(vector(T::one(), T::zero())) * depth)
+    }
+}
+
+impl<T: Float> Penetration<Rect<T>> for Rect<T> {
+    type Output = Vector<T>;
+
+    /// Pushes along the axis of the smaller overlap.
+    fn penetration(&self, rhs: &Rect<T>) -> Option<Vector<T>> {
+        let (a_lo, a_hi) = self.bounds();
+        let (b_lo, b_hi) = rhs.bounds();
+        let (x, sx) = axis_overlap(a_lo.x, a_hi.x, b_lo.x, b_hi.x);
+        let (y, sy) = axis_overlap(a_lo.y, a_hi.y, b_lo.y, b_hi.y);
+        if x <= T::zero() || y <= T::zero() {
+            return None;
+        }
+        if x <= y {
+            Some(vector(x * sx, T::zero()))
+        } else {
+            Some(vector(T::zero(), y * sy))
+        }
+    }
+}
+
+impl<T: Float> Penetration<Circle<T>> for Rect<T> {
+    type Output = Vector<T>;
+
+    /// Pushes away from the nearest point of the rect, or through the nearest side if the center
+    /// of the circle is in the rect.
+    fn penetration(&self, rhs: &Circle<T>) -> Option<Vector<T>> {
+        let c = rhs.center;
+        let nearest = self.clamp_point(c);
+        if nearest != c {
+            let d = c - nearest;
+            let depth = rhs.radius - d.abs();
+            return (depth > T::zero()).then(|| d.normalize() * depth);
+        }
+        let (lo, hi) = self.bounds();
+        let (zero, one) = (T::zero(), T::one());
+        let sides = [
+            (hi.x - c.x, vector(one, zero)),
+            (c.x - lo.x, vector(-one, zero)),
+            (hi.y - c.y, vector(zero, one)),
+            (c.y - lo.y, vector(zero, -one)),
+        ];
+        let (dist, dir) = sides[1..]
+            .iter()
+            .fold(sides[0], |m, &s| if s.0 < m.0 { s } else { m });
+        let depth = dist + rhs.radius;
+        (depth > T::zero()).then(|| dir * depth)
+    }
+}
+
+impl<T: Float> Penetration<Rect<T>> for Circle<T> {
+    type Output = Vector<T>;
+
+    /// The negation of `Rect::penetration` with the circle, so the rect is pushed out.
+    #[inline]
+    fn penetration(&self, rhs: &Rect<T>) -> Option<Vector<T>> {
+        rhs.penetration(self).map(|v| -v)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn circle_circle_test() {
+        let a = circle((0.0f32, 0.0), 5.0);
+        assert!(penetration(&a, &circle((8.0, 0.0), 5.0)) == Some(vector(2.0, 0.0)));
+        assert!(penetration(&a, &circle((0.0, -6.0), 2.0)) == Some(vector(0.0, -1.0)));
+        assert!(penetration(&a, &circle((10.0, 0.0), 5.0)).is_none());
+        assert!(penetration(&a, &circle((0.0, 0.0), 2.0)) == Some(vector(7.0, 0.0)));
+        let b = circle((3.0f32, 4.0), 5.0);
+        let v = penetration(&a, &b).unwrap();
+        assert!(v.approx_eq(&vector(3.0, 4.0), 1e-6));
+        assert!(!is_crossing(&a, &circle(b.center + v * 1.001, b.radius)));
+    }
+
+    #[test]
+    fn rect_rect_test() {
+        let a = rect((0.0f32, 0.0), (10.0, 10.0));
+        assert!(penetration(&a, &rect((8.0, 1.0), (10.0, 5.0))) == Some(vector(2.0, 0.0)));
+        assert!(penetration(&a, &rect((-8.0, 1.0), (10.0, 5.0))) == Some(vector(-2.0, 0.0)));
+        assert!(penetration(&a, &rect((1.0, 9.0), (5.0, 5.0))) == Some(vector(0.0, 1.0)));
+        assert!(penetration(&a, &rect((1.0, -3.0), (5.0, 5.0))) == Some(vector(0.0, -2.0)));
+        assert!(penetration(&a, &rect((10.0, 0.0), (5.0, 5.0))).is_none());
+        assert!(penetration(&a, &a) == Some(vector(10.0, 0.0)));
+        let flipped = rect((10.0f32, 10.0), (-10.0, -10.0));
+        assert!(penetration(&flipped, &rect((8.0, 1.0), (10.0, 5.0))) == Some(vector(2.0, 0.0)));
+    }
+
+    #[test]
+    fn rect_circle_test() {
+        let floor = rect((0.0f32, 100.0), (200.0, 20.0));
+        // half sunk into the floor, so it rises by its radius
+        let ball = circle((50.0f32, 100.0), 10.0);
+        assert!(penetration(&floor, &ball) == Some(vector(0.0, -10.0)));
+        let ball = circle((50.0f32, 95.0), 10.0);
+        assert!(penetration(&floor, &ball) == Some(vector(0.0, -5.0)));
+        assert!(penetration(&ball, &floor) == Some(vector(0.0, 5.0)));
+        assert!(penetration(&floor, &circle((50.0, 90.0), 10.0)).is_none());
+        let v = penetration(&floor, &circle((-3.0, 96.0), 10.0)).unwrap();
+        assert!(v.approx_eq(&vector(-3.0, -4.0), 1e-5));
+        let deep = circle((198.0f32, 110.0), 1.0);
+        assert!(penetration(&floor, &deep) == Some(vector(3.0, 0.0)));
+        let centered = circle((100.0f32, 110.0), 1.0);
+        assert!(penetration(&floor, &centered) == Some(vector(0.0, 11.0)));
+    }
+}
@@ -1,6 +1,16 @@
 use crate::*;
 
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+/// A point in 2D space.
+///
+/// # Conversions
+///
+/// `as_f32` and `as_f64` convert each component with `ToPrimitive`. Conversion from an integer
+/// cannot fail but may round large values. `as_i32` and `as_u32` convert each component like
+/// `as`: fractions are truncated toward zero, values out of range saturate and NaN becomes `0`.
+///
+/// `Vector`, `Size`, `Rect` and `Circle` have the same methods, which convert their components
+/// in the same way.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Debug)]
 #[repr(C)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Point<T> {
@@ -10,7 +20,7 @@ pub struct Point<T> {
 
 impl<T> Point<T> {
     #[inline]
-    pub fn new(x: T, y: T) -> Self {
+    pub const fn new(x: T, y: T) -> Self {
         Self { x, y }
     }
 
@@ -18,6 +28,216 @@ impl<T> Point<T> {
     pub fn map<R>(self, mut f: impl FnMut(T) -> R) -> Point<R> {
         Point::new(f(self.x), f(self.y))
     }
+
+    #[inline]
+    pub fn yx(self) -> Self {
+        Self::new(self.y, self.x)
+    }
+
+    #[inline]
+    pub fn with_x(self, x: T) -> Self {
+        Self { x, ..self }
+    }
+
+    #[inline]
+    pub fn with_y(self, y: T) -> Self {
+        Self { y, ..self }
+    }
+
+    /// Iterates references to the components in x, y order.
+    #[inline]
+    pub fn iter(&self) -> core::array::IntoIter<&T, 2> {
+        [&self.x, &self.y].into_iter()
+    }
+
+    #[inline]
+    pub fn as_array(&self) -> &[T; 2] {
+        // SAFETY: `Point<T>` is `repr(C)` with two fields of `T`, which has the same layout as `[T; 2]`.
+        unsafe { &*(self as *const Self as *const [T; 2]) }
+    }
+
+    #[inline]
+    pub fn as_array_mut(&mut self) -> &mut [T; 2] {
+        // SAFETY: `Point<T>` is `repr(C)` with two fields of `T`, which has the same layout as `[T; 2]`.
+        unsafe { &mut *(self as *mut Self as *mut [T; 2]) }
+    }
+}
+
+impl<T: Scalar + Zero + One> Point<T> {
+    /// Interpolates each component linearly. `t` outside of `0..=1` extrapolates.
+    ///
+    /// # Panics
+    ///
+    /// For unsigned `T`, panics on overflow in debug builds if an extrapolated component would be
+    /// negative.
+    #[inline]
+    pub fn lerp(self, other: impl Into<Self>, t: T) -> Self {
+        let other = other.into();
+        Self::new(lerp(self.x, other.x, t), lerp(self.y, other.y, t))
+    }
+
+    /// Same as `lerp` but `t` is clamped to `0..=1`.
+    #[inline]
+    pub fn lerp_clamped(self, other: impl Into<Self>, t: T) -> Self {
+        self.lerp(other, clamp_unit(t))
+    }
+}
+
+impl<T: PartialOrd + Copy> Point<T> {
+    /// Returns the componentwise minimum.
+    #[inline]
+    pub fn min(self, rhs: impl Into<Self>) -> Self {
+        let rhs = rhs.into();
+        Self::new(partial_min(self.x, rhs.x), partial_min(self.y, rhs.y))
+    }
+
+    /// Returns the componentwise maximum.
+    #[inline]
+    pub fn max(self, rhs: impl Into<Self>) -> Self {
+        let rhs = rhs.into();
+        Self::new(partial_max(self.x, rhs.x), partial_max(self.y, rhs.y))
+    }
+
+    /// Clamps each component to `lo..=hi`.
+    ///
+    /// When a component of `lo` is greater than the one of `hi`, that component becomes `hi`.
+    #[inline]
+    pub fn clamp(self, lo: impl Into<Self>, hi: impl Into<Self>) -> Self {
+        self.max(lo).min(hi)
+    }
+
+    #[inline]
+    pub fn min_element(self) -> T {
+        partial_min(self.x, self.y)
+    }
+
+    #[inline]
+    pub fn max_element(self) -> T {
+        partial_max(self.x, self.y)
+    }
+}
+
+impl<T> Point<T>
+where
+    T: Zero + core::ops::Div<T, Output = T> + NumCast + Copy,
+{
+    /// Returns the average of `points`, or `None` if `points` is empty.
+    pub fn centroid(points: impl IntoIterator<Item = Self>) -> Option<Self> {
+        let (sum, n) = points
+            .into_iter()
+            .fold((Self::zero(), 0usize), |(sum, n), p| (sum + p, n + 1));
+        if n == 0 {
+            return None;
+        }
+        let n = T::from(n)?;
+        Some(Self::new(sum.x / n, sum.y / n))
+    }
+}
+
+impl<T: Float> Point<T> {
+    /// Returns `true` if every component differs from the one of `other` by at most `epsilon`.
+    #[inline]
+    pub fn approx_eq(&self, other: &Self, epsilon: T) -> bool {
+        (self.x - other.x).abs() <= epsilon && (self.y - other.y).abs() <= epsilon
+    }
+
+    #[inline]
+    pub fn floor(self) -> Self {
+        self.map(T::floor)
+    }
+
+    #[inline]
+    pub fn ceil(self) -> Self {
+        self.map(T::ceil)
+    }
+
+    #[inline]
+    pub fn round(self) -> Self {
+        self.map(T::round)
+    }
+
+    #[inline]
+    pub fn distance(self, other: impl Into<Self>) -> T {
+        let other = other.into();
+        (self.x - other.x).hypot(self.y - other.y)
+    }
+
+    /// Rotates `self` by `radians` counterclockwise with the y axis up around `pivot`.
+    #[inline]
+    pub fn rotate_around(self, pivot: impl Into<Self>, radians: T) -> Self {
+        let pivot = pivot.into();
+        pivot + (self - pivot).rotate(radians)
+    }
+}
+
+impl<T: Scalar> Point<T> {
+    /// Returns the squared Euclidean distance without `sqrt`, so it also works for integers.
+    #[inline]
+    pub fn distance_squared(self, other: impl Into<Self>) -> T {
+        let other = other.into();
+        let dx = abs_diff(self.x, other.x);
+        let dy = abs_diff(self.y, other.y);
+        dx * dx + dy * dy
+    }
+
+    /// Returns `|dx| + |dy|`, the number of 4-neighbor steps between grid cells.
+    #[inline]
+    pub fn manhattan_distance(self, other: impl Into<Self>) -> T {
+        let other = other.into();
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y)
+    }
+
+    /// Returns `max(|dx|, |dy|)`, the number of 8-neighbor steps between grid cells.
+    #[inline]
+    pub fn chebyshev_distance(self, other: impl Into<Self>) -> T {
+        let other = other.into();
+        partial_max(abs_diff(self.x, other.x), abs_diff(self.y, other.y))
+    }
+}
+
+impl<T> Point<T>
+where
+    T: Scalar + core::ops::Div<T, Output = T> + Zero + One,
+{
+    /// Returns the point halfway to `other`. Integer components are rounded toward the smaller
+    /// one, and do not overflow.
+    #[inline]
+    pub fn midpoint(self, other: impl Into<Self>) -> Self {
+        let other = other.into();
+        let two = T::one() + T::one();
+        let mid = |a, b| {
+            let (lo, hi) = if a < b { (a, b) } else { (b, a) };
+            if lo >= T::zero() || hi <= T::zero() {
+                return lo + (hi - lo) / two;
+            }
+            // the difference of opposite signs can overflow, but their sum cannot
+            let sum = lo + hi;
+            let half = sum / two;
+            if sum < T::zero() && half * two != sum {
+                half - T::one()
+            } else {
+                half
+            }
+        };
+        Self::new(mid(self.x, other.x), mid(self.y, other.y))
+    }
+}
+
+impl<T: TotalCmpKey> Point<T> {
+    /// Returns a key ordered lexicographically like the derived `Ord`, using `total_cmp` for
+    /// each component. It makes float components usable as `BTreeMap` or `HashMap` keys.
+    #[inline]
+    pub fn total_cmp_key(self) -> (T::Key, T::Key) {
+        (self.x.total_cmp_key(), self.y.total_cmp_key())
+    }
+}
+
+impl<T: Widen> Point<T> {
+    /// Converts each component to `T::Wide`, e.g. to compute products without overflow.
+    #[inline]
+    pub fn widen(self) -> Point<T::Wide> {
+        self.map(T::widen)
+    }
 }
 
 impl<T: ToPrimitive> Point<T> {
@@ -25,6 +245,187 @@ impl<T: ToPrimitive> Point<T> {
     pub fn cast<U: NumCast>(self) -> Option<Point<U>> {
         Some(Point::new(U::from(self.x)?, U::from(self.y)?))
     }
+
+    #[inline]
+    pub fn try_cast<U: NumCast>(self) -> Result<Point<U>, CastError>
+    where
+        T: Copy,
+    {
+        Ok(Point::new(
+            cast_component(self.x, "Point", "x")?,
+            cast_component(self.y, "Point", "y")?,
+        ))
+    }
+
+    /// Converts each component to `f32`. See [the conversions of `Point`](Self#conversions).
+    #[inline]
+    pub fn as_f32(self) -> Point<f32> {
+        self.map(|v| v.to_f32().unwrap_or(f32::NAN))
+    }
+
+    /// Converts each component to `f64`. See [the conversions of `Point`](Self#conversions).
+    #[inline]
+    pub fn as_f64(self) -> Point<f64> {
+        self.map(|v| v.to_f64().unwrap_or(f64::NAN))
+    }
+
+    /// Converts each component to `i32`. See [the conversions of `Point`](Self#conversions).
+    #[inline]
+    pub fn as_i32(self) -> Point<i32> {
+        self.map(|v| v.to_f64().map_or(0, |v| v as i32))
+    }
+
+    /// Converts each component to `u32`. See [the conversions of `Point`](Self#conversions).
+    #[inline]
+    pub fn as_u32(self) -> Point<u32> {
+        self.map(|v| v.to_f64().map_or(0, |v| v as u32))
+    }
+}
+
+impl<T: num::traits::CheckedAdd> Point<T> {
+    /// `self + rhs`, or `None` if either component overflows.
+    #[inline]
+    pub fn checked_add(self, rhs: impl Into<Size<T>>) -> Option<Self> {
+        let rhs = rhs.into();
+        Some(Point::new(
+            self.x.checked_add(&rhs.width)?,
+            self.y.checked_add(&rhs.height)?,
+        ))
+    }
+}
+
+impl<T: num::traits::CheckedSub> Point<T> {
+    /// `self - rhs`, or `None` if either component overflows.
+    #[inline]
+    pub fn checked_sub(self, rhs: Vector<T>) -> Option<Self> {
+        Some(Point::new(
+            self.x.checked_sub(&rhs.x)?,
+            self.y.checked_sub(&rhs.y)?,
+        ))
+    }
+
+    /// The vector `self - rhs` between two points, or `None` if either component overflows.
+    #[inline]
+    pub fn checked_sub_point(self, rhs: Point<T>) -> Option<Vector<T>> {
+        Some(Vector::new(
+            self.x.checked_sub(&rhs.x)?,
+            self.y.checked_sub(&rhs.y)?,
+        ))
+    }
+}
+
+impl<T: num::traits::SaturatingAdd> Point<T> {
+    #[inline]
+    pub fn saturating_add(self, rhs: impl Into<Size<T>>) -> Self {
+        let rhs = rhs.into();
+        Point::new(
+            self.x.saturating_add(&rhs.width),
+            self.y.saturating_add(&rhs.height),
+        )
+    }
+}
+
+impl<T: num::traits::SaturatingSub> Point<T> {
+    #[inline]
+    pub fn saturating_sub(self, rhs: Vector<T>) -> Self {
+        Point::new(self.x.saturating_sub(&rhs.x), self.y.saturating_sub(&rhs.y))
+    }
+
+    /// The vector `self - rhs` between two points, with each component saturated.
+    #[inline]
+    pub fn saturating_sub_point(self, rhs: Point<T>) -> Vector<T> {
+        Vector::new(self.x.saturating_sub(&rhs.x), self.y.saturating_sub(&rhs.y))
+    }
+}
+
+impl<T: num::traits::WrappingAdd> Point<T> {
+    #[inline]
+    pub fn wrapping_add(self, rhs: impl Into<Vector<T>>) -> Self {
+        let rhs = rhs.into();
+        Point::new(self.x.wrapping_add(&rhs.x), self.y.wrapping_add(&rhs.y))
+    }
+}
+
+impl<T: num::traits::WrappingSub> Point<T> {
+    #[inline]
+    pub fn wrapping_sub(self, rhs: impl Into<Vector<T>>) -> Self {
+        let rhs = rhs.into();
+        Point::new(self.x.wrapping_sub(&rhs.x), self.y.wrapping_sub(&rhs.y))
+    }
+}
+
+impl<T: num::traits::WrappingMul> Point<T> {
+    #[inline]
+    pub fn wrapping_mul(self, rhs: T) -> Self {
+        Point::new(self.x.wrapping_mul(&rhs), self.y.wrapping_mul(&rhs))
+    }
+}
+
+impl<T: num::traits::Euclid> Point<T> {
+    /// Wraps each component into `0..size` with `rem_euclid`, so negative components wrap
+    /// from the far edge instead of staying negative as with `%`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the width or the height is zero for integers. Floats give NaN instead.
+    #[inline]
+    pub fn wrap_to(self, size: impl Into<Size<T>>) -> Self {
+        let size = size.into();
+        Point::new(
+            self.x.rem_euclid(&size.width),
+            self.y.rem_euclid(&size.height),
+        )
+    }
+}
+
+impl<T> Point<T>
+where
+    T: num::traits::Euclid + core::ops::Sub<T, Output = T> + Copy,
+{
+    /// Rounds each component down to a multiple of the cell size, toward negative infinity
+    /// for both integers and floats.
+    #[inline]
+    pub fn snap_to_grid(self, cell: impl Into<Size<T>>) -> Self {
+        let cell = cell.into();
+        Point::new(
+            self.x - self.x.rem_euclid(&cell.width),
+            self.y - self.y.rem_euclid(&cell.height),
+        )
+    }
+}
+
+impl<T> Point<T>
+where
+    T: num::traits::CheckedAdd + num::traits::CheckedSub + One + Copy,
+{
+    #[inline]
+    fn offset(self, dx: i8, dy: i8) -> Option<Self> {
+        let step = |v: T, d: i8| match d {
+            -1 => v.checked_sub(&T::one()),
+            1 => v.checked_add(&T::one()),
+            _ => Some(v),
+        };
+        Some(Point::new(step(self.x, dx)?, step(self.y, dy)?))
+    }
+
+    /// Iterates the orthogonally adjacent points in the order of -x, +x, -y and +y, skipping
+    /// the ones out of range of `T`.
+    #[inline]
+    pub fn neighbors4(self) -> impl Iterator<Item = Self> {
+        [(-1, 0), (1, 0), (0, -1), (0, 1)]
+            .into_iter()
+            .filter_map(move |(dx, dy)| self.offset(dx, dy))
+    }
+
+    /// Iterates the adjacent points including diagonals in row-major order from `(x - 1, y - 1)`,
+    /// skipping the ones out of range of `T`.
+    #[inline]
+    pub fn neighbors8(self) -> impl Iterator<Item = Self> {
+        (-1..=1)
+            .flat_map(|dy| (-1..=1).map(move |dx| (dx, dy)))
+            .filter(|&d| d != (0, 0))
+            .filter_map(move |(dx, dy)| self.offset(dx, dy))
+    }
 }
 
 impl<T> From<(T, T)> for Point<T> {
@@ -48,6 +449,16 @@ impl<T> From<Vector<T>> for Point<T> {
     }
 }
 
+/// Parses `"x,y"`, allowing whitespace around each component.
+impl<T: core::str::FromStr> core::str::FromStr for Point<T> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x, y] = parse_components(s, &[','], "Point", ["x", "y"])?;
+        Ok(Self::new(x, y))
+    }
+}
+
 impl<T> PartialEq<(T, T)> for Point<T>
 where
     T: PartialEq,
@@ -84,9 +495,21 @@ where
     }
 }
 
-impl<T> std::ops::Add<Point<T>> for Point<T>
+impl<T: Zero> Zero for Point<T> {
+    #[inline]
+    fn zero() -> Self {
+        Self::new(T::zero(), T::zero())
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.x.is_zero() && self.y.is_zero()
+    }
+}
+
+impl<T> core::ops::Add<Point<T>> for Point<T>
 where
-    T: std::ops::Add<T, Output = T>,
+    T: core::ops::Add<T, Output = T>,
 {
     type Output = Self;
 
@@ -96,9 +519,9 @@ where
     }
 }
 
-impl<T, U> std::ops::Add<U> for Point<T>
+impl<T, U> core::ops::Add<U> for Point<T>
 where
-    T: std::ops::Add<T, Output = T>,
+    T: core::ops::Add<T, Output = T>,
     U: Into<Size<T>>,
 {
     type Output = Self;
@@ -110,9 +533,9 @@ where
     }
 }
 
-impl<T> std::ops::Sub<Point<T>> for Point<T>
+impl<T> core::ops::Sub<Point<T>> for Point<T>
 where
-    T: std::ops::Sub<T, Output = T>,
+    T: core::ops::Sub<T, Output = T>,
 {
     type Output = Vector<T>;
 
@@ -122,9 +545,9 @@ where
     }
 }
 
-impl<T> std::ops::Sub<Vector<T>> for Point<T>
+impl<T> core::ops::Sub<Vector<T>> for Point<T>
 where
-    T: std::ops::Sub<T, Output = T>,
+    T: core::ops::Sub<T, Output = T>,
 {
     type Output = Point<T>;
 
@@ -134,9 +557,21 @@ where
     }
 }
 
-impl<T> std::ops::Mul<T> for Point<T>
+impl<T> core::ops::Neg for Point<T>
 where
-    T: std::ops::Mul<T, Output = T> + Copy,
+    T: core::ops::Neg<Output = T>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl<T> core::ops::Mul<T> for Point<T>
+where
+    T: core::ops::Mul<T, Output = T> + Copy,
 {
     type Output = Point<T>;
 
@@ -146,9 +581,9 @@ where
     }
 }
 
-impl<T> std::ops::Div<T> for Point<T>
+impl<T> core::ops::Div<T> for Point<T>
 where
-    T: std::ops::Div<T, Output = T> + Copy,
+    T: core::ops::Div<T, Output = T> + Copy,
 {
     type Output = Point<T>;
 
@@ -158,9 +593,9 @@ where
     }
 }
 
-impl<T, U> std::ops::AddAssign<U> for Point<T>
+impl<T, U> core::ops::AddAssign<U> for Point<T>
 where
-    T: std::ops::AddAssign<T>,
+    T: core::ops::AddAssign<T>,
     U: Into<Size<T>>,
 {
     #[inline]
@@ -171,9 +606,9 @@ where
     }
 }
 
-impl<T> std::ops::SubAssign<Vector<T>> for Point<T>
+impl<T> core::ops::SubAssign<Vector<T>> for Point<T>
 where
-    T: std::ops::SubAssign<T>,
+    T: core::ops::SubAssign<T>,
 {
     #[inline]
     fn sub_assign(&mut self, rhs: Vector<T>) {
@@ -182,9 +617,9 @@ where
     }
 }
 
-impl<T> std::ops::MulAssign<T> for Point<T>
+impl<T> core::ops::MulAssign<T> for Point<T>
 where
-    T: std::ops::MulAssign<T> + Copy,
+    T: core::ops::MulAssign<T> + Copy,
 {
     #[inline]
     fn mul_assign(&mut self, rhs: T) {
@@ -193,9 +628,9 @@ where
     }
 }
 
-impl<T> std::ops::DivAssign<T> for Point<T>
+impl<T> core::ops::DivAssign<T> for Point<T>
 where
-    T: std::ops::DivAssign<T> + Copy,
+    T: core::ops::DivAssign<T> + Copy,
 {
     #[inline]
     fn div_assign(&mut self, rhs: T) {
@@ -204,8 +639,45 @@ where
     }
 }
 
+impl<T> core::ops::Index<usize> for Point<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: usize) -> &T {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            _ => panic!("Point index out of range: {}", index),
+        }
+    }
+}
+
+impl<T> core::ops::IndexMut<usize> for Point<T> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            _ => panic!("Point index out of range: {}", index),
+        }
+    }
+}
+
+/// Iterates the components in x, y order.
+impl<T> IntoIterator for Point<T> {
+    type Item = T;
+    type IntoIter = core::array::IntoIter<T, 2>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        [self.x, self.y].into_iter()
+    }
+}
+
+impl_left_scalar_mul!(Point);
+
 #[inline]
-pub fn point<T>(x: T, y: T) -> Point<T> {
+pub const fn point<T>(x: T, y: T) -> Point<T> {
     Point::new(x, y)
 }
 
@@ -213,11 +685,351 @@ pub fn point<T>(x: T, y: T) -> Point<T> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn as_test() {
+        assert!(point(1, -2).as_f32() == (1.0, -2.0));
+        assert!(point(u64::MAX, 0).as_f64() == (u64::MAX as f64, 0.0));
+        assert!(point(1.9f32, -1.9).as_i32() == (1, -1));
+        assert!(point(-1.5f32, 1.5).as_u32() == (0, 1));
+        assert!(point(1e20f64, -1e20).as_i32() == (i32::MAX, i32::MIN));
+        assert!(point(1e20f64, f64::NAN).as_u32() == (u32::MAX, 0));
+        assert!(point(-7i64, 3).as_u32() == (0, 3));
+    }
+
+    #[test]
+    fn try_cast_test() {
+        assert!(point(1, 2).try_cast::<u8>() == Ok(point(1, 2)));
+        let e = point(-1, 2).try_cast::<u8>().unwrap_err();
+        assert!(e.type_name() == "Point" && e.component() == "x");
+        let e = point(1, 256).try_cast::<u8>().unwrap_err();
+        assert!(e.type_name() == "Point" && e.component() == "y");
+        assert!(point(1, 2).cast::<u8>().is_some());
+    }
+
+    #[test]
+    fn wrapping_test() {
+        assert!(point(i32::MAX, 0).wrapping_add((1, -1)) == (i32::MIN, -1));
+        assert!(point(0u8, 5).wrapping_sub(vector(1, 10)) == (255, 251));
+        assert!(point(200u8, 3).wrapping_mul(2) == (144, 6));
+    }
+
+    #[test]
+    fn wrap_to_test() {
+        let s = size(10, 5);
+        assert!(point(0, 0).wrap_to(s) == (0, 0));
+        assert!(point(9, 4).wrap_to(s) == (9, 4));
+        assert!(point(10, 5).wrap_to(s) == (0, 0));
+        assert!(point(-1, -1).wrap_to(s) == (9, 4));
+        assert!(point(-10, -5).wrap_to(s) == (0, 0));
+        assert!(point(-11, -6).wrap_to(s) == (9, 4));
+        assert!(point(23, 7).wrap_to(s) == (3, 2));
+        assert!(point(-1_000_000_001, i32::MIN).wrap_to(s) == (9, 2));
+        assert!(point(i32::MIN, i32::MAX).wrap_to((7, 7)) == (5, 1));
+        assert!(point(-0.5f32, 12.5).wrap_to((10.0, 5.0)) == (9.5, 2.5));
+    }
+
+    #[test]
+    #[should_panic]
+    fn wrap_to_zero_size_test() {
+        point(1, 2).wrap_to((10, 0));
+    }
+
+    #[test]
+    fn const_test() {
+        const A: Point<i32> = Point::new(1, 2);
+        const B: Point<i32> = point(3, 4);
+        assert!(A == (1, 2) && B == (3, 4));
+    }
+
+    #[test]
+    fn lerp_test() {
+        let a = point(0.3f64, 10.0);
+        let b = point(-0.9f64, 20.0);
+        assert!(a.lerp(b, 0.0) == a && a.lerp(b, 1.0) == b);
+        assert!(point(0.0f64, 10.0).lerp((1.0, 20.0), 0.5) == (0.5, 15.0));
+        assert!(point(0.0f64, 0.0).lerp((1.0, 1.0), -1.0) == (-1.0, -1.0));
+        assert!(point(0.0f64, 0.0).lerp_clamped((1.0, 1.0), -1.0) == (0.0, 0.0));
+        assert!(point(0.0f64, 1.0).lerp((1.0, 0.0), 2.0) == (2.0, -1.0));
+        assert!(point(0u32, 0).lerp((10, 10), 2) == (20, 20));
+        assert!(point(10u32, 20).lerp((5, 30), 2) == (0, 40));
+        assert!(point(10u32, 20).lerp((5, 30), 1) == (5, 30));
+        assert!(point(10i32, 20).lerp((5, 30), 3) == (-5, 50));
+    }
+
+    #[test]
+    #[should_panic]
+    fn lerp_unsigned_negative_test() {
+        point(10u32, 0).lerp((0, 0), 2);
+    }
+
+    #[test]
+    fn min_max_test() {
+        let a = point(1.0f64, 20.0);
+        assert!(a.min((10.0, 2.0)) == (1.0, 2.0) && a.max((10.0, 2.0)) == (10.0, 20.0));
+        assert!(point(3, 7).min_element() == 3 && point(3, 7).max_element() == 7);
+    }
+
+    #[test]
+    fn clamp_test() {
+        let lo = point(0, 0);
+        let hi = point(100, 50);
+        assert!(point(-10, 20).clamp(lo, hi) == (0, 20));
+        assert!(point(120, 80).clamp(lo, hi) == (100, 50));
+        assert!(point(5, 5).clamp((8, 8), (2, 2)) == (2, 2));
+    }
+
+    #[test]
+    fn centroid_test() {
+        let c = Point::centroid([point(0.0f32, 0.0), point(4.0, -2.0), point(-1.0, 5.0)]);
+        assert!(c == Some(point(1.0, 1.0)));
+        assert!(Point::centroid([point(-4, 8), point(2, -2)]) == Some(point(-1, 3)));
+        assert!(Point::<f64>::centroid([]).is_none());
+    }
+
+    #[test]
+    fn approx_eq_test() {
+        let a = point(0.1f64 + 0.2, 1.0);
+        assert!(a != (0.3, 1.0));
+        assert!(a.approx_eq(&point(0.3, 1.0), f64::EPSILON));
+        assert!(!a.approx_eq(&point(0.3, 1.001), f64::EPSILON));
+    }
+
+    #[test]
+    fn with_test() {
+        let a = point(1, 2);
+        assert!(a.yx() == (2, 1));
+        assert!(a.with_x(10) == (10, 2));
+        assert!(a.with_y(20) == (1, 20));
+    }
+
+    #[test]
+    fn checked_saturating_test() {
+        let a = point(250u8, 5);
+        assert!(a.checked_add((5, 0)) == Some(point(255, 5)));
+        assert!(a.checked_add((6, 0)).is_none());
+        assert!(a.checked_sub(vector(0, 6)).is_none());
+        assert!(a.checked_sub(vector(250, 5)) == Some(point(0, 0)));
+        assert!(a.checked_sub_point(point(10, 5)) == Some(vector(240, 0)));
+        assert!(a.checked_sub_point(point(0, 6)).is_none());
+        assert!(a.saturating_add((10, 10)) == (255, 15));
+        assert!(a.saturating_sub(vector(10, 10)) == (240, 0));
+        assert!(a.saturating_sub_point(point(10, 10)) == vector(240, 0));
+        assert!(a.wrapping_add((6, 0)) == (0, 5));
+        assert!(point(0u8, 0).wrapping_sub((1, 0)) == (255, 0));
+    }
+
+    #[test]
+    fn checked_matches_operators_test() {
+        let a = point(100i8, -100);
+        for v in [vector(27i8, -27), vector(-27, 27), vector(0, 0)] {
+            assert!(a.checked_add(v) == Some(a + v));
+            assert!(a.checked_add(size(v.x, v.y)) == Some(a + size(v.x, v.y)));
+            assert!(a.checked_sub(v) == Some(a - v));
+            assert!(a.saturating_add(v) == a + v);
+            assert!(a.saturating_sub(v) == a - v);
+        }
+        for b in [point(0i8, 0), point(-27, 27), point(100, -100)] {
+            assert!(a.checked_sub_point(b) == Some(a - b));
+            assert!(a.saturating_sub_point(b) == a - b);
+        }
+        assert!(a.checked_sub_point(point(-28, 0)).is_none());
+        assert!(a.saturating_sub_point(point(-28, 28)) == vector(127, -128));
+    }
+
+    #[test]
+    fn ord_test() {
+        use std::collections::{BTreeSet, HashSet};
+        let v = [
+            point(2, 1),
+            point(1, 5),
+            point(1, -3),
+            point(-4, 9),
+            point(2, 1),
+        ];
+        let set = v.iter().copied().collect::<BTreeSet<_>>();
+        let sorted = set.into_iter().collect::<Vec<_>>();
+        assert!(sorted == [point(-4, 9), point(1, -3), point(1, 5), point(2, 1)]);
+        assert!(v.iter().collect::<HashSet<_>>().len() == 4);
+        let mut v = [
+            point(0.5f32, 1.0),
+            point(-0.0, 2.0),
+            point(-1.0, 3.0),
+            point(0.5, -1.0),
+        ];
+        v.sort_by_key(|p| p.total_cmp_key());
+        assert!(
+            v == [
+                point(-1.0, 3.0),
+                point(-0.0, 2.0),
+                point(0.5, -1.0),
+                point(0.5, 1.0)
+            ]
+        );
+    }
+
+    #[test]
+    fn round_test() {
+        let a = point(-1.5f32, 2.5);
+        assert!(a.floor() == (-2.0, 2.0));
+        assert!(a.ceil() == (-1.0, 3.0));
+        assert!(a.round() == (-2.0, 3.0));
+        assert!(a.cast::<i32>() == Some(point(-1, 2)));
+    }
+
+    #[test]
+    fn distance_test() {
+        assert!(point(1.0f32, 2.0).distance((4.0, 6.0)) == 5.0);
+        assert!(point(-1.0f64, -1.0).distance((-1.0, -1.0)) == 0.0);
+        assert!(point(1, 2).distance_squared((4, 6)) == 25);
+        assert!(point(4u32, 6).distance_squared((1, 2)) == 25);
+        assert!(point(1u32, 6).distance_squared((4, 2)) == 25);
+        assert!(point(0.5f32, 0.0).distance_squared((0.0, 0.0)) == 0.25);
+    }
+
+    #[test]
+    fn manhattan_chebyshev_test() {
+        assert!(point(1, 2).manhattan_distance((-3, 5)) == 7);
+        assert!(point(1, 2).chebyshev_distance((-3, 5)) == 4);
+        // `2 - 5` would underflow
+        assert!(point(2u32, 10).manhattan_distance((5, 4)) == 9);
+        assert!(point(2u32, 10).chebyshev_distance((5, 4)) == 6);
+        assert!(point(0u8, 255).chebyshev_distance((255, 0)) == 255);
+        assert!(point(1.5f32, 0.0).manhattan_distance((0.0, -1.0)) == 2.5);
+    }
+
+    #[test]
+    fn midpoint_test() {
+        assert!(point(1.0f32, -2.0).midpoint((4.0, 6.0)) == (2.5, 2.0));
+        assert!(point(0, 0).midpoint((5, -5)) == (2, -3));
+        assert!(point(u8::MAX, 0).midpoint((u8::MAX - 2, 1)) == (u8::MAX - 1, 0));
+        assert!(point(i32::MAX, i32::MIN).midpoint((i32::MAX, i32::MIN)) == (i32::MAX, i32::MIN));
+        assert!(point(i32::MIN, 0).midpoint((i32::MAX, 0)) == (-1, 0));
+        assert!(point(i32::MAX, i32::MIN).midpoint((i32::MIN + 1, i32::MAX)) == (0, -1));
+        assert!(point(i8::MIN, -5).midpoint((i8::MAX, 4)) == (-1, -1));
+        assert!(point(-5, 5).midpoint((2, -2)) == (-2, 1));
+        assert!(point(f64::MIN, -1.0).midpoint((f64::MAX, 2.0)) == (0.0, 0.5));
+    }
+
+    #[test]
+    fn rotate_around_test() {
+        use core::f32::consts::{FRAC_PI_2, TAU};
+        let center = point(0.5f32, 0.5);
+        let square = [
+            point(0.0, 0.0),
+            point(1.0, 0.0),
+            point(1.0, 1.0),
+            point(0.0, 1.0),
+        ];
+        for (i, p) in square.iter().enumerate() {
+            let r = p.rotate_around(center, FRAC_PI_2);
+            assert!(r.approx_eq(&square[(i + 1) % 4], f32::EPSILON * 4.0));
+        }
+        let p = point(3.0f32, -2.0);
+        assert!(p.rotate_around(p, 1.0) == p);
+        assert!(p.rotate_around((1.0, 1.0), TAU).approx_eq(&p, 1e-5));
+    }
+
+    #[test]
+    fn snap_to_grid_test() {
+        assert!(point(37, -5).snap_to_grid((16, 16)) == (32, -16));
+        assert!(point(32u32, 15).snap_to_grid((16, 8)) == (32, 8));
+        assert!(point(2.5f32, -0.5).snap_to_grid((1.0, 0.25)) == (2.0, -0.5));
+        assert!(point(2.5f32, -0.6).snap_to_grid((1.0, 0.5)) == (2.0, -1.0));
+    }
+
+    #[test]
+    fn neighbors_test() {
+        let v = point(5, 5).neighbors4().collect::<Vec<_>>();
+        assert!(v == [point(4, 5), point(6, 5), point(5, 4), point(5, 6)]);
+        let v = point(5, 5).neighbors8().collect::<Vec<_>>();
+        assert!(v.len() == 8 && v[0] == (4, 4) && v[7] == (6, 6));
+        assert!(!v.contains(&point(5, 5)));
+        let v = point(0u32, 0).neighbors4().collect::<Vec<_>>();
+        assert!(v == [point(1, 0), point(0, 1)]);
+        let v = point(0u32, 0).neighbors8().collect::<Vec<_>>();
+        assert!(v == [point(1, 0), point(0, 1), point(1, 1)]);
+        assert!(point(u8::MAX, 0).neighbors8().count() == 3);
+    }
+
     #[test]
     fn map_test() {
         assert!(point(1, 2).map(|x| x + 1) == point(2, 3));
     }
 
+    #[test]
+    fn zero_test() {
+        fn zero<T: Zero>() -> T {
+            Zero::zero()
+        }
+        assert!(zero::<Point<i32>>() == (0, 0));
+        assert!(zero::<Point<f64>>() == (0.0, 0.0));
+        assert!(Zero::is_zero(&point(0.0, 0.0)));
+        assert!(!Zero::is_zero(&point(1, 0)));
+    }
+
+    #[test]
+    fn as_array_test() {
+        let mut a = point(1, 2);
+        assert!(*a.as_array() == [1, 2]);
+        a.as_array_mut()[1] = 10;
+        assert!(a.y == 10);
+        assert!(a.into_iter().eq(a.as_array().iter().copied()));
+    }
+
+    #[test]
+    fn index_test() {
+        let mut a = point(1, 2);
+        assert!(a[0] == 1 && a[1] == 2);
+        a[0] = 10;
+        assert!(a.x == 10);
+    }
+
+    #[test]
+    #[should_panic(expected = "Point index out of range: 2")]
+    fn index_out_of_range_test() {
+        let a = point(1, 2);
+        let _ = a[2];
+    }
+
+    #[test]
+    fn into_iter_test() {
+        let a = point(1, 2);
+        let it = a.into_iter();
+        assert!(it.len() == 2 && it.size_hint() == (2, Some(2)));
+        assert!(a.into_iter().collect::<Vec<_>>() == [a.x, a.y]);
+        assert!(a.iter().copied().collect::<Vec<_>>() == [1, 2]);
+        assert!(a.iter().len() == 2);
+    }
+
+    #[test]
+    fn from_str_round_trip_test() {
+        for p in [point(0, 0), point(-10, 20), point(i32::MIN, i32::MAX)] {
+            assert!(format!("{},{}", p.x, p.y).parse::<Point<i32>>() == Ok(p));
+        }
+        for p in [
+            point(-3.25f64, 1e10),
+            point(0.1, -f64::MAX),
+            point(f64::MIN_POSITIVE, 0.0),
+        ] {
+            assert!(format!("{}, {}", p.x, p.y).parse::<Point<f64>>() == Ok(p));
+        }
+    }
+
+    #[test]
+    fn from_str_test() {
+        assert!("10,20".parse::<Point<i32>>() == Ok(point(10, 20)));
+        assert!("10 , 20".parse::<Point<i32>>() == Ok(point(10, 20)));
+        let e = "".parse::<Point<i32>>().unwrap_err();
+        assert!(e.kind() == ParseErrorKind::Missing && e.component() == Some("x"));
+        let e = "10,abc".parse::<Point<i32>>().unwrap_err();
+        assert!(e.kind() == ParseErrorKind::Invalid && e.component() == Some("y"));
+        assert!(e.type_name() == "Point");
+        let e = "10,20,30".parse::<Point<i32>>().unwrap_err();
+        assert!(e.kind() == ParseErrorKind::Trailing && e.component().is_none());
+        let e = "-1,0".parse::<Point<u32>>().unwrap_err();
+        assert!(e.kind() == ParseErrorKind::Invalid && e.component() == Some("x"));
+    }
+
     #[test]
     fn eq_test() {
         assert!(point(1, 2) == point(1, 2));
@@ -257,6 +1069,12 @@ mod tests {
         assert!(c == point(5, 5));
     }
 
+    #[test]
+    fn neg_test() {
+        assert!(-point(1, -2) == (-1, 2));
+        assert!(-point(1.5f32, 0.0) == (-1.5, 0.0));
+    }
+
     #[test]
     fn mul_test() {
         let a = point(1, 2);
@@ -264,6 +1082,12 @@ mod tests {
         assert!(b == (2, 4));
     }
 
+    #[test]
+    fn left_mul_test() {
+        assert!(3i64 * point(1i64, -2) == point(1, -2) * 3);
+        assert!(2.0f64 * point(1.0f64, 2.5) == (2.0, 5.0));
+    }
+
     #[test]
     fn div_test() {
         let a = point(2, 6);
@@ -0,0 +1,350 @@
+use crate::*;
+
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point3<T> {
+    #[inline]
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+
+    #[inline]
+    pub fn map<R>(self, mut f: impl FnMut(T) -> R) -> Point3<R> {
+        Point3::new(f(self.x), f(self.y), f(self.z))
+    }
+
+    #[inline]
+    pub fn truncate(self) -> Point<T> {
+        Point::new(self.x, self.y)
+    }
+}
+
+impl<T> Point<T> {
+    #[inline]
+    pub fn extend(self, z: T) -> Point3<T> {
+        Point3::new(self.x, self.y, z)
+    }
+}
+
+impl<T: ToPrimitive> Point3<T> {
+    #[inline]
+    pub fn cast<U: NumCast>(self) -> Option<Point3<U>> {
+        Some(Point3::new(
+            U::from(self.x)?,
+            U::from(self.y)?,
+            U::from(self.z)?,
+        ))
+    }
+}
+
+impl<T> From<(T, T, T)> for Point3<T> {
+    #[inline]
+    fn from(src: (T, T, T)) -> Point3<T> {
+        Point3::new(src.0, src.1, src.2)
+    }
+}
+
+impl<T: Copy> From<[T; 3]> for Point3<T> {
+    #[inline]
+    fn from(src: [T; 3]) -> Point3<T> {
+        Point3::new(src[0], src[1], src[2])
+    }
+}
+
+impl<T> From<Vector3<T>> for Point3<T> {
+    #[inline]
+    fn from(src: Vector3<T>) -> Point3<T> {
+        Point3::new(src.x, src.y, src.z)
+    }
+}
+
+impl<T> PartialEq<(T, T, T)> for Point3<T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &(T, T, T)) -> bool {
+        self.x == other.0 && self.y == other.1 && self.z == other.2
+    }
+}
+
+impl<T> PartialEq<[T; 3]> for Point3<T>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &[T; 3]) -> bool {
+        self.x == other[0] && self.y == other[1] && self.z == other[2]
+    }
+}
+
+impl<T> PartialEq<Point3<T>> for (T, T, T)
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Point3<T>) -> bool {
+        self.0 == other.x && self.1 == other.y && self.2 == other.z
+    }
+}
+
+impl<T> PartialEq<Point3<T>> for [T; 3]
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Point3<T>) -> bool {
+        self[0] == other.x && self[1] == other.y && self[2] == other.z
+    }
+}
+
+impl<T> core::ops::Add<Point3<T>> for Point3<T>
+where
+    T: core::ops::Add<T, Output = T>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Point3<T>) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl<T, U> core::ops::Add<U> for Point3<T>
+where
+    T: core::ops::Add<T, Output = T>,
+    U: Into<Size3<T>>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: U) -> Self {
+        let rhs = rhs.into();
+        Self::new(self.x + rhs.width, self.y + rhs.height, self.z + rhs.depth)
+    }
+}
+
+impl<T> core::ops::Sub<Point3<T>> for Point3<T>
+where
+    T: core::ops::Sub<T, Output = T>,
+{
+    type Output = Vector3<T>;
+
+    #[inline]
+    fn sub(self, rhs: Point3<T>) -> Vector3<T> {
+        Vector3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl<T> core::ops::Sub<Vector3<T>> for Point3<T>
+where
+    T: core::ops::Sub<T, Output = T>,
+{
+    type Output = Point3<T>;
+
+    #[inline]
+    fn sub(self, rhs: Vector3<T>) -> Point3<T> {
+        Point3::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl<T> core::ops::Mul<T> for Point3<T>
+where
+    T: core::ops::Mul<T, Output = T> + Copy,
+{
+    type Output = Point3<T>;
+
+    #[inline]
+    fn mul(self, rhs: T) -> Self {
+        Point3::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl<T> core::ops::Div<T> for Point3<T>
+where
+    T: core::ops::Div<T, Output = T> + Copy,
+{
+    type Output = Point3<T>;
+
+    #[inline]
+    fn div(self, rhs: T) -> Self {
+        Point3::new(self.x / rhs, self.y / rhs, self.z / rhs)
+    }
+}
+
+impl<T, U> core::ops::AddAssign<U> for Point3<T>
+where
+    T: core::ops::AddAssign<T>,
+    U: Into<Size3<T>>,
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: U) {
+        let rhs = rhs.into();
+        self.x += rhs.width;
+        self.y += rhs.height;
+        self.z += rhs.depth;
+    }
+}
+
+impl<T> core::ops::SubAssign<Vector3<T>> for Point3<T>
+where
+    T: core::ops::SubAssign<T>,
+{
+    #[inline]
+    fn sub_assign(&mut self, rhs: Vector3<T>) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+        self.z -= rhs.z;
+    }
+}
+
+impl<T> core::ops::MulAssign<T> for Point3<T>
+where
+    T: core::ops::MulAssign<T> + Copy,
+{
+    #[inline]
+    fn mul_assign(&mut self, rhs: T) {
+        self.x *= rhs;
+        self.y *= rhs;
+        self.z *= rhs;
+    }
+}
+
+impl<T> core::ops::DivAssign<T> for Point3<T>
+where
+    T: core::ops::DivAssign<T> + Copy,
+{
+    #[inline]
+    fn div_assign(&mut self, rhs: T) {
+        self.x /= rhs;
+        self.y /= rhs;
+        self.z /= rhs;
+    }
+}
+
+#[inline]
+pub const fn point3<T>(x: T, y: T, z: T) -> Point3<T> {
+    Point3::new(x, y, z)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn const_test() {
+        const A: Point3<i32> = Point3::new(1, 2, 3);
+        const B: Point3<i32> = point3(1, 2, 3);
+        assert!(A == (1, 2, 3) && B == (1, 2, 3));
+    }
+
+    #[test]
+    fn map_test() {
+        assert!(point3(1, 2, 3).map(|x| x + 1) == point3(2, 3, 4));
+    }
+
+    #[test]
+    fn cast_test() {
+        assert!(point3(1.5f32, -2.0, 3.0).cast::<i32>() == Some(point3(1, -2, 3)));
+        assert!(point3(0, 0, -1).cast::<u8>().is_none());
+    }
+
+    #[test]
+    fn extend_truncate_test() {
+        assert!(point(1, 2).extend(3) == (1, 2, 3));
+        assert!(point3(1, 2, 3).truncate() == (1, 2));
+    }
+
+    #[test]
+    fn eq_test() {
+        assert!(point3(1, 2, 3) == point3(1, 2, 3));
+        assert!(point3(1, 2, 3) == (1, 2, 3));
+        assert!(point3(1, 2, 3) == [1, 2, 3]);
+        assert!((1, 2, 3) == point3(1, 2, 3));
+        assert!([1, 2, 3] == point3(1, 2, 3));
+    }
+
+    #[test]
+    fn add_test() {
+        let a = point3(1, 2, 3);
+        let b = point3(6, 7, 8);
+        let c = a + b;
+        assert!(c == (7, 9, 11));
+        let a = point3(1, 2, 3);
+        let b = size3(6, 7, 8);
+        let c = a + b;
+        assert!(c == (7, 9, 11));
+        let a = point3(1, 2, 3);
+        let b = vector3(6, 7, 8);
+        let c = a + b;
+        assert!(c == (7, 9, 11));
+        let c = a + (6, 7, 8);
+        assert!(c == (7, 9, 11));
+    }
+
+    #[test]
+    fn sub_test() {
+        let a = point3(1, 2, 3);
+        let b = point3(6, 7, 8);
+        let c = b - a;
+        assert!(c == vector3(5, 5, 5));
+        let a = point3(6, 7, 8);
+        let b = vector3(1, 2, 3);
+        let c = a - b;
+        assert!(c == point3(5, 5, 5));
+    }
+
+    #[test]
+    fn mul_test() {
+        let a = point3(1, 2, 3);
+        let b = a * 2;
+        assert!(b == (2, 4, 6));
+    }
+
+    #[test]
+    fn div_test() {
+        let a = point3(2, 6, 8);
+        let b = a / 2;
+        assert!(b == (1, 3, 4));
+    }
+
+    #[test]
+    fn add_assign_test() {
+        let mut a = point3(1, 2, 3);
+        let b = size3(6, 7, 8);
+        a += b;
+        assert!(a == (7, 9, 11));
+        let mut a = point3(1, 2, 3);
+        let b = vector3(6, 7, 8);
+        a += b;
+        assert!(a == (7, 9, 11));
+        let mut a = point3(1, 2, 3);
+        a += (6, 7, 8);
+        assert!(a == (7, 9, 11));
+    }
+
+    #[test]
+    fn sub_assign_test() {
+        let mut a = point3(6, 7, 8);
+        let b = vector3(1, 2, 3);
+        a -= b;
+        assert!(a == (5, 5, 5));
+    }
+
+    #[test]
+    fn mul_assign_test() {
+        let mut a = point3(1, 2, 3);
+        a *= 2;
+        assert!(a == (2, 4, 6));
+    }
+
+    #[test]
+    fn div_assign_test() {
+        let mut a = point3(3, 6, 9);
+        a /= 3;
+        assert!(a == (1, 2, 3));
+    }
+}
@@ -0,0 +1,277 @@
+use crate::*;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+/// A polygon given by its vertices in order, with an implicit edge from the last vertex to the
+/// first one.
+///
+/// Either winding order is accepted. Convex polygons are collided by the separating axis theorem
+/// and concave ones by testing their edges, so both give exact results.
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Polygon<T> {
+    vertices: Vec<Point<T>>,
+    convex: bool,
+}
+
+impl<T> Polygon<T> {
+    #[inline]
+    pub fn vertices(&self) -> &[Point<T>] {
+        &self.vertices
+    }
+
+    #[inline]
+    pub fn into_vertices(self) -> Vec<Point<T>> {
+        self.vertices
+    }
+
+    /// Returns `true` if the polygon has no vertices.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.vertices.is_empty()
+    }
+}
+
+impl<T: Copy> Polygon<T> {
+    /// Iterates the edges from the first vertex, ending with the one back to the first vertex.
+    #[inline]
+    pub fn edges(&self) -> impl Iterator<Item = Segment<T>> + '_ {
+        let next = self.vertices.iter().cycle().skip(1);
+        self.vertices
+            .iter()
+            .zip(next)
+            .map(|(&a, &b)| Segment::from_start_end(a, b))
+    }
+}
+
+impl<T: Scalar + Zero> Polygon<T> {
+    pub fn new(vertices: Vec<Point<T>>) -> Self {
+        let convex = convex(&vertices);
+        Self { vertices, convex }
+    }
+
+    /// Creates the polygon of the corners of `rect` in the order of `Rect::corners`.
+    #[inline]
+    pub fn from_rect(rect: &Rect<T>) -> Self {
+        Self::new(rect.normalized().corners().to_vec())
+    }
+
+    /// Returns the smallest rect containing the polygon, or `None` if it has no vertices.
+    #[inline]
+    pub fn bounding_rect(&self) -> Option<Rect<T>> {
+        Rect::bounding(self.vertices.iter().copied())
+    }
+
+    /// Returns `true` if the polygon is convex and does not intersect itself.
+    ///
+    /// Collinear vertices are allowed, and polygons with fewer than three vertices are convex.
+    #[inline]
+    pub fn is_convex(&self) -> bool {
+        self.convex
+    }
+
+    /// Returns `true` if `p` is inside or on the boundary of the polygon.
+    ///
+    /// The inside of a self-intersecting polygon is decided by the even-odd rule.
+    pub fn contains_point(&self, p: impl Into<Point<T>>) -> bool {
+        let p = p.into();
+        let mut inside = false;
+        for e in self.edges() {
+            if e.contains_point(p) {
+                return true;
+            }
+            let (a, b) = (e.start, e.end);
+            if (a.y > p.y) != (b.y > p.y) {
+                // the edge crosses the horizontal line through `p`, so it is on the +x side of
+                // `p` if `p` is on the left of the edge going +y
+                let o = e.orientation(p);
+                if (b.y > a.y && o == Ordering::Greater) || (b.y < a.y && o == Ordering::Less) {
+                    inside = !inside;
+                }
+            }
+        }
+        inside
+    }
+}
+
+impl<T: Float> Polygon<T> {
+    /// Returns twice the signed area, which is positive for vertices counterclockwise in y-up
+    /// coordinates.
+    #[inline]
+    fn doubled_signed_area(&self) -> T {
+        let Some(&o) = self.vertices.first() else {
+            return T::zero();
+        };
+        self.edges()
+            .fold(T::zero(), |acc, e| acc + (e.start - o).cross(e.end - o))
+    }
+
+    /// Returns the area by the shoelace formula. For self-intersecting polygons, regions wound in
+    /// opposite directions cancel out.
+    #[inline]
+    pub fn area(&self) -> T {
+        self.doubled_signed_area().abs() / (T::one() + T::one())
+    }
+
+    /// Returns the center of mass, or the average of the vertices if the area is zero, or `None`
+    /// if the polygon has no vertices.
+    pub fn centroid(&self) -> Option<Point<T>> {
+        let o = *self.vertices.first()?;
+        let a = self.doubled_signed_area();
+        if a == T::zero() {
+            return Point::centroid(self.vertices.iter().copied());
+        }
+        let sum = self.edges().fold(Vector::zero(), |acc, e| {
+            let (p, q) = (e.start - o, e.end - o);
+            acc + (p + q) * p.cross(q)
+        });
+        Some(o + sum / (a * T::from(3).unwrap()))
+    }
+}
+
+/// Returns `true` if all turns have the same direction and the edges go around only once.
+fn convex<T: Scalar + Zero>(vertices: &[Point<T>]) -> bool {
+    let n = vertices.len();
+    if n < 3 {
+        return true;
+    }
+    let mut turn = Ordering::Equal;
+    let mut flips = [0; 2];
+    let mut first = [Ordering::Equal; 2];
+    let mut last = [Ordering::Equal; 2];
+    for i in 0..n {
+        let (a, b, c) = (vertices[i], vertices[(i + 1) % n], vertices[(i + 2) % n]);
+        let o = Segment::from_start_end(a, b).orientation(c);
+        if o != Ordering::Equal {
+            if turn != Ordering::Equal && o != turn {
+                return false;
+            }
+            turn = o;
+        }
+        let dir = [
+            b.x.partial_cmp(&a.x).unwrap_or(Ordering::Equal),
+            b.y.partial_cmp(&a.y).unwrap_or(Ordering::Equal),
+        ];
+        for k in 0..2 {
+            if dir[k] == Ordering::Equal {
+                continue;
+            }
+            if first[k] == Ordering::Equal {
+                first[k] = dir[k];
+            } else if dir[k] != last[k] {
+                flips[k] += 1;
+            }
+            last[k] = dir[k];
+        }
+    }
+    // a convex polygon goes back and forth exactly once along each axis
+    (0..2).all(|k| flips[k] + (last[k] != first[k]) as usize <= 2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    fn pentagon() -> Polygon<i32> {
+        Polygon::new(vec![
+            point(0, -10),
+            point(10, -3),
+            point(6, 8),
+            point(-6, 8),
+            point(-10, -3),
+        ])
+    }
+
+    #[test]
+    fn triangle_test() {
+        let t = Polygon::new(vec![point(0.0f32, 0.0), point(4.0, 0.0), point(0.0, 3.0)]);
+        assert!(t.area() == 6.0);
+        let c = t.centroid().unwrap();
+        assert!(c.approx_eq(&point(4.0 / 3.0, 1.0), 1e-6));
+        assert!(t.is_convex());
+        assert!(t.bounding_rect() == Some(rect((0.0, 0.0), (4.0, 3.0))));
+        assert!(t.contains_point((1.0, 1.0)));
+        assert!(!t.contains_point((3.0, 3.0)));
+    }
+
+    #[test]
+    fn pentagon_test() {
+        let p = pentagon();
+        assert!(p.is_convex());
+        assert!(p.contains_point((0, 0)));
+        assert!(p.contains_point((0, 8)));
+        assert!(!p.contains_point((0, 9)));
+        assert!(!p.contains_point((10, 8)));
+        assert!(p.bounding_rect() == Some(rect((-10, -10), (20, 18))));
+        let reversed = Polygon::new(p.vertices().iter().rev().copied().collect());
+        assert!(reversed.is_convex());
+        assert!(reversed.contains_point((0, 0)));
+    }
+
+    #[test]
+    fn contains_point_on_edge_test() {
+        let p = Polygon::new(vec![point(0, 0), point(10, 0), point(10, 10), point(0, 10)]);
+        assert!(p.contains_point((5, 0)));
+        assert!(p.contains_point((10, 5)));
+        assert!(p.contains_point((0, 10)));
+        assert!(!p.contains_point((11, 5)));
+        let t = Polygon::new(vec![point(0u32, 0), point(10, 10), point(0, 10)]);
+        assert!(t.contains_point((5, 5)));
+        assert!(t.contains_point((2, 7)));
+        assert!(!t.contains_point((7, 2)));
+    }
+
+    #[test]
+    fn concave_test() {
+        // an L shape
+        let l = Polygon::new(vec![
+            point(0, 0),
+            point(10, 0),
+            point(10, 4),
+            point(4, 4),
+            point(4, 10),
+            point(0, 10),
+        ]);
+        assert!(!l.is_convex());
+        assert!(l.contains_point((2, 8)));
+        assert!(l.contains_point((8, 2)));
+        assert!(!l.contains_point((8, 8)));
+        assert!(l.contains_point((4, 7)));
+        let l = Polygon::new(l.into_vertices().into_iter().map(|p| p.as_f32()).collect());
+        assert!(l.area() == 64.0);
+    }
+
+    #[test]
+    fn is_convex_test() {
+        let star = Polygon::new(vec![
+            point(0, -10),
+            point(6, 8),
+            point(-10, -3),
+            point(10, -3),
+            point(-6, 8),
+        ]);
+        assert!(!star.is_convex());
+        assert!(Polygon::from_rect(&rect((0, 0), (5, 5))).is_convex());
+        assert!(
+            Polygon::new(vec![point(0, 0), point(5, 0), point(10, 0), point(10, 5)]).is_convex()
+        );
+        assert!(Polygon::<i32>::new(vec![]).is_convex());
+    }
+
+    #[test]
+    fn from_rect_test() {
+        let p = Polygon::from_rect(&rect((10, 20), (-5, 5)));
+        assert!(p.vertices() == [point(5, 20), point(10, 20), point(10, 25), point(5, 25)]);
+        assert!(p.edges().count() == 4);
+        assert!(p.edges().last() == Some(segment((5, 25), (5, 20))));
+    }
+
+    #[test]
+    fn centroid_test() {
+        let p = Polygon::from_rect(&rect((2.0f64, 4.0), (6.0, 2.0)));
+        assert!(p.centroid() == Some(point(5.0, 5.0)));
+        let line = Polygon::new(vec![point(0.0f64, 0.0), point(2.0, 2.0)]);
+        assert!(line.centroid() == Some(point(1.0, 1.0)));
+        assert!(Polygon::<f32>::new(vec![]).centroid().is_none());
+    }
+}
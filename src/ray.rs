@@ -0,0 +1,231 @@
+use crate::*;
+
+/// A half-line from `origin` toward `direction`. `direction` does not need to be normalized.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Debug)]
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ray<T> {
+    pub origin: Point<T>,
+    pub direction: Vector<T>,
+}
+
+/// The first hit of a ray on a shape.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct RayHit<T> {
+    /// The hit is at `origin + direction * t`, so `t` is in units of the length of `direction`.
+    pub t: T,
+    pub point: Point<T>,
+    /// The unit normal of the surface facing the ray, or zero if the ray starts inside the shape.
+    pub normal: Vector<T>,
+}
+
+impl<T> Ray<T> {
+    #[inline]
+    pub fn new(origin: impl Into<Point<T>>, direction: impl Into<Vector<T>>) -> Self {
+        Self {
+            origin: origin.into(),
+            direction: direction.into(),
+        }
+    }
+
+    /// A `const` variant of `Ray::new`.
+    #[inline]
+    pub const fn from_origin_direction(origin: Point<T>, direction: Vector<T>) -> Self {
+        Self { origin, direction }
+    }
+}
+
+impl<T: Float> Ray<T> {
+    /// Returns `origin + direction * t`.
+    #[inline]
+    pub fn at(&self, t: T) -> Point<T> {
+        self.origin + self.direction * t
+    }
+
+    #[inline]
+    fn inside(&self) -> RayHit<T> {
+        RayHit {
+            t: T::zero(),
+            point: self.origin,
+            normal: Vector::zero(),
+        }
+    }
+
+    /// Returns the first hit on the circumference, or `t == 0` with a zero normal if the ray
+    /// starts inside or on the circle.
+    pub fn cast_circle(&self, circle: &Circle<T>) -> Option<RayHit<T>> {
+        let m = self.origin - circle.center;
+        let c = m.abs_pow2() - circle.radius * circle.radius;
+        if c <= T::zero() {
+            return Some(self.inside());
+        }
+        let a = self.direction.abs_pow2();
+        let b = m.dot(self.direction);
+        if b >= T::zero() || a == T::zero() {
+            return None;
+        }
+        let disc = b * b - a * c;
+        if disc < T::zero() {
+            return None;
+        }
+        let t = (-b - disc.sqrt()) / a;
+        let point = self.at(t);
+        Some(RayHit {
+            t,
+            point,
+            normal: (point - circle.center).normalize(),
+        })
+    }
+
+    /// Returns the first hit on the edges by the slab method, or `t == 0` with a zero normal if
+    /// the ray starts inside or on the rect.
+    pub fn cast_rect(&self, rect: &Rect<T>) -> Option<RayHit<T>> {
+        if rect.contains(&self.origin) {
+            return Some(self.inside());
+        }
+        let (lo, hi) = rect.bounds();
+        let slabs = [
+            (
+                self.origin.x,
+                self.direction.x,
+                lo.x,
+                hi.x,
+                vector(T::one(), T::zero()),
+            ),
+            (
+                self.origin.y,
+                self.direction.y,
+                lo.y,
+                hi.y,
+                vector(T::zero(), T::one()),
+            ),
+        ];
+        let mut near = (T::neg_infinity(), Vector::zero());
+        let mut far = T::infinity();
+        for (o, d, lo, hi, axis) in slabs {
+            if d == T::zero() {
+                if o < lo || o > hi {
+                    return None;
+                }
+                continue;
+            }
+            let (t0, t1) = ((lo - o) / d, (hi - o) / d);
+            let (t0, t1) = (t0.min(t1), t0.max(t1));
+            if t0 > near.0 {
+                near = (t0, if d > T::zero() { -axis } else { axis });
+            }
+            far = far.min(t1);
+        }
+        let (t, normal) = near;
+        if t > far || t < T::zero() {
+            return None;
+        }
+        Some(RayHit {
+            t,
+            point: self.at(t),
+            normal,
+        })
+    }
+
+    /// Returns the hit on `segment`, whose normal faces the origin of the ray.
+    ///
+    /// A ray parallel to the segment never hits it.
+    pub fn cast_segment(&self, segment: &Segment<T>) -> Option<RayHit<T>> {
+        let this = Segment::from_start_end(self.origin, self.at(T::one()));
+        let (t, u) = this.intersect_t(segment)?;
+        if t < T::zero() || u < T::zero() || u > T::one() {
+            return None;
+        }
+        let normal = (segment.end - segment.start).perp().normalize();
+        let normal = if normal.dot(self.direction) > T::zero() {
+            -normal
+        } else {
+            normal
+        };
+        Some(RayHit {
+            t,
+            point: self.at(t),
+            normal,
+        })
+    }
+}
+
+#[inline]
+pub fn ray<T>(origin: impl Into<Point<T>>, direction: impl Into<Vector<T>>) -> Ray<T> {
+    Ray::new(origin, direction)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cast_circle_test() {
+        let c = circle((10.0f32, 0.0), 2.0);
+        let hit = ray((0.0f32, 0.0), (2.0, 0.0)).cast_circle(&c).unwrap();
+        assert!(hit.t == 4.0);
+        assert!(hit.point == point(8.0, 0.0));
+        assert!(hit.normal == vector(-1.0, 0.0));
+        assert!(ray((0.0f32, 0.0), (-1.0, 0.0)).cast_circle(&c).is_none());
+        assert!(ray((0.0f32, 0.0), (1.0, 1.0)).cast_circle(&c).is_none());
+        assert!(ray((0.0f32, 0.0), (0.0, 0.0)).cast_circle(&c).is_none());
+    }
+
+    #[test]
+    fn cast_circle_tangent_test() {
+        let c = circle((0.0f32, 0.0), 1.0);
+        let hit = ray((-5.0f32, 1.0), (1.0, 0.0)).cast_circle(&c).unwrap();
+        assert!(hit.t == 5.0);
+        assert!(hit.point == point(0.0, 1.0));
+        assert!(hit.normal == vector(0.0, 1.0));
+        assert!(ray((-5.0f32, 1.01), (1.0, 0.0)).cast_circle(&c).is_none());
+    }
+
+    #[test]
+    fn cast_circle_inside_test() {
+        let c = circle((0.0f32, 0.0), 1.0);
+        let hit = ray((0.5f32, 0.0), (1.0, 0.0)).cast_circle(&c).unwrap();
+        assert!(hit.t == 0.0 && hit.point == point(0.5, 0.0));
+        assert!(hit.normal == vector(0.0, 0.0));
+    }
+
+    #[test]
+    fn cast_rect_test() {
+        let r = rect((10.0f32, 10.0), (10.0, 10.0));
+        let hit = ray((0.0f32, 15.0), (1.0, 0.0)).cast_rect(&r).unwrap();
+        assert!(hit.t == 10.0 && hit.point == point(10.0, 15.0));
+        assert!(hit.normal == vector(-1.0, 0.0));
+        let hit = ray((15.0f32, 30.0), (0.0, -2.0)).cast_rect(&r).unwrap();
+        assert!(hit.t == 5.0 && hit.point == point(15.0, 20.0));
+        assert!(hit.normal == vector(0.0, 1.0));
+        let hit = ray((0.0f32, 0.0), (1.0, 1.0)).cast_rect(&r).unwrap();
+        assert!(hit.t == 10.0 && hit.point == point(10.0, 10.0));
+        assert!(ray((0.0f32, 15.0), (-1.0, 0.0)).cast_rect(&r).is_none());
+        assert!(ray((0.0f32, 0.0), (1.0, 0.0)).cast_rect(&r).is_none());
+        assert!(ray((0.0f32, 0.0), (1.0, 3.0)).cast_rect(&r).is_none());
+        let flipped = rect((20.0f32, 20.0), (-10.0, -10.0));
+        let a = ray((0.0f32, 15.0), (1.0, 0.0));
+        assert!(a.cast_rect(&flipped) == a.cast_rect(&r));
+    }
+
+    #[test]
+    fn cast_rect_inside_test() {
+        let r = rect((10.0f32, 10.0), (10.0, 10.0));
+        let hit = ray((12.0f32, 12.0), (1.0, 0.0)).cast_rect(&r).unwrap();
+        assert!(hit.t == 0.0 && hit.point == point(12.0, 12.0));
+        assert!(hit.normal == vector(0.0, 0.0));
+    }
+
+    #[test]
+    fn cast_segment_test() {
+        let s = segment((5.0f32, -5.0), (5.0, 5.0));
+        let hit = ray((0.0f32, 0.0), (1.0, 0.0)).cast_segment(&s).unwrap();
+        assert!(hit.t == 5.0 && hit.point == point(5.0, 0.0));
+        assert!(hit.normal == vector(-1.0, 0.0));
+        let hit = ray((10.0f32, 5.0), (-1.0, 0.0)).cast_segment(&s).unwrap();
+        assert!(hit.t == 5.0 && hit.normal == vector(1.0, 0.0));
+        assert!(ray((0.0f32, 0.0), (-1.0, 0.0)).cast_segment(&s).is_none());
+        assert!(ray((0.0f32, 6.0), (1.0, 0.0)).cast_segment(&s).is_none());
+        assert!(ray((0.0f32, 0.0), (0.0, 1.0)).cast_segment(&s).is_none());
+    }
+}
@@ -1,6 +1,13 @@
 use crate::*;
 
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+/// An axis-aligned rectangle.
+///
+/// The accessors use the y-down screen convention: `origin` is the top-left corner and the
+/// endpoint `origin + size` is the bottom-right corner.
+///
+/// A negative size is allowed. `Collision`, `intersection` and `union` treat such a rect like
+/// its `normalized()` form, while the accessors return the raw values.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Debug)]
 #[repr(C)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Rect<T> {
@@ -16,28 +23,161 @@ impl<T> Rect<T> {
             size: size.into(),
         }
     }
+
+    /// A `const` variant of `Rect::new`.
+    #[inline]
+    pub const fn from_origin_size(origin: Point<T>, size: Size<T>) -> Self {
+        Self { origin, size }
+    }
+
+    #[inline]
+    pub fn with_origin(self, origin: impl Into<Point<T>>) -> Self {
+        Self {
+            origin: origin.into(),
+            ..self
+        }
+    }
+
+    #[inline]
+    pub fn with_size(self, size: impl Into<Size<T>>) -> Self {
+        Self {
+            size: size.into(),
+            ..self
+        }
+    }
 }
 
 impl<T> Rect<T>
 where
-    T: std::ops::Add<T, Output = T> + Copy,
+    T: core::ops::Add<T, Output = T> + Copy,
 {
     pub fn endpoint(&self) -> Point<T> {
         self.origin + self.size
     }
+
+    #[inline]
+    pub fn x(&self) -> T {
+        self.origin.x
+    }
+
+    #[inline]
+    pub fn y(&self) -> T {
+        self.origin.y
+    }
+
+    #[inline]
+    pub fn width(&self) -> T {
+        self.size.width
+    }
+
+    #[inline]
+    pub fn height(&self) -> T {
+        self.size.height
+    }
+
+    #[inline]
+    pub fn left(&self) -> T {
+        self.origin.x
+    }
+
+    #[inline]
+    pub fn top(&self) -> T {
+        self.origin.y
+    }
+
+    #[inline]
+    pub fn right(&self) -> T {
+        self.origin.x + self.size.width
+    }
+
+    #[inline]
+    pub fn bottom(&self) -> T {
+        self.origin.y + self.size.height
+    }
+
+    #[inline]
+    pub fn top_left(&self) -> Point<T> {
+        self.origin
+    }
+
+    #[inline]
+    pub fn top_right(&self) -> Point<T> {
+        Point::new(self.right(), self.top())
+    }
+
+    #[inline]
+    pub fn bottom_left(&self) -> Point<T> {
+        Point::new(self.left(), self.bottom())
+    }
+
+    #[inline]
+    pub fn bottom_right(&self) -> Point<T> {
+        self.endpoint()
+    }
+
+    /// Returns the top-left and bottom-right corners with a negative size flipped.
+    #[inline]
+    pub(crate) fn bounds(&self) -> (Point<T>, Point<T>)
+    where
+        T: PartialOrd,
+    {
+        let ep = self.endpoint();
+        (self.origin.min(ep), self.origin.max(ep))
+    }
+
+    /// Returns `true` if neither dimension is negative.
+    #[inline]
+    pub fn is_normalized(&self) -> bool
+    where
+        T: PartialOrd,
+    {
+        let ep = self.endpoint();
+        ep.x >= self.origin.x && ep.y >= self.origin.y
+    }
+
+    /// Returns the corners in the order of top-left, top-right, bottom-right and bottom-left,
+    /// which is clockwise on screen.
+    #[inline]
+    pub fn corners(&self) -> [Point<T>; 4] {
+        [
+            self.top_left(),
+            self.top_right(),
+            self.bottom_right(),
+            self.bottom_left(),
+        ]
+    }
+
+    /// Returns the nearest point to `p` inside or on the boundary of `self`.
+    #[inline]
+    pub fn clamp_point(&self, p: impl Into<Point<T>>) -> Point<T>
+    where
+        T: PartialOrd,
+    {
+        let (lo, hi) = self.bounds();
+        p.into().clamp(lo, hi)
+    }
+
+    /// Same as `clamp_point`.
+    #[inline]
+    pub fn closest_point(&self, p: impl Into<Point<T>>) -> Point<T>
+    where
+        T: PartialOrd,
+    {
+        self.clamp_point(p)
+    }
 }
 
 impl<T> Rect<T>
 where
-    T: std::ops::Sub<T, Output = T> + Copy + PartialOrd,
+    T: core::ops::Sub<T, Output = T> + Copy + PartialOrd,
 {
     #[inline]
     pub fn from_points(a: impl Into<Point<T>>, b: impl Into<Point<T>>) -> Self {
         let a = a.into();
         let b = b.into();
         let (t, u) = {
-            let (tx, ux) = (a.x < b.x).then(|| (a.x, b.x)).unwrap_or((b.x, a.x));
-            let (ty, uy) = (a.y < b.y).then(|| (a.y, b.y)).unwrap_or((b.y, a.y));
+            let (tx, ux) = if a.x < b.x { (a.x, b.x) } else { (b.x, a.x) };
+            let (ty, uy) = if a.y < b.y { (a.y, b.y) } else { (b.y, a.y) };
             (point(tx, ty), point(ux, uy))
         };
         Self::new(t, u - t)
@@ -46,7 +186,7 @@ where
 
 impl<T> Rect<T>
 where
-    T: std::ops::Add<T, Output = T> + Copy,
+    T: core::ops::Add<T, Output = T> + Copy,
 {
     #[inline]
     pub fn translate(&self, d: impl Into<Vector<T>>) -> Self {
@@ -57,19 +197,425 @@ where
 
 impl<T> Rect<T>
 where
-    T: std::ops::Mul<T, Output = T> + Copy,
+    T: core::ops::Mul<T, Output = T> + Copy,
 {
+    /// Scales the size, keeping the origin fixed. See `scale_from_center` and `scale_from`.
     #[inline]
     pub fn scale(&self, x: T, y: T) -> Self {
         Self::new(self.origin, (self.size.width * x, self.size.height * y))
     }
 }
 
+impl<T: Scalar> Rect<T> {
+    /// Returns the overlapping area, or `None` if the rects do not overlap.
+    ///
+    /// Rects touching along an edge or at a corner have a zero-sized intersection, matching
+    /// `Collision::is_crossing`.
+    #[inline]
+    pub fn intersection(&self, other: &Rect<T>) -> Option<Rect<T>> {
+        let (a_lo, a_hi) = self.bounds();
+        let (b_lo, b_hi) = other.bounds();
+        let (lo, hi) = (a_lo.max(b_lo), a_hi.min(b_hi));
+        (lo.x <= hi.x && lo.y <= hi.y).then(|| Rect::new(lo, hi - lo))
+    }
+
+    /// Same as `Collision::is_crossing`.
+    #[inline]
+    pub fn intersects(&self, other: &Rect<T>) -> bool {
+        self.is_crossing(other)
+    }
+
+    /// Flips negative dimensions by moving the origin so that the size is non-negative.
+    #[inline]
+    pub fn normalized(&self) -> Rect<T> {
+        let (lo, hi) = self.bounds();
+        Rect::new(lo, hi - lo)
+    }
+
+    /// Returns the smallest rect containing both rects. Zero-sized rects still contribute their
+    /// origin.
+    #[inline]
+    pub fn union(&self, other: &Rect<T>) -> Rect<T> {
+        let (a_lo, a_hi) = self.bounds();
+        let (b_lo, b_hi) = other.bounds();
+        let (lo, hi) = (a_lo.min(b_lo), a_hi.max(b_hi));
+        Rect::new(lo, hi - lo)
+    }
+
+    /// Returns the smallest rect containing `self` and `p`.
+    #[inline]
+    pub fn union_point(&self, p: impl Into<Point<T>>) -> Rect<T> {
+        let p = p.into();
+        let (lo, hi) = self.bounds();
+        let (lo, hi) = (lo.min(p), hi.max(p));
+        Rect::new(lo, hi - lo)
+    }
+
+    /// Returns the smallest rect containing all `points`, or `None` if `points` is empty.
+    pub fn bounding(points: impl IntoIterator<Item = Point<T>>) -> Option<Rect<T>> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        let (lo, hi) = points.fold((first, first), |(lo, hi), p| (lo.min(p), hi.max(p)));
+        Some(Rect::new(lo, hi - lo))
+    }
+
+    /// Splits into the left and the right parts at the x coordinate `x`, which is clamped to the
+    /// rect.
+    #[inline]
+    pub fn split_h(&self, x: T) -> (Rect<T>, Rect<T>) {
+        let x = partial_max(partial_min(x, self.right()), self.left());
+        let h = self.size.height;
+        (
+            Rect::new(self.origin, (x - self.left(), h)),
+            Rect::new((x, self.top()), (self.right() - x, h)),
+        )
+    }
+
+    /// Splits into the top and the bottom parts at the y coordinate `y`, which is clamped to the
+    /// rect.
+    #[inline]
+    pub fn split_v(&self, y: T) -> (Rect<T>, Rect<T>) {
+        let y = partial_max(partial_min(y, self.bottom()), self.top());
+        let w = self.size.width;
+        (
+            Rect::new(self.origin, (w, y - self.top())),
+            Rect::new((self.left(), y), (w, self.bottom() - y)),
+        )
+    }
+
+    /// Cuts off a strip of width `w` from the left and returns `(strip, remainder)`.
+    ///
+    /// If `w` exceeds the width, the strip is the whole rect and the remainder has zero width at
+    /// the right edge.
+    #[inline]
+    pub fn split_left(&self, w: T) -> (Rect<T>, Rect<T>) {
+        self.split_h(self.left() + partial_min(w, self.size.width))
+    }
+
+    /// Cuts off a strip of height `h` from the top and returns `(strip, remainder)`.
+    ///
+    /// If `h` exceeds the height, the strip is the whole rect and the remainder has zero height
+    /// at the bottom edge.
+    #[inline]
+    pub fn split_top(&self, h: T) -> (Rect<T>, Rect<T>) {
+        self.split_v(self.top() + partial_min(h, self.size.height))
+    }
+
+    /// Scales `self` by `x` and `y` around `anchor`, which stays fixed.
+    #[inline]
+    pub fn scale_from(&self, anchor: impl Into<Point<T>>, x: T, y: T) -> Rect<T> {
+        let anchor = anchor.into();
+        Rect::new(
+            (
+                scale_about(self.origin.x, anchor.x, x),
+                scale_about(self.origin.y, anchor.y, y),
+            ),
+            (self.size.width * x, self.size.height * y),
+        )
+    }
+
+    /// Moves the origin by `-d` and grows the size by `2 * d`.
+    #[inline]
+    pub fn inflate(&self, d: impl Into<Size<T>>) -> Rect<T> {
+        let d = d.into();
+        self.inflate_ltrb(d.width, d.height, d.width, d.height)
+    }
+
+    /// Grows each side by its own margin.
+    #[inline]
+    pub fn inflate_ltrb(&self, left: T, top: T, right: T, bottom: T) -> Rect<T> {
+        Rect::new(
+            (self.origin.x - left, self.origin.y - top),
+            (
+                self.size.width + left + right,
+                self.size.height + top + bottom,
+            ),
+        )
+    }
+}
+
+impl<T> Rect<T>
+where
+    T: Scalar + core::ops::Div<T, Output = T> + One,
+{
+    /// Returns `origin + size / 2`. For integers the half size is rounded toward zero.
+    #[inline]
+    pub fn center(&self) -> Point<T> {
+        self.origin + self.size / (T::one() + T::one())
+    }
+
+    /// Creates a rect whose `center()` is `center`. For integers the half size is rounded
+    /// toward zero.
+    #[inline]
+    pub fn from_center_size(center: impl Into<Point<T>>, size: impl Into<Size<T>>) -> Self {
+        let size = size.into();
+        let half = Vector::from(size / (T::one() + T::one()));
+        Self::from_origin_size(center.into() - half, size)
+    }
+
+    /// Moves the origin by `d` and shrinks the size by `2 * d`.
+    ///
+    /// If `2 * d` exceeds the size on an axis, that axis collapses to zero at the center instead
+    /// of going negative or underflowing.
+    #[inline]
+    pub fn deflate(&self, d: impl Into<Size<T>>) -> Rect<T>
+    where
+        T: Zero,
+    {
+        let d = d.into();
+        let two = T::one() + T::one();
+        let shrink = |o: T, s: T, d: T| {
+            if d + d <= s {
+                (o + d, s - d - d)
+            } else {
+                (o + s / two, T::zero())
+            }
+        };
+        let (x, width) = shrink(self.origin.x, self.size.width, d.width);
+        let (y, height) = shrink(self.origin.y, self.size.height, d.height);
+        Rect::new((x, y), (width, height))
+    }
+
+    /// Scales `self` by `x` and `y` around `center()`.
+    ///
+    /// For integers the anchor is `center()` rounded toward zero, so the center of the result can
+    /// differ from it by the rounding when the size is odd.
+    #[inline]
+    pub fn scale_from_center(&self, x: T, y: T) -> Rect<T> {
+        self.scale_from(self.center(), x, y)
+    }
+
+    /// Moves `self` so that its center matches the center of `outer`.
+    #[inline]
+    pub fn centered_in(&self, outer: &Rect<T>) -> Rect<T> {
+        Self::from_center_size(outer.center(), self.size)
+    }
+}
+
+/// The number of cells of length `cell` in `len`, counting a partial last cell if `partial`.
+#[inline]
+fn tile_count<T>(len: T, cell: T, partial: bool) -> usize
+where
+    T: Scalar + core::ops::Div<T, Output = T> + NumCast + Zero,
+{
+    if !(cell > T::zero() && len > T::zero()) {
+        return 0;
+    }
+    let n = (len / cell).to_usize().unwrap_or(0);
+    let rest = T::from(n).is_some_and(|n| cell * n < len);
+    if partial && rest {
+        n + 1
+    } else {
+        n
+    }
+}
+
+impl<T> Rect<T>
+where
+    T: Scalar + core::ops::Div<T, Output = T> + NumCast + Zero,
+{
+    /// Iterates the cells of `self` divided into `cols` × `rows` in row-major order.
+    ///
+    /// For integers the cells are `size / (cols, rows)` rounded toward zero and the last column
+    /// and the last row absorb the remainder.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cols` or `rows` is not representable in `T`.
+    #[inline]
+    pub fn grid(&self, cols: usize, rows: usize) -> impl ExactSizeIterator<Item = Rect<T>> {
+        let r = *self;
+        let count = if cols == 0 || rows == 0 {
+            0
+        } else {
+            cols * rows
+        };
+        let cell = if count == 0 {
+            Size::new(T::zero(), T::zero())
+        } else {
+            Size::new(
+                r.size.width / T::from(cols).unwrap(),
+                r.size.height / T::from(rows).unwrap(),
+            )
+        };
+        (0..count).map(move |i| {
+            let (c, w) = (i % cols, i / cols);
+            let x = r.origin.x + cell.width * T::from(c).unwrap();
+            let y = r.origin.y + cell.height * T::from(w).unwrap();
+            let width = if c + 1 == cols {
+                r.right() - x
+            } else {
+                cell.width
+            };
+            let height = if w + 1 == rows {
+                r.bottom() - y
+            } else {
+                cell.height
+            };
+            Rect::new((x, y), (width, height))
+        })
+    }
+
+    /// Iterates the cells of size `cell` from the origin of `self` in row-major order.
+    ///
+    /// The cells in the last column and the last row are clipped to `self` when the size is not a
+    /// multiple of `cell`. Use `tiles_exact` to skip them. Nothing is yielded unless `cell` is
+    /// positive.
+    #[inline]
+    pub fn tiles(&self, cell: impl Into<Size<T>>) -> impl ExactSizeIterator<Item = Rect<T>> {
+        self.tiles_impl(cell.into(), true)
+    }
+
+    /// Iterates the whole cells of size `cell` that fit in `self` in row-major order.
+    ///
+    /// Nothing is yielded unless `cell` is positive.
+    #[inline]
+    pub fn tiles_exact(&self, cell: impl Into<Size<T>>) -> impl ExactSizeIterator<Item = Rect<T>> {
+        self.tiles_impl(cell.into(), false)
+    }
+
+    #[inline]
+    fn tiles_impl(&self, cell: Size<T>, partial: bool) -> impl ExactSizeIterator<Item = Rect<T>> {
+        let r = *self;
+        let cols = tile_count(r.size.width, cell.width, partial);
+        let rows = tile_count(r.size.height, cell.height, partial);
+        (0..cols * rows).map(move |i| {
+            let x = r.origin.x + cell.width * T::from(i % cols).unwrap();
+            let y = r.origin.y + cell.height * T::from(i / cols).unwrap();
+            let width = partial_min(cell.width, r.right() - x);
+            let height = partial_min(cell.height, r.bottom() - y);
+            Rect::new((x, y), (width, height))
+        })
+    }
+}
+
+impl<T: core::ops::Mul<T, Output = T>> Rect<T> {
+    #[inline]
+    pub fn area(self) -> T {
+        self.size.area()
+    }
+}
+
+impl<T: Zero + PartialOrd> Rect<T> {
+    /// Returns `true` if the size is empty. See `Size::is_empty`.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.size.is_empty()
+    }
+}
+
+impl<T: FloatScalar> Rect<T> {
+    /// Returns `true` if every component differs from the one of `other` by at most `epsilon`.
+    #[inline]
+    pub fn approx_eq(&self, other: &Self, epsilon: T) -> bool {
+        self.origin.approx_eq(&other.origin, epsilon) && self.size.approx_eq(&other.size, epsilon)
+    }
+
+    /// Returns the distance from `p` to the nearest point of `self`, which is zero if `p` is
+    /// inside.
+    #[inline]
+    pub fn distance_to_point(&self, p: impl Into<Point<T>>) -> T {
+        let p = p.into();
+        self.clamp_point(p).distance(p)
+    }
+
+    #[inline]
+    pub fn floor(self) -> Self {
+        Self::from_origin_size(self.origin.floor(), self.size.floor())
+    }
+
+    #[inline]
+    pub fn ceil(self) -> Self {
+        Self::from_origin_size(self.origin.ceil(), self.size.ceil())
+    }
+
+    #[inline]
+    pub fn round(self) -> Self {
+        Self::from_origin_size(self.origin.round(), self.size.round())
+    }
+
+    /// Returns the smallest rect with integral edges that contains `self`, by flooring the origin
+    /// and ceiling the endpoint.
+    #[inline]
+    pub fn round_out(self) -> Self {
+        let origin = self.origin.floor();
+        Self::from_origin_size(origin, Size::from(self.endpoint().ceil() - origin))
+    }
+
+    /// Returns the largest rect with integral edges inside `self`, by ceiling the origin and
+    /// flooring the endpoint. The size is clamped to zero if no such rect exists.
+    #[inline]
+    pub fn round_in(self) -> Self {
+        let origin = self.origin.ceil();
+        let size = Size::from(self.endpoint().floor() - origin);
+        Self::from_origin_size(origin, size.max((T::zero(), T::zero())))
+    }
+
+    /// Returns the largest rect with the aspect ratio of `self` that fits in `outer`, centered
+    /// in `outer`.
+    #[inline]
+    pub fn fit_into(&self, outer: &Rect<T>) -> Rect<T> {
+        let size = self.size.fit_into(outer.size);
+        let two = T::one() + T::one();
+        let offset = (outer.size - size) / two;
+        Rect::from_origin_size(outer.origin + offset, size)
+    }
+}
+
+impl<T: Widen> Rect<T> {
+    /// Converts each component to `T::Wide`, e.g. to compute products without overflow.
+    #[inline]
+    pub fn widen(self) -> Rect<T::Wide> {
+        Rect::from_origin_size(self.origin.widen(), self.size.widen())
+    }
+}
+
 impl<T: ToPrimitive> Rect<T> {
     #[inline]
     pub fn cast<U: NumCast>(self) -> Option<Rect<U>> {
         Some(Rect::new(self.origin.cast::<U>()?, self.size.cast::<U>()?))
     }
+
+    #[inline]
+    pub fn try_cast<U: NumCast>(self) -> Result<Rect<U>, CastError>
+    where
+        T: Copy,
+    {
+        Ok(Rect::new(
+            (
+                cast_component(self.origin.x, "Rect", "origin.x")?,
+                cast_component(self.origin.y, "Rect", "origin.y")?,
+            ),
+            (
+                cast_component(self.size.width, "Rect", "size.width")?,
+                cast_component(self.size.height, "Rect", "size.height")?,
+            ),
+        ))
+    }
+
+    /// Converts each component to `f32`. See [the conversions of `Point`](Point#conversions).
+    #[inline]
+    pub fn as_f32(self) -> Rect<f32> {
+        Rect::new(self.origin.as_f32(), self.size.as_f32())
+    }
+
+    /// Converts each component to `f64`. See [the conversions of `Point`](Point#conversions).
+    #[inline]
+    pub fn as_f64(self) -> Rect<f64> {
+        Rect::new(self.origin.as_f64(), self.size.as_f64())
+    }
+
+    /// Converts each component to `i32`. See [the conversions of `Point`](Point#conversions).
+    #[inline]
+    pub fn as_i32(self) -> Rect<i32> {
+        Rect::new(self.origin.as_i32(), self.size.as_i32())
+    }
+
+    /// Converts each component to `u32`. See [the conversions of `Point`](Point#conversions).
+    #[inline]
+    pub fn as_u32(self) -> Rect<u32> {
+        Rect::new(self.origin.as_u32(), self.size.as_u32())
+    }
 }
 
 impl<T> From<((T, T), (T, T))> for Rect<T> {
@@ -86,6 +632,75 @@ impl<T: Copy> From<([T; 2], [T; 2])> for Rect<T> {
     }
 }
 
+/// Parses `"x,y,w,h"`, allowing whitespace around each component.
+impl<T: core::str::FromStr> core::str::FromStr for Rect<T> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x, y, width, height] = parse_components(
+            s,
+            &[','],
+            "Rect",
+            ["origin.x", "origin.y", "size.width", "size.height"],
+        )?;
+        Ok(Self::from_origin_size(point(x, y), size(width, height)))
+    }
+}
+
+impl<T, U> core::ops::Add<U> for Rect<T>
+where
+    T: core::ops::Add<T, Output = T> + Copy,
+    U: Into<Vector<T>>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: U) -> Self {
+        Self {
+            origin: self.origin + rhs.into(),
+            ..self
+        }
+    }
+}
+
+impl<T, U> core::ops::Sub<U> for Rect<T>
+where
+    T: core::ops::Sub<T, Output = T> + Copy,
+    U: Into<Vector<T>>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: U) -> Self {
+        Self {
+            origin: self.origin - rhs.into(),
+            ..self
+        }
+    }
+}
+
+impl<T, U> core::ops::AddAssign<U> for Rect<T>
+where
+    T: core::ops::Add<T, Output = T> + Copy,
+    U: Into<Vector<T>>,
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: U) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T, U> core::ops::SubAssign<U> for Rect<T>
+where
+    T: core::ops::Sub<T, Output = T> + Copy,
+    U: Into<Vector<T>>,
+{
+    #[inline]
+    fn sub_assign(&mut self, rhs: U) {
+        *self = *self - rhs;
+    }
+}
+
 #[inline]
 pub fn rect<T>(point: impl Into<Point<T>>, size: impl Into<Size<T>>) -> Rect<T> {
     Rect::new(point, size)
@@ -95,6 +710,402 @@ pub fn rect<T>(point: impl Into<Point<T>>, size: impl Into<Size<T>>) -> Rect<T>
 mod tests {
     use super::*;
 
+    #[test]
+    fn as_test() {
+        assert!(rect((1, 2), (3, 4)).as_f32() == rect((1.0, 2.0), (3.0, 4.0)));
+        assert!(rect((-1.5f32, 2.5), (3.9, 4.1)).as_i32() == rect((-1, 2), (3, 4)));
+        assert!(rect((-1.5f32, 2.5), (3.9, -4.1)).as_u32() == rect((0, 2), (3, 0)));
+    }
+
+    #[test]
+    fn try_cast_test() {
+        assert!(rect((1, 2), (3, 4)).try_cast::<u8>() == Ok(rect((1, 2), (3, 4))));
+        let e = rect((-1, 0), (0, 0)).try_cast::<u8>().unwrap_err();
+        assert!(e.type_name() == "Rect" && e.component() == "origin.x");
+        let e = rect((0, -1), (0, 0)).try_cast::<u8>().unwrap_err();
+        assert!(e.type_name() == "Rect" && e.component() == "origin.y");
+        let e = rect((0, 0), (256, 0)).try_cast::<u8>().unwrap_err();
+        assert!(e.type_name() == "Rect" && e.component() == "size.width");
+        let e = rect((0, 0), (0, -5)).try_cast::<u8>().unwrap_err();
+        assert!(e.type_name() == "Rect" && e.component() == "size.height");
+        assert!(rect((1, 2), (3, 4)).cast::<u8>().is_some());
+    }
+
+    #[test]
+    fn const_test() {
+        const RECTS: [Rect<i32>; 2] = [
+            Rect::from_origin_size(point(1, 2), size(3, 4)),
+            Rect::from_origin_size(Point::new(5, 6), Size::new(7, 8)),
+        ];
+        assert!(RECTS[0] == rect((1, 2), (3, 4)));
+        assert!(RECTS[1] == rect((5, 6), (7, 8)));
+    }
+
+    #[test]
+    fn default_test() {
+        let rc = Rect::<f32>::default();
+        assert!(rc.origin == (0.0, 0.0));
+        assert!(rc.size == (0.0, 0.0));
+        assert!(rc == rect((0.0, 0.0), (0.0, 0.0)));
+
+        fn fill<T: Default + Copy>() -> [T; 2] {
+            [T::default(); 2]
+        }
+        assert!(fill::<Point<i32>>() == [point(0, 0); 2]);
+        assert!(fill::<Size<u32>>() == [size(0, 0); 2]);
+
+        #[derive(Default)]
+        struct Item {
+            rect: Rect<i32>,
+            circle: Circle<f32>,
+            velocity: Vector<f32>,
+            color: Rgba<u8>,
+        }
+        let item = Item::default();
+        assert!(item.rect == rect((0, 0), (0, 0)));
+        assert!(item.circle == circle((0.0, 0.0), 0.0));
+        assert!(item.velocity == (0.0, 0.0));
+        assert!(item.color == (0, 0, 0, 0));
+    }
+
+    #[test]
+    fn approx_eq_test() {
+        let a = rect((0.1f64 + 0.2, 0.0), (1.0, 0.7 + 0.1));
+        let b = rect((0.3, 0.0), (1.0, 0.8));
+        assert!(a != b);
+        assert!(a.approx_eq(&b, 1e-12));
+        assert!(!a.approx_eq(&rect((0.3, 0.0), (1.1, 0.8)), 1e-12));
+    }
+
+    #[test]
+    fn from_str_round_trip_test() {
+        let format = |r: Rect<f32>| {
+            format!(
+                "{},{},{},{}",
+                r.origin.x, r.origin.y, r.size.width, r.size.height
+            )
+        };
+        for r in [
+            rect((0.5f32, -1.0), (2.0, 3.5)),
+            rect((0.1, -0.2), (1e-30, 3e30)),
+            rect((f32::MIN, 0.0), (f32::MAX, f32::MIN_POSITIVE)),
+        ] {
+            assert!(format(r).parse::<Rect<f32>>() == Ok(r));
+        }
+        let r = rect((i64::MIN, -1), (i64::MAX, 0));
+        let s = format!(
+            "{}, {}, {}, {}",
+            r.origin.x, r.origin.y, r.size.width, r.size.height
+        );
+        assert!(s.parse::<Rect<i64>>() == Ok(r));
+    }
+
+    #[test]
+    fn from_str_test() {
+        assert!("1,2,3,4".parse::<Rect<i32>>() == Ok(rect((1, 2), (3, 4))));
+        assert!(" 1 , 2 ,3, 4 ".parse::<Rect<i32>>() == Ok(rect((1, 2), (3, 4))));
+        let e = "1,2,3".parse::<Rect<i32>>().unwrap_err();
+        assert!(e.kind() == ParseErrorKind::Missing && e.component() == Some("size.height"));
+        let e = "1,2,3,4,5".parse::<Rect<i32>>().unwrap_err();
+        assert!(e.kind() == ParseErrorKind::Trailing);
+    }
+
+    #[test]
+    fn with_test() {
+        let a = rect((1, 2), (3, 4));
+        assert!(a.with_origin((5, 6)) == rect((5, 6), (3, 4)));
+        assert!(a.with_size(size(7, 8)) == rect((1, 2), (7, 8)));
+    }
+
+    #[test]
+    fn round_test() {
+        let a = rect((-1.5f32, 0.5), (2.0, 1.2));
+        assert!(a.floor() == rect((-2.0, 0.0), (2.0, 1.0)));
+        assert!(a.ceil() == rect((-1.0, 1.0), (2.0, 2.0)));
+        assert!(a.round() == rect((-2.0, 1.0), (2.0, 1.0)));
+    }
+
+    #[test]
+    fn round_out_in_test() {
+        let a = rect((0.4f32, 0.4), (0.2, 0.2));
+        assert!(a.round().size == (0.0, 0.0));
+        assert!(a.round_out() == rect((0.0, 0.0), (1.0, 1.0)));
+        assert!(a.round_in() == rect((1.0, 1.0), (0.0, 0.0)));
+        let b = rect((-2.5f64, -0.5), (5.0, 2.0));
+        assert!(b.round_out() == rect((-3.0, -1.0), (6.0, 3.0)));
+        assert!(b.round_in() == rect((-2.0, 0.0), (4.0, 1.0)));
+        let c = rect((1.0f32, 2.0), (3.0, 4.0));
+        assert!(c.round_out() == c && c.round_in() == c);
+    }
+
+    #[test]
+    fn area_test() {
+        assert!(rect((-5, 3), (4, 6)).area() == 24);
+        assert!(rect((1, 1), (0, 6)).is_empty());
+        assert!(!rect((1.0f32, 1.0), (0.5, 6.0)).is_empty());
+    }
+
+    #[test]
+    fn fit_into_test() {
+        let image = rect((0.0f32, 0.0), (600.0, 800.0));
+        let window = rect((10.0, 20.0), (1920.0, 1080.0));
+        assert!(image.fit_into(&window) == rect((565.0, 20.0), (810.0, 1080.0)));
+        let image = rect((0.0f32, 0.0), (1920.0, 1080.0));
+        let window = rect((0.0, 0.0), (600.0, 800.0));
+        assert!(image.fit_into(&window) == rect((0.0, 231.25), (600.0, 337.5)));
+    }
+
+    #[test]
+    fn intersection_test() {
+        let a = rect((0, 0), (10, 10));
+        // containment
+        let b = rect((2, 3), (4, 5));
+        assert!(a.intersection(&b) == Some(b) && b.intersection(&a) == Some(b));
+        // partial overlap on each side
+        assert!(a.intersection(&rect((-5, 2), (7, 3))) == Some(rect((0, 2), (2, 3))));
+        assert!(a.intersection(&rect((8, 2), (7, 3))) == Some(rect((8, 2), (2, 3))));
+        assert!(a.intersection(&rect((2, -5), (3, 7))) == Some(rect((2, 0), (3, 2))));
+        assert!(a.intersection(&rect((2, 8), (3, 7))) == Some(rect((2, 8), (3, 2))));
+        // touching
+        assert!(a.intersection(&rect((10, 10), (5, 5))) == Some(rect((10, 10), (0, 0))));
+        assert!(a.intersection(&rect((10, 3), (5, 5))) == Some(rect((10, 3), (0, 5))));
+        assert!(a.intersects(&rect((10, 10), (5, 5))));
+        // disjoint
+        assert!(a.intersection(&rect((11, 0), (5, 5))).is_none());
+        assert!(!a.intersects(&rect((11, 0), (5, 5))));
+        let c = rect((0.5f32, 0.5), (1.0, 1.0));
+        assert!(
+            c.intersection(&rect((1.0, 0.0), (2.0, 1.0))) == Some(rect((1.0, 0.5), (0.5, 0.5)))
+        );
+    }
+
+    #[test]
+    fn union_test() {
+        let a = rect((0, 0), (2, 2));
+        assert!(a.union(&rect((5, -1), (1, 1))) == rect((0, -1), (6, 3)));
+        assert!(a.union(&rect((1, 1), (0, 0))) == a);
+        assert!(a.union(&rect((-3, 4), (0, 0))) == rect((-3, 0), (5, 4)));
+        assert!(a.union_point((4, -1)) == rect((0, -1), (4, 3)));
+        assert!(a.union_point((1, 1)) == a);
+        let b = rect((0.5f32, 0.5), (1.0, 1.0));
+        assert!(b.union(&rect((-1.0, 2.0), (0.5, 0.5))) == rect((-1.0, 0.5), (2.5, 2.0)));
+    }
+
+    #[test]
+    fn bounding_test() {
+        let r = Rect::bounding([point(3, 1), point(-2, 5), point(0, -4)]);
+        assert!(r == Some(rect((-2, -4), (5, 9))));
+        assert!(Rect::bounding([point(1.5f32, 2.0)]) == Some(rect((1.5, 2.0), (0.0, 0.0))));
+        let r = Rect::bounding([point(1.5f32, 2.0), point(-0.5, 3.0)]);
+        assert!(r == Some(rect((-0.5, 2.0), (2.0, 1.0))));
+        assert!(Rect::<i32>::bounding([]).is_none());
+        assert!(Rect::<f32>::bounding(core::iter::empty()).is_none());
+    }
+
+    #[test]
+    fn edge_corner_test() {
+        let a = rect((1, 2), (3, 4));
+        assert!(a.x() == 1 && a.y() == 2 && a.width() == 3 && a.height() == 4);
+        assert!(a.left() == 1 && a.top() == 2 && a.right() == 4 && a.bottom() == 6);
+        assert!(a.top_left() == (1, 2) && a.top_right() == (4, 2));
+        assert!(a.bottom_left() == (1, 6) && a.bottom_right() == (4, 6));
+        assert!(a.corners() == [point(1, 2), point(4, 2), point(4, 6), point(1, 6)]);
+        let c = rect((0.5f32, -1.0), (1.0, 2.0)).corners();
+        // clockwise on screen gives a positive shoelace sum as the y axis is flipped
+        let area2 = (0..4)
+            .map(|i| Vector::from(c[i]).cross(c[(i + 1) % 4]))
+            .sum::<f32>();
+        assert!(area2 == 4.0);
+    }
+
+    #[test]
+    fn clamp_point_test() {
+        let a = rect((10, 20), (30, 40));
+        assert!(a.clamp_point((15, 25)) == point(15, 25));
+        assert!(a.clamp_point((10, 60)) == point(10, 60));
+        assert!(a.clamp_point((0, 30)) == point(10, 30));
+        assert!(a.clamp_point((50, 30)) == point(40, 30));
+        assert!(a.clamp_point((15, 0)) == point(15, 20));
+        assert!(a.clamp_point((15, 99)) == point(15, 60));
+        assert!(a.clamp_point((0, 0)) == point(10, 20));
+        assert!(a.clamp_point((50, 0)) == point(40, 20));
+        assert!(a.clamp_point((50, 99)) == point(40, 60));
+        assert!(a.clamp_point((0, 99)) == point(10, 60));
+        assert!(a.closest_point((50, 99)) == point(40, 60));
+        assert!(rect((40, 60), (-30, -40)).clamp_point((0, 0)) == point(10, 20));
+    }
+
+    #[test]
+    fn distance_to_point_test() {
+        let a = rect((0.0f32, 0.0), (10.0, 10.0));
+        assert!(a.distance_to_point((5.0, 5.0)) == 0.0);
+        assert!(a.distance_to_point((-3.0, 5.0)) == 3.0);
+        assert!(a.distance_to_point((13.0, 14.0)) == 5.0);
+    }
+
+    #[test]
+    fn center_test() {
+        assert!(rect((1.0f32, 2.0), (3.0, 5.0)).center() == (2.5, 4.5));
+        assert!(rect((0, 0), (5, 3)).center() == (2, 1));
+        assert!(rect((-5, -3), (5, 3)).center() == (-3, -2));
+        let a = Rect::from_center_size((2.5f32, 4.5), (3.0, 5.0));
+        assert!(a == rect((1.0, 2.0), (3.0, 5.0)));
+        let b = Rect::from_center_size((10, 10), (5, 3));
+        assert!(b == rect((8, 9), (5, 3)) && b.center() == (10, 10));
+    }
+
+    #[test]
+    fn centered_in_test() {
+        let outer = rect((0.0f32, 0.0), (100.0, 50.0));
+        let a = rect((90.0f32, 90.0), (20.0, 10.0)).centered_in(&outer);
+        assert!(a == rect((40.0, 20.0), (20.0, 10.0)));
+        assert!(a.center() == outer.center());
+        let a = rect((0, 0), (3, 3)).centered_in(&rect((0, 0), (10, 10)));
+        assert!(a == rect((4, 4), (3, 3)));
+    }
+
+    #[test]
+    fn inflate_deflate_test() {
+        let a = rect((10, 10), (20, 10));
+        assert!(a.inflate((2, 3)) == rect((8, 7), (24, 16)));
+        assert!(a.inflate((2, 3)).deflate((2, 3)) == a);
+        assert!(a.deflate((5, 5)) == rect((15, 15), (10, 0)));
+        assert!(a.inflate_ltrb(1, 2, 3, 4) == rect((9, 8), (24, 16)));
+        let b = rect((0.0f32, 0.0), (4.0, 4.0));
+        assert!(b.deflate((0.5, 0.5)) == rect((0.5, 0.5), (3.0, 3.0)));
+        assert!(b.deflate((3.0, 1.0)) == rect((2.0, 1.0), (0.0, 2.0)));
+    }
+
+    #[test]
+    fn deflate_unsigned_test() {
+        let a = rect((4u32, 4), (10, 3));
+        assert!(a.deflate((2, 2)) == rect((6, 5), (6, 0)));
+        assert!(a.deflate((100, 100)) == rect((9, 5), (0, 0)));
+        assert!(rect((0u32, 0), (0, 0)).deflate((1, 1)) == rect((0, 0), (0, 0)));
+    }
+
+    #[test]
+    fn normalized_test() {
+        let a = rect((10, 10), (-5, -5));
+        assert!(!a.is_normalized());
+        assert!(a.normalized() == rect((5, 5), (5, 5)));
+        assert!(a.normalized().is_normalized());
+        assert!(rect((10, 10), (5, -5)).normalized() == rect((10, 5), (5, 5)));
+        assert!(rect((1.0f32, 2.0), (0.0, 3.0)).is_normalized());
+    }
+
+    #[test]
+    fn negative_size_collision_test() {
+        // a drag from (10, 10) to (5, 5) built without `from_points`
+        let drag = rect((10, 10), (-5, -5));
+        assert!(drag.normalized() == Rect::from_points((10, 10), (5, 5)));
+        let items = [
+            rect((0, 0), (6, 6)),
+            rect((11, 11), (2, 2)),
+            rect((7, 7), (1, 1)),
+        ];
+        for item in &items {
+            assert!(drag.is_crossing(item) == drag.normalized().is_crossing(item));
+            assert!(item.is_crossing(&drag) == item.is_crossing(&drag.normalized()));
+            assert!(drag.contains(item) == drag.normalized().contains(item));
+        }
+        assert!(drag.is_crossing(&items[0]) && drag.contains(&items[2]));
+        assert!(drag.is_crossing(&point(7, 7)) && !drag.is_crossing(&point(11, 7)));
+        assert!(drag.is_crossing(&circle((4, 7), 1)) && drag.contains(&circle((7, 7), 1)));
+        assert!(drag.intersection(&items[0]) == Some(rect((5, 5), (1, 1))));
+        assert!(drag.union(&items[1]) == rect((5, 5), (8, 8)));
+    }
+
+    #[test]
+    fn split_test() {
+        let a = rect((10, 20), (100, 50));
+        assert!(a.split_h(40) == (rect((10, 20), (30, 50)), rect((40, 20), (70, 50))));
+        assert!(a.split_h(10) == (rect((10, 20), (0, 50)), a));
+        assert!(a.split_h(0) == (rect((10, 20), (0, 50)), a));
+        assert!(a.split_h(500) == (a, rect((110, 20), (0, 50))));
+        assert!(a.split_v(30) == (rect((10, 20), (100, 10)), rect((10, 30), (100, 40))));
+        assert!(a.split_v(70) == (a, rect((10, 70), (100, 0))));
+    }
+
+    #[test]
+    fn split_strip_test() {
+        let a = rect((0u32, 0), (100, 50));
+        assert!(a.split_left(30) == (rect((0, 0), (30, 50)), rect((30, 0), (70, 50))));
+        assert!(a.split_left(100) == (a, rect((100, 0), (0, 50))));
+        assert!(a.split_left(u32::MAX) == (a, rect((100, 0), (0, 50))));
+        assert!(a.split_top(10) == (rect((0, 0), (100, 10)), rect((0, 10), (100, 40))));
+        assert!(a.split_top(80) == (a, rect((0, 50), (100, 0))));
+    }
+
+    #[test]
+    fn grid_test() {
+        let a = rect((10, 20), (100, 50));
+        let cells = a.grid(2, 2).collect::<Vec<_>>();
+        assert!(
+            cells
+                == [
+                    rect((10, 20), (50, 25)),
+                    rect((60, 20), (50, 25)),
+                    rect((10, 45), (50, 25)),
+                    rect((60, 45), (50, 25)),
+                ]
+        );
+        assert!(a.grid(3, 2).len() == 6);
+        assert!(a.grid(0, 2).len() == 0);
+        assert!(a.grid(2, 0).next().is_none());
+    }
+
+    #[test]
+    fn grid_remainder_test() {
+        let a = rect((0u32, 0), (10, 5));
+        let cells = a.grid(3, 2).collect::<Vec<_>>();
+        assert!(
+            cells
+                == [
+                    rect((0, 0), (3, 2)),
+                    rect((3, 0), (3, 2)),
+                    rect((6, 0), (4, 2)),
+                    rect((0, 2), (3, 3)),
+                    rect((3, 2), (3, 3)),
+                    rect((6, 2), (4, 3)),
+                ]
+        );
+        let widths = a.grid(3, 1).map(|c| c.size.width).sum::<u32>();
+        assert!(widths == 10);
+    }
+
+    #[test]
+    fn tiles_test() {
+        let a = rect((0, 0), (10, 5));
+        let tiles = a.tiles((4, 4)).collect::<Vec<_>>();
+        assert!(
+            tiles
+                == [
+                    rect((0, 0), (4, 4)),
+                    rect((4, 0), (4, 4)),
+                    rect((8, 0), (2, 4)),
+                    rect((0, 4), (4, 1)),
+                    rect((4, 4), (4, 1)),
+                    rect((8, 4), (2, 1)),
+                ]
+        );
+        let tiles = a.tiles_exact((4, 4)).collect::<Vec<_>>();
+        assert!(tiles == [rect((0, 0), (4, 4)), rect((4, 0), (4, 4))]);
+        assert!(a.tiles((5, 5)).len() == 2);
+        assert!(a.tiles_exact((5, 5)).len() == 2);
+        assert!(a.tiles((0, 5)).len() == 0);
+        assert!(a.tiles((11, 6)).collect::<Vec<_>>() == [a]);
+        assert!(a.tiles_exact((11, 6)).len() == 0);
+    }
+
+    #[test]
+    fn tiles_float_test() {
+        let a = rect((0.0f32, 0.0), (1.0, 1.0));
+        assert!(a.tiles((0.25, 0.5)).len() == 8);
+        assert!(a.tiles((0.3, 1.0)).len() == 4);
+        assert!(a.tiles_exact((0.3, 1.0)).len() == 3);
+    }
+
     #[test]
     fn eq_test() {
         assert!(rect((10, 20), (30, 40)) == rect((10, 20), (30, 40)));
@@ -117,6 +1128,43 @@ mod tests {
         assert!(rect((10, 20), (30, 40)).scale(2, 3) == rect((10, 20), (60, 120)));
     }
 
+    #[test]
+    fn scale_from_test() {
+        let a = rect((10, 20), (30, 40));
+        assert!(a.scale_from((0, 0), 2, 3) == rect((20, 60), (60, 120)));
+        assert!(a.scale_from((10, 20), 2, 3) == a.scale(2, 3));
+        let a = rect((10u32, 20), (30, 40));
+        assert!(a.scale_from((15, 30), 2, 2) == rect((5, 10), (60, 80)));
+    }
+
+    #[test]
+    fn scale_from_center_test() {
+        let a = rect((10, 20), (30, 40));
+        let b = a.scale_from_center(3, 2);
+        assert!(b == rect((-20, 0), (90, 80)));
+        assert!(b.center() == a.center());
+        let a = rect((-1.5f32, 2.0), (3.0, 5.0));
+        let b = a.scale_from_center(0.5, 4.0);
+        assert!(b.center() == a.center());
+        assert!(b.size == size(1.5, 20.0));
+        // odd integer sizes round the anchor
+        let a = rect((0, 0), (5, 5));
+        assert!(a.scale_from_center(2, 2) == rect((-2, -2), (10, 10)));
+    }
+
+    #[test]
+    fn translate_ops_test() {
+        let a = rect((0, 0), (1, 1));
+        assert!(a + (5, 5) == a.translate((5, 5)));
+        assert!(a + vector(5, -2) == rect((5, -2), (1, 1)));
+        assert!(a - (5, 5) == rect((-5, -5), (1, 1)));
+        let mut b = a;
+        b += (3, 4);
+        assert!(b == rect((3, 4), (1, 1)));
+        b -= vector(1, 1);
+        assert!(b == rect((2, 3), (1, 1)));
+    }
+
     #[test]
     fn from_test() {
         let rc = Rect::from(((10, 20), (30, 40)));
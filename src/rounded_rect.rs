@@ -0,0 +1,170 @@
+use crate::*;
+
+/// A rect whose corners are rounded by quarter circles of `radius`.
+///
+/// The radius used by the queries is `corner_radius()`, which clamps `radius` to between zero and
+/// half the smaller side, so the shape degenerates to a stadium or a circle instead of folding.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Debug)]
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RoundedRect<T> {
+    pub rect: Rect<T>,
+    pub radius: T,
+}
+
+impl<T> RoundedRect<T> {
+    /// A `const` variant of `RoundedRect::new` which keeps `radius` as is.
+    #[inline]
+    pub const fn from_rect_radius(rect: Rect<T>, radius: T) -> Self {
+        Self { rect, radius }
+    }
+
+    /// Returns the rect without the rounding.
+    #[inline]
+    pub fn bounding_rect(&self) -> Rect<T>
+    where
+        T: Copy,
+    {
+        self.rect
+    }
+}
+
+impl<T> RoundedRect<T>
+where
+    T: core::ops::Add<T, Output = T> + Copy,
+{
+    #[inline]
+    pub fn translate(&self, d: impl Into<Vector<T>>) -> Self {
+        Self::from_rect_radius(self.rect.translate(d), self.radius)
+    }
+}
+
+impl<T> RoundedRect<T>
+where
+    T: Scalar + core::ops::Div<T, Output = T> + One + Zero,
+{
+    /// Creates a rounded rect with `radius` clamped as by `corner_radius()`.
+    #[inline]
+    pub fn new(rect: impl Into<Rect<T>>, radius: T) -> Self {
+        let rect = rect.into();
+        Self::from_rect_radius(rect, clamp_radius(&rect, radius))
+    }
+
+    /// Returns `radius` clamped to between zero and half the smaller side of `rect`.
+    ///
+    /// For integers the half side is rounded toward zero.
+    #[inline]
+    pub fn corner_radius(&self) -> T {
+        clamp_radius(&self.rect, self.radius)
+    }
+
+    /// Returns the rect of the centers of the corner circles and their radius. The shape is the
+    /// set of points within the radius of that rect.
+    #[inline]
+    pub(crate) fn core(&self) -> (Rect<T>, T) {
+        let r = self.corner_radius();
+        (self.rect.normalized().deflate((r, r)), r)
+    }
+
+    /// Grows each side by `d` and the radius by `d`, so the corners stay concentric.
+    #[inline]
+    pub fn inflate(&self, d: T) -> Self {
+        Self::new(self.rect.inflate((d, d)), self.radius + d)
+    }
+
+    /// Shrinks each side by `d` and the radius by `d` down to zero. See `Rect::deflate` for
+    /// shrinking past the size.
+    #[inline]
+    pub fn deflate(&self, d: T) -> Self {
+        let radius = if self.radius > d {
+            self.radius - d
+        } else {
+            T::zero()
+        };
+        Self::new(self.rect.deflate((d, d)), radius)
+    }
+}
+
+impl<T> RoundedRect<T>
+where
+    T: Widen + core::ops::Div<T, Output = T> + One + Zero,
+{
+    /// Returns `true` if `p` is inside or on the boundary.
+    #[inline]
+    pub fn contains_point(&self, p: impl Into<Point<T>>) -> bool {
+        let p = p.into();
+        let (core, r) = self.core();
+        Circle::new(core.clamp_point(p), r).contains(&p)
+    }
+}
+
+#[inline]
+fn clamp_radius<T>(rect: &Rect<T>, radius: T) -> T
+where
+    T: Scalar + core::ops::Div<T, Output = T> + One + Zero,
+{
+    let size = rect.normalized().size;
+    let half = partial_min(size.width, size.height) / (T::one() + T::one());
+    partial_min(partial_max(radius, T::zero()), half)
+}
+
+#[inline]
+pub fn rounded_rect<T>(rect: impl Into<Rect<T>>, radius: T) -> RoundedRect<T>
+where
+    T: Scalar + core::ops::Div<T, Output = T> + One + Zero,
+{
+    RoundedRect::new(rect, radius)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn corner_radius_test() {
+        assert!(rounded_rect(((0, 0), (10, 5)), 3).radius == 2);
+        assert!(rounded_rect(((0, 0), (10, 5)), -1).radius == 0);
+        assert!(rounded_rect(((10, 10), (-10, -6)), 4).radius == 3);
+        let a = RoundedRect::from_rect_radius(rect((0u32, 0), (10, 6)), 100);
+        assert!(a.corner_radius() == 3);
+    }
+
+    #[test]
+    fn contains_point_corner_test() {
+        let a = rounded_rect(((0.0f32, 0.0), (100.0, 50.0)), 10.0);
+        let centers = [(10.0, 10.0), (90.0, 10.0), (10.0, 40.0), (90.0, 40.0)];
+        let dirs = [(-1.0, -1.0), (1.0, -1.0), (-1.0, 1.0), (1.0, 1.0)];
+        for (c, d) in centers.into_iter().zip(dirs) {
+            let (c, d) = (point(c.0, c.1), vector(d.0, d.1) / 2.0f32.sqrt());
+            assert!(a.contains_point(c + d * 9.9));
+            assert!(!a.contains_point(c + d * 10.1));
+            assert!(a.rect.contains(&(c + d * 10.1)));
+        }
+        assert!(a.contains_point((0.0, 25.0)));
+        assert!(a.contains_point((50.0, 50.0)));
+        assert!(!a.contains_point((50.0, 50.1)));
+    }
+
+    #[test]
+    fn contains_point_unsigned_test() {
+        let a = rounded_rect(((0u32, 0), (20, 20)), 5);
+        assert!(a.contains_point((1, 2)));
+        assert!(!a.contains_point((1, 1)));
+        assert!(a.contains_point((10, 10)));
+        assert!(!a.contains_point((21, 10)));
+    }
+
+    #[test]
+    fn inflate_deflate_test() {
+        let a = rounded_rect(((10, 10), (20, 10)), 4);
+        assert!(a.inflate(2) == rounded_rect(((8, 8), (24, 14)), 6));
+        assert!(a.deflate(2) == rounded_rect(((12, 12), (16, 6)), 2));
+        assert!(a.deflate(3).radius == 1);
+        let b = a.deflate(5);
+        assert!(b.rect == rect((15, 15), (10, 0)) && b.radius == 0);
+        let c = rounded_rect(((0, 0), (10, 10)), 5).inflate(10);
+        assert!(c.radius == 15 && c.rect == rect((-10, -10), (30, 30)));
+        assert!(a.translate((5, -5)) == rounded_rect(((15, 5), (20, 10)), 4));
+        assert!(a.bounding_rect() == rect((10, 10), (20, 10)));
+    }
+}
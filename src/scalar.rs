@@ -0,0 +1,226 @@
+use crate::*;
+use core::cmp::Ordering;
+
+/// The arithmetic most geometry operations of this crate need.
+///
+/// It is implemented for every type satisfying the bounds, so it can be used as a shorthand
+/// in where clauses.
+///
+/// ```
+/// use gecl::{circle, point, Circle, Point, Scalar};
+///
+/// fn hits<T: Scalar>(targets: &[Circle<T>], pt: Point<T>) -> usize {
+///     targets
+///         .iter()
+///         .filter(|c| c.center.distance_squared(pt) <= c.radius * c.radius)
+///         .count()
+/// }
+///
+/// let targets = [circle((0, 0), 2), circle((5, 5), 1)];
+/// assert_eq!(hits(&targets, point(1, 1)), 1);
+/// assert_eq!(hits(&[circle((0.0, 0.0), 2.0)], point(3.0, 0.0)), 0);
+/// ```
+pub trait Scalar:
+    core::ops::Add<Output = Self>
+    + core::ops::Sub<Output = Self>
+    + core::ops::Mul<Output = Self>
+    + PartialOrd
+    + Copy
+{
+}
+
+impl<T> Scalar for T where
+    T: core::ops::Add<Output = T>
+        + core::ops::Sub<Output = T>
+        + core::ops::Mul<Output = T>
+        + PartialOrd
+        + Copy
+{
+}
+
+/// `Scalar` that is also `num::Float`.
+pub trait FloatScalar: Scalar + Float {}
+
+impl<T: Scalar + Float> FloatScalar for T {}
+
+/// Scalars with a wider type in which the collision tests of circles and segments compute squared
+/// distances.
+///
+/// The sum of two squared coordinate differences always fits in `Wide` for the integers up to
+/// 32 bits. The 64-bit integers widen to 128 bits, which covers coordinates up to `2^62`. The
+/// 128-bit integers and the floats, including `half::f16`, are their own `Wide`, which leaves
+/// their results unchanged. With the `fixed` feature the fixed-point types widen to the type of
+/// twice the bits with the same fraction.
+pub trait Widen: Scalar {
+    type Wide: Scalar;
+
+    fn widen(self) -> Self::Wide;
+}
+
+macro_rules! impl_widen {
+    ($($t:ty => $wide:ty),*) => {
+        $(
+            impl Widen for $t {
+                type Wide = $wide;
+
+                #[inline]
+                fn widen(self) -> $wide {
+                    self as $wide
+                }
+            }
+        )*
+    };
+}
+
+impl_widen!(
+    i8 => i32, i16 => i64, i32 => i128, i64 => i128, i128 => i128, isize => i128,
+    u8 => u32, u16 => u64, u32 => u128, u64 => u128, u128 => u128, usize => u128,
+    f32 => f32, f64 => f64
+);
+
+/// Floats that can be mapped to an integer key ordered like `total_cmp`.
+///
+/// This lets float geometry be sorted deterministically or stored in `BTreeSet`/`HashSet`.
+/// `-0.0` and `0.0` have different keys, and NaNs are ordered by their sign and payload.
+pub trait TotalCmpKey: Copy {
+    type Key: Ord + core::hash::Hash + Copy;
+
+    fn total_cmp_key(self) -> Self::Key;
+}
+
+impl TotalCmpKey for f32 {
+    type Key = i32;
+
+    #[inline]
+    fn total_cmp_key(self) -> i32 {
+        let v = self.to_bits() as i32;
+        v ^ (((v >> 31) as u32) >> 1) as i32
+    }
+}
+
+impl TotalCmpKey for f64 {
+    type Key = i64;
+
+    #[inline]
+    fn total_cmp_key(self) -> i64 {
+        let v = self.to_bits() as i64;
+        v ^ (((v >> 63) as u64) >> 1) as i64
+    }
+}
+
+/// A value as a magnitude and a sign, so that unsigned types can hold negative intermediates.
+#[derive(Clone, Copy)]
+pub(crate) struct Signed<T> {
+    pub(crate) mag: T,
+    pub(crate) neg: bool,
+}
+
+impl<T: Scalar + Zero> Signed<T> {
+    #[inline]
+    pub(crate) fn zero() -> Self {
+        Self {
+            mag: T::zero(),
+            neg: false,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn from_value(v: T) -> Self {
+        Self::diff(T::zero(), v)
+    }
+
+    #[inline]
+    pub(crate) fn neg(self) -> Self {
+        Self {
+            neg: !self.neg && self.mag > T::zero(),
+            ..self
+        }
+    }
+
+    /// `b - a`
+    #[inline]
+    pub(crate) fn diff(a: T, b: T) -> Self {
+        if b >= a {
+            Self {
+                mag: b - a,
+                neg: false,
+            }
+        } else {
+            Self {
+                mag: a - b,
+                neg: true,
+            }
+        }
+    }
+
+    #[inline]
+    pub(crate) fn mul(self, rhs: Self) -> Self {
+        let mag = self.mag * rhs.mag;
+        Self {
+            mag,
+            neg: self.neg != rhs.neg && mag > T::zero(),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn add(self, rhs: Self) -> Self {
+        if self.neg == rhs.neg {
+            return Self {
+                mag: self.mag + rhs.mag,
+                neg: self.neg,
+            };
+        }
+        let (pos, neg) = if self.neg { (rhs, self) } else { (self, rhs) };
+        Self::diff(neg.mag, pos.mag)
+    }
+
+    #[inline]
+    pub(crate) fn sub(self, rhs: Self) -> Self {
+        self.add(rhs.neg())
+    }
+
+    #[inline]
+    pub(crate) fn cmp(self, rhs: Self) -> Ordering {
+        let cmp = |a: T, b: T| a.partial_cmp(&b).unwrap_or(Ordering::Equal);
+        match (self.neg, rhs.neg) {
+            (false, false) => cmp(self.mag, rhs.mag),
+            (true, true) => cmp(rhs.mag, self.mag),
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn widen_test() {
+        let d = (i16::MAX.widen() - i16::MIN.widen()).pow(2);
+        assert!(d + d == 2 * 65535i64 * 65535);
+        assert!(200u8.widen() * 200u8.widen() == 40000);
+        assert!(1.5f32.widen() == 1.5);
+    }
+
+    #[test]
+    fn total_cmp_key_test() {
+        let v = [
+            f32::NEG_INFINITY,
+            -1.5,
+            -0.0,
+            0.0,
+            1e-30,
+            2.0,
+            f32::INFINITY,
+            f32::NAN,
+        ];
+        assert!(v
+            .windows(2)
+            .all(|w| w[0].total_cmp_key() < w[1].total_cmp_key()));
+        let v = [-1e300f64, -0.0, 0.0, 0.5, f64::INFINITY];
+        assert!(v
+            .windows(2)
+            .all(|w| w[0].total_cmp_key() < w[1].total_cmp_key()));
+    }
+}
@@ -0,0 +1,126 @@
+use crate::*;
+use sdl2::{pixels::Color, rect};
+
+/// The error of converting to `sdl2::rect` types.
+///
+/// sdl2 clamps positions to `i32::MIN / 2..=i32::MAX / 2` and sizes to `1..=i32::MAX / 2`.
+/// Values outside of them are reported by this error instead.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Sdl2RangeError;
+
+impl core::fmt::Display for Sdl2RangeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(f, "value out of range for sdl2")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Sdl2RangeError {}
+
+#[inline]
+fn check_position(v: i32) -> Result<i32, Sdl2RangeError> {
+    if v < rect::min_int_value() || v > rect::max_int_value() as i32 {
+        return Err(Sdl2RangeError);
+    }
+    Ok(v)
+}
+
+#[inline]
+fn check_size(v: i32) -> Result<u32, Sdl2RangeError> {
+    if v <= 0 || v as u32 > rect::max_int_value() {
+        return Err(Sdl2RangeError);
+    }
+    Ok(v as u32)
+}
+
+impl From<rect::Point> for Point<i32> {
+    #[inline]
+    fn from(src: rect::Point) -> Self {
+        Point::new(src.x(), src.y())
+    }
+}
+
+impl TryFrom<Point<i32>> for rect::Point {
+    type Error = Sdl2RangeError;
+
+    #[inline]
+    fn try_from(src: Point<i32>) -> Result<Self, Self::Error> {
+        Ok(rect::Point::new(
+            check_position(src.x)?,
+            check_position(src.y)?,
+        ))
+    }
+}
+
+/// sdl2 keeps sizes at most `i32::MAX / 2`, so the conversion is lossless.
+impl From<rect::Rect> for Rect<i32> {
+    #[inline]
+    fn from(src: rect::Rect) -> Self {
+        Rect::new(
+            (src.x(), src.y()),
+            (src.width() as i32, src.height() as i32),
+        )
+    }
+}
+
+/// Fails if the size is not positive, since sdl2 would turn a zero size into `1` and a negative
+/// one into a large unsigned value.
+impl TryFrom<Rect<i32>> for rect::Rect {
+    type Error = Sdl2RangeError;
+
+    #[inline]
+    fn try_from(src: Rect<i32>) -> Result<Self, Self::Error> {
+        Ok(rect::Rect::new(
+            check_position(src.origin.x)?,
+            check_position(src.origin.y)?,
+            check_size(src.size.width)?,
+            check_size(src.size.height)?,
+        ))
+    }
+}
+
+impl From<Color> for Rgba<u8> {
+    #[inline]
+    fn from(src: Color) -> Self {
+        Rgba::new(src.r, src.g, src.b, src.a)
+    }
+}
+
+impl From<Rgba<u8>> for Color {
+    #[inline]
+    fn from(src: Rgba<u8>) -> Self {
+        Color::RGBA(src.r, src.g, src.b, src.a)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn point_test() {
+        let p = rect::Point::try_from(point(-10, 20)).unwrap();
+        assert!(p == rect::Point::new(-10, 20));
+        assert!(Point::from(p) == (-10, 20));
+        assert!(rect::Point::try_from(point(i32::MAX, 0)) == Err(Sdl2RangeError));
+        assert!(rect::Point::try_from(point(0, i32::MIN)) == Err(Sdl2RangeError));
+    }
+
+    #[test]
+    fn rect_test() {
+        let rc = rect::Rect::try_from(rect((-10, -20), (30, 40))).unwrap();
+        assert!(rc == rect::Rect::new(-10, -20, 30, 40));
+        assert!(Rect::from(rc) == rect((-10, -20), (30, 40)));
+        assert!(rect::Rect::try_from(rect((0, 0), (-1, 10))) == Err(Sdl2RangeError));
+        assert!(rect::Rect::try_from(rect((0, 0), (10, 0))) == Err(Sdl2RangeError));
+        assert!(rect::Rect::try_from(rect((0, 0), (i32::MAX, 10))) == Err(Sdl2RangeError));
+        assert!(rect::Rect::try_from(rect((i32::MIN, 0), (10, 10))) == Err(Sdl2RangeError));
+    }
+
+    #[test]
+    fn color_test() {
+        let c: Color = rgba(1, 2, 3, 4).into();
+        assert!(c == Color::RGBA(1, 2, 3, 4));
+        assert!(Rgba::from(c) == (1, 2, 3, 4));
+    }
+}
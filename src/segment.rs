@@ -0,0 +1,433 @@
+use crate::*;
+use core::cmp::Ordering;
+
+/// A line segment from `start` to `end`.
+///
+/// A segment whose endpoints are equal behaves like the point in `Collision`.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Debug)]
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Segment<T> {
+    pub start: Point<T>,
+    pub end: Point<T>,
+}
+
+/// The result of `Segment::intersect`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum SegmentIntersection<T> {
+    None,
+    Point(Point<T>),
+    /// The segments are collinear and share this sub-segment, which runs in the direction of
+    /// `self`.
+    Overlap(Segment<T>),
+}
+
+impl<T> Segment<T> {
+    #[inline]
+    pub fn new(start: impl Into<Point<T>>, end: impl Into<Point<T>>) -> Self {
+        Self {
+            start: start.into(),
+            end: end.into(),
+        }
+    }
+
+    /// A `const` variant of `Segment::new`.
+    #[inline]
+    pub const fn from_start_end(start: Point<T>, end: Point<T>) -> Self {
+        Self { start, end }
+    }
+
+    /// Returns the segment from `end` to `start`.
+    #[inline]
+    pub fn reversed(self) -> Self {
+        Self::from_start_end(self.end, self.start)
+    }
+}
+
+impl<T> Segment<T>
+where
+    T: core::ops::Add<T, Output = T> + Copy,
+{
+    #[inline]
+    pub fn translate(&self, d: impl Into<Vector<T>>) -> Self {
+        let d = d.into();
+        Self::from_start_end(self.start + d, self.end + d)
+    }
+}
+
+impl<T: Scalar> Segment<T> {
+    /// Returns the squared length without `sqrt`, so it also works for integers.
+    #[inline]
+    pub fn length_squared(&self) -> T {
+        self.start.distance_squared(self.end)
+    }
+
+    /// Returns the smallest rect containing the segment.
+    #[inline]
+    pub fn bounding_rect(&self) -> Rect<T> {
+        let (lo, hi) = (self.start.min(self.end), self.start.max(self.end));
+        Rect::new(lo, hi - lo)
+    }
+
+    /// Returns `true` if `p` is in the bounding rect of the segment.
+    #[inline]
+    fn in_bounds(&self, p: Point<T>) -> bool {
+        let (lo, hi) = (self.start.min(self.end), self.start.max(self.end));
+        p.x >= lo.x && p.x <= hi.x && p.y >= lo.y && p.y <= hi.y
+    }
+}
+
+impl<T> Segment<T>
+where
+    T: Scalar + core::ops::Div<T, Output = T> + Zero + One,
+{
+    /// Returns the middle of the segment. See `Point::midpoint` for the rounding of integers.
+    #[inline]
+    pub fn midpoint(&self) -> Point<T> {
+        self.start.midpoint(self.end)
+    }
+}
+
+impl<T: Float> Segment<T> {
+    #[inline]
+    pub fn length(&self) -> T {
+        self.start.distance(self.end)
+    }
+
+    /// Returns the point of the segment nearest to `p`.
+    #[inline]
+    pub fn closest_point(&self, p: impl Into<Point<T>>) -> Point<T> {
+        let p = p.into();
+        let d = self.end - self.start;
+        let len2 = d.abs_pow2();
+        if len2 == T::zero() {
+            return self.start;
+        }
+        let t = clamp_unit((p - self.start).dot(d) / len2);
+        self.start + d * t
+    }
+
+    /// Returns the distance from `p` to the nearest point of the segment.
+    #[inline]
+    pub fn distance_to_point(&self, p: impl Into<Point<T>>) -> T {
+        let p = p.into();
+        self.closest_point(p).distance(p)
+    }
+
+    /// Returns the distance between the nearest points of the segments, which is zero if they
+    /// cross.
+    #[inline]
+    pub fn distance_to_segment(&self, other: &Segment<T>) -> T {
+        if self.crosses(other) {
+            return T::zero();
+        }
+        let a = partial_min(
+            self.distance_to_point(other.start),
+            self.distance_to_point(other.end),
+        );
+        let b = partial_min(
+            other.distance_to_point(self.start),
+            other.distance_to_point(self.end),
+        );
+        partial_min(a, b)
+    }
+
+    /// Returns `true` if `p` is within `epsilon` of the segment.
+    ///
+    /// `Collision` requires `p` to be exactly on the segment, which rarely holds for floats.
+    #[inline]
+    pub fn contains_point_eps(&self, p: impl Into<Point<T>>, epsilon: T) -> bool {
+        self.distance_to_point(p) <= epsilon
+    }
+
+    /// Clips the segment to `rect` by the Liang–Barsky algorithm, returning `None` if no part of
+    /// the segment is in `rect`.
+    pub fn clip(&self, rect: &Rect<T>) -> Option<Segment<T>> {
+        let (lo, hi) = rect.bounds();
+        let d = self.end - self.start;
+        let (mut t0, mut t1) = (T::zero(), T::one());
+        let edges = [
+            (-d.x, self.start.x - lo.x),
+            (d.x, hi.x - self.start.x),
+            (-d.y, self.start.y - lo.y),
+            (d.y, hi.y - self.start.y),
+        ];
+        for (p, q) in edges {
+            if p == T::zero() {
+                if q < T::zero() {
+                    return None;
+                }
+                continue;
+            }
+            let t = q / p;
+            if p < T::zero() {
+                t0 = t0.max(t);
+            } else {
+                t1 = t1.min(t);
+            }
+            if t0 > t1 {
+                return None;
+            }
+        }
+        Some(Segment::from_start_end(
+            self.start + d * t0,
+            self.start + d * t1,
+        ))
+    }
+
+    /// Solves `start + (end - start) * t == other.start + (other.end - other.start) * u` for the
+    /// lines through the segments and returns `(t, u)`.
+    ///
+    /// `t` and `u` are not limited to `0..=1`. Returns `None` if the lines are parallel, that is,
+    /// if the sine of the angle between them is at most `T::epsilon()`.
+    #[inline]
+    pub fn intersect_t(&self, other: &Segment<T>) -> Option<(T, T)> {
+        let d = self.end - self.start;
+        let e = other.end - other.start;
+        let denom = d.cross(e);
+        if denom.abs() <= T::epsilon() * d.abs() * e.abs() {
+            return None;
+        }
+        let w = other.start - self.start;
+        Some((w.cross(e) / denom, w.cross(d) / denom))
+    }
+
+    /// Returns the point where the segments cross, including touching at an endpoint.
+    ///
+    /// Returns `None` for collinear segments even if they overlap. Use `intersect` for them.
+    #[inline]
+    pub fn intersection(&self, other: &Segment<T>) -> Option<Point<T>> {
+        match self.intersect(other) {
+            SegmentIntersection::Point(p) => Some(p),
+            _ => None,
+        }
+    }
+
+    /// Returns the shared point or sub-segment of the segments.
+    ///
+    /// Parallel segments are collinear if the distance between their lines is at most
+    /// `T::epsilon()` relative to their extent. A zero-length segment is treated as a point.
+    pub fn intersect(&self, other: &Segment<T>) -> SegmentIntersection<T> {
+        let unit = T::zero()..=T::one();
+        if let Some((t, u)) = self.intersect_t(other) {
+            return if unit.contains(&t) && unit.contains(&u) {
+                SegmentIntersection::Point(self.start + (self.end - self.start) * t)
+            } else {
+                SegmentIntersection::None
+            };
+        }
+        let d = self.end - self.start;
+        let len2 = d.abs_pow2();
+        if len2 == T::zero() {
+            return if other.closest_point(self.start) == self.start {
+                SegmentIntersection::Point(self.start)
+            } else {
+                SegmentIntersection::None
+            };
+        }
+        let w = other.start - self.start;
+        if w.cross(d).abs() > T::epsilon() * d.abs() * w.abs().max(d.abs()) {
+            return SegmentIntersection::None;
+        }
+        let t0 = w.dot(d) / len2;
+        let t1 = (other.end - self.start).dot(d) / len2;
+        let lo = t0.min(t1).max(T::zero());
+        let hi = t0.max(t1).min(T::one());
+        if lo > hi {
+            SegmentIntersection::None
+        } else if lo == hi {
+            SegmentIntersection::Point(self.start + d * lo)
+        } else {
+            SegmentIntersection::Overlap(Segment::from_start_end(
+                self.start + d * lo,
+                self.start + d * hi,
+            ))
+        }
+    }
+}
+
+impl<T: Scalar + Zero> Segment<T> {
+    /// Returns the sign of the cross product `(end - start) × (p - start)`.
+    #[inline]
+    pub(crate) fn orientation(&self, p: Point<T>) -> Ordering {
+        let (a, b) = (self.start, self.end);
+        let (dx, dy) = (Signed::diff(a.x, b.x), Signed::diff(a.y, b.y));
+        let (px, py) = (Signed::diff(a.x, p.x), Signed::diff(a.y, p.y));
+        dx.mul(py).cmp(dy.mul(px))
+    }
+
+    /// Returns `true` if `p` is exactly on the segment.
+    #[inline]
+    pub(crate) fn contains_point(&self, p: Point<T>) -> bool {
+        self.orientation(p) == Ordering::Equal && self.in_bounds(p)
+    }
+
+    /// Returns `true` if the segments share at least one point.
+    pub(crate) fn crosses(&self, other: &Segment<T>) -> bool {
+        let o1 = self.orientation(other.start);
+        let o2 = self.orientation(other.end);
+        let o3 = other.orientation(self.start);
+        let o4 = other.orientation(self.end);
+        if o1 != o2 && o3 != o4 {
+            return true;
+        }
+        (o1 == Ordering::Equal && self.in_bounds(other.start))
+            || (o2 == Ordering::Equal && self.in_bounds(other.end))
+            || (o3 == Ordering::Equal && other.in_bounds(self.start))
+            || (o4 == Ordering::Equal && other.in_bounds(self.end))
+    }
+}
+
+impl<T: Widen + Zero> Segment<T> {
+    /// Compares the distance from `p` to the segment with `r`, without division.
+    ///
+    /// The squared cross product and `r² * len²` are fourth powers of the coordinates, so they are
+    /// compared in `Widen::Wide`.
+    pub(crate) fn cmp_distance(&self, p: Point<T>, r: T) -> Ordering {
+        let rr = r * r;
+        let (a, b) = (self.start, self.end);
+        let (dx, dy) = (Signed::diff(a.x, b.x), Signed::diff(a.y, b.y));
+        let (px, py) = (Signed::diff(a.x, p.x), Signed::diff(a.y, p.y));
+        let len2 = dx.mul(dx).add(dy.mul(dy));
+        let dot = dx.mul(px).add(dy.mul(py));
+        // the nearest point is an endpoint unless the projection of `p` falls strictly inside
+        if dot.cmp(Signed::zero()) != Ordering::Greater || dot.cmp(len2) != Ordering::Less {
+            let d = partial_min(a.distance_squared(p), b.distance_squared(p));
+            return d.partial_cmp(&rr).unwrap_or(Ordering::Greater);
+        }
+        let cross = dx.mul(py).sub(dy.mul(px));
+        let (cross, r, len2) = (cross.mag.widen(), r.widen(), len2.mag.widen());
+        let d = cross * cross;
+        let rr = r * r * len2;
+        d.partial_cmp(&rr).unwrap_or(Ordering::Greater)
+    }
+}
+
+#[inline]
+pub fn segment<T>(start: impl Into<Point<T>>, end: impl Into<Point<T>>) -> Segment<T> {
+    Segment::new(start, end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn length_test() {
+        let a = segment((1.0f32, 2.0), (4.0, 6.0));
+        assert!(a.length() == 5.0);
+        assert!(a.length_squared() == 25.0);
+        assert!(segment((1u32, 2), (4, 6)).length_squared() == 25);
+        assert!(segment((3, 3), (3, 3)).length_squared() == 0);
+    }
+
+    #[test]
+    fn midpoint_translate_test() {
+        assert!(segment((0, 0), (10, 4)).midpoint() == point(5, 2));
+        assert!(segment((0, 0), (10, 4)).translate((1, 2)) == segment((1, 2), (11, 6)));
+        assert!(segment((0, 0), (10, 4)).reversed() == segment((10, 4), (0, 0)));
+        assert!(segment((5, 6), (1, 2)).bounding_rect() == rect((1, 2), (4, 4)));
+    }
+
+    #[test]
+    fn closest_point_test() {
+        let a = segment((0.0f32, 0.0), (10.0, 0.0));
+        assert!(a.closest_point((5.0, 3.0)) == point(5.0, 0.0));
+        assert!(a.closest_point((-5.0, 3.0)) == point(0.0, 0.0));
+        assert!(a.closest_point((15.0, -3.0)) == point(10.0, 0.0));
+        assert!(a.distance_to_point((13.0, 4.0)) == 5.0);
+        let b = segment((1.0f32, 1.0), (1.0, 1.0));
+        assert!(b.closest_point((4.0, 5.0)) == point(1.0, 1.0));
+        assert!(b.distance_to_point((4.0, 5.0)) == 5.0);
+    }
+
+    #[test]
+    fn distance_to_segment_test() {
+        let a = segment((0.0f32, 0.0), (10.0, 0.0));
+        assert!(a.distance_to_segment(&segment((5.0, -1.0), (5.0, 1.0))) == 0.0);
+        assert!(a.distance_to_segment(&segment((5.0, 2.0), (8.0, 6.0))) == 2.0);
+        assert!(a.distance_to_segment(&segment((13.0, 4.0), (20.0, 4.0))) == 5.0);
+        assert!(a.distance_to_segment(&segment((12.0, 0.0), (15.0, 0.0))) == 2.0);
+        let p = segment((3.0f32, 3.0), (3.0, 3.0));
+        assert!(a.distance_to_segment(&p) == 3.0 && p.distance_to_segment(&a) == 3.0);
+    }
+
+    #[test]
+    fn contains_point_eps_test() {
+        let a = segment((0.0f32, 0.0), (1.0, 3.0));
+        let p = point(1.0f32 / 3.0, 1.0);
+        assert!(a.contains_point_eps(p, 1e-6));
+        assert!(!a.contains_point_eps((0.5, 1.0), 1e-6));
+    }
+
+    #[test]
+    fn clip_test() {
+        let r = rect((0.0f32, 0.0), (10.0, 10.0));
+        let a = segment((-5.0f32, 5.0), (15.0, 5.0));
+        assert!(a.clip(&r) == Some(segment((0.0, 5.0), (10.0, 5.0))));
+        let a = segment((2.0f32, 2.0), (4.0, 6.0));
+        assert!(a.clip(&r) == Some(a));
+        assert!(segment((-5.0f32, -1.0), (15.0, -1.0)).clip(&r).is_none());
+        assert!(segment((11.0f32, 0.0), (20.0, 10.0)).clip(&r).is_none());
+        let a = segment((-10.0f32, 0.0), (0.0, 10.0));
+        assert!(a.clip(&r) == Some(segment((0.0, 10.0), (0.0, 10.0))));
+    }
+
+    #[test]
+    fn intersect_t_test() {
+        let a = segment((0.0f32, 0.0), (4.0, 0.0));
+        let b = segment((1.0f32, -1.0), (1.0, 3.0));
+        assert!(a.intersect_t(&b) == Some((0.25, 0.25)));
+        let c = segment((8.0f32, -1.0), (8.0, 1.0));
+        assert!(a.intersect_t(&c) == Some((2.0, 0.5)));
+        assert!(a.intersect_t(&segment((0.0, 1.0), (4.0, 1.0))).is_none());
+    }
+
+    #[test]
+    fn intersection_test() {
+        let a = segment((0.0f32, 0.0), (10.0, 10.0));
+        let b = segment((0.0f32, 10.0), (10.0, 0.0));
+        assert!(a.intersection(&b) == Some(point(5.0, 5.0)));
+        assert!(a.intersect(&b) == SegmentIntersection::Point(point(5.0, 5.0)));
+        // touching at an endpoint
+        let c = segment((10.0f32, 10.0), (20.0, 0.0));
+        assert!(a.intersection(&c) == Some(point(10.0, 10.0)));
+        // the lines cross outside of the segments
+        assert!(a
+            .intersection(&segment((20.0, 0.0), (30.0, -10.0)))
+            .is_none());
+        // parallel
+        let d = segment((0.0f32, 1.0), (10.0, 11.0));
+        assert!(a.intersection(&d).is_none());
+        assert!(a.intersect(&d) == SegmentIntersection::None);
+    }
+
+    #[test]
+    fn intersect_collinear_test() {
+        let a = segment((0.0f32, 0.0), (10.0, 0.0));
+        let b = segment((15.0f32, 0.0), (5.0, 0.0));
+        assert!(a.intersect(&b) == SegmentIntersection::Overlap(segment((5.0, 0.0), (10.0, 0.0))));
+        assert!(b.intersect(&a) == SegmentIntersection::Overlap(segment((10.0, 0.0), (5.0, 0.0))));
+        assert!(a.intersection(&b).is_none());
+        let c = segment((2.0f32, 0.0), (4.0, 0.0));
+        assert!(a.intersect(&c) == SegmentIntersection::Overlap(c));
+        let d = segment((10.0f32, 0.0), (12.0, 0.0));
+        assert!(a.intersect(&d) == SegmentIntersection::Point(point(10.0, 0.0)));
+        let e = segment((11.0f32, 0.0), (12.0, 0.0));
+        assert!(a.intersect(&e) == SegmentIntersection::None);
+        let f = segment((3.0f32, 0.0), (3.0, 0.0));
+        assert!(a.intersect(&f) == SegmentIntersection::Point(point(3.0, 0.0)));
+        assert!(f.intersect(&a) == SegmentIntersection::Point(point(3.0, 0.0)));
+        assert!(f.intersect(&segment((3.0, 1.0), (3.0, 1.0))) == SegmentIntersection::None);
+    }
+
+    #[test]
+    fn orientation_unsigned_test() {
+        let a = segment((5u32, 5), (0, 0));
+        assert!(a.orientation(point(0, 5)) == Ordering::Less);
+        assert!(a.orientation(point(5, 0)) == Ordering::Greater);
+        assert!(a.orientation(point(9, 9)) == Ordering::Equal);
+        assert!(a.contains_point(point(2, 2)));
+        assert!(!a.contains_point(point(9, 9)));
+    }
+}
@@ -0,0 +1,200 @@
+use crate::*;
+
+/// Any of the `Copy` shapes, for storing colliders of different kinds together.
+///
+/// `Polygon` is left out so that `Shape` stays `Copy` and available without `alloc`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Shape<T> {
+    Point(Point<T>),
+    Rect(Rect<T>),
+    Circle(Circle<T>),
+    Segment(Segment<T>),
+    Triangle(Triangle<T>),
+    RoundedRect(RoundedRect<T>),
+}
+
+/// Evaluates `$e` with `$v` bound to the shape inside `$shape`.
+macro_rules! dispatch {
+    ($shape:expr, $v:ident => $e:expr) => {
+        match $shape {
+            Shape::Point($v) => $e,
+            Shape::Rect($v) => $e,
+            Shape::Circle($v) => $e,
+            Shape::Segment($v) => $e,
+            Shape::Triangle($v) => $e,
+            Shape::RoundedRect($v) => $e,
+        }
+    };
+}
+
+impl<T> Shape<T>
+where
+    T: core::ops::Add<T, Output = T> + Copy,
+{
+    #[inline]
+    pub fn translate(&self, d: impl Into<Vector<T>>) -> Self {
+        let d = d.into();
+        match self {
+            Self::Point(p) => Self::Point(*p + d),
+            Self::Rect(r) => Self::Rect(r.translate(d)),
+            Self::Circle(c) => Self::Circle(Circle::new(c.center + d, c.radius)),
+            Self::Segment(s) => Self::Segment(s.translate(d)),
+            Self::Triangle(t) => Self::Triangle(t.translate(d)),
+            Self::RoundedRect(r) => Self::RoundedRect(r.translate(d)),
+        }
+    }
+}
+
+impl<T> Shape<T>
+where
+    T: Widen + core::ops::Div<T, Output = T> + One + Zero,
+{
+    /// Returns the smallest rect containing the shape, which has a zero size for a point.
+    #[inline]
+    pub fn bounding_rect(&self) -> Rect<T> {
+        match self {
+            Self::Point(p) => Rect::new(*p, (T::zero(), T::zero())),
+            Self::Rect(r) => r.normalized(),
+            Self::Circle(c) => c.bounding_rect(),
+            Self::Segment(s) => s.bounding_rect(),
+            Self::Triangle(t) => t.bounding_rect(),
+            Self::RoundedRect(r) => r.bounding_rect().normalized(),
+        }
+    }
+
+    /// Returns `true` if `p` is inside or on the boundary of the shape.
+    #[inline]
+    pub fn contains_point(&self, p: impl Into<Point<T>>) -> bool {
+        self.contains(&p.into())
+    }
+}
+
+macro_rules! impl_shape_collision {
+    ($($t:ident),*) => {
+        $(
+            impl<T> Collision<$t<T>> for Shape<T>
+            where
+                T: Widen + core::ops::Div<T, Output = T> + One + Zero,
+            {
+                #[inline]
+                fn is_crossing(&self, rhs: &$t<T>) -> bool {
+                    dispatch!(self, a => a.is_crossing(rhs))
+                }
+
+                #[inline]
+                fn contains(&self, v: &$t<T>) -> bool {
+                    dispatch!(self, a => a.contains(v))
+                }
+            }
+
+            impl<T> Collision<Shape<T>> for $t<T>
+            where
+                T: Widen + core::ops::Div<T, Output = T> + One + Zero,
+            {
+                #[inline]
+                fn is_crossing(&self, rhs: &Shape<T>) -> bool {
+                    dispatch!(rhs, b => self.is_crossing(b))
+                }
+
+                #[inline]
+                fn contains(&self, v: &Shape<T>) -> bool {
+                    dispatch!(v, b => self.contains(b))
+                }
+            }
+
+            impl<T> From<$t<T>> for Shape<T> {
+                #[inline]
+                fn from(src: $t<T>) -> Self {
+                    Self::$t(src)
+                }
+            }
+        )*
+    };
+}
+
+impl_shape_collision!(Point, Rect, Circle, Segment, Triangle, RoundedRect);
+
+impl<T> Collision<Shape<T>> for Shape<T>
+where
+    T: Widen + core::ops::Div<T, Output = T> + One + Zero,
+{
+    #[inline]
+    fn is_crossing(&self, rhs: &Shape<T>) -> bool {
+        dispatch!(self, a => a.is_crossing(rhs))
+    }
+
+    #[inline]
+    fn contains(&self, v: &Shape<T>) -> bool {
+        dispatch!(self, a => a.contains(v))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn shapes() -> [Shape<i32>; 6] {
+        [
+            point(5, 5).into(),
+            rect((0, 0), (10, 10)).into(),
+            circle((20, 0), 5).into(),
+            segment((0, 20), (10, 30)).into(),
+            triangle((20, 20), (30, 20), (20, 30)).into(),
+            rounded_rect(((40, 0), (10, 10)), 3).into(),
+        ]
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn point_query_test() {
+        let shapes: alloc::vec::Vec<Shape<i32>> = shapes().to_vec();
+        let hits = |p: Point<i32>| {
+            shapes
+                .iter()
+                .enumerate()
+                .filter(|(_, s)| s.contains_point(p))
+                .map(|(i, _)| i)
+                .collect::<alloc::vec::Vec<_>>()
+        };
+        assert!(hits(point(5, 5)) == [0, 1]);
+        assert!(hits(point(1, 1)) == [1]);
+        assert!(hits(point(23, 4)) == [2]);
+        assert!(hits(point(5, 25)) == [3]);
+        assert!(hits(point(25, 25)) == [4]);
+        assert!(hits(point(45, 5)) == [5]);
+        assert!(hits(point(40, 0)).is_empty());
+        assert!(hits(point(100, 100)).is_empty());
+    }
+
+    #[test]
+    fn collision_test() {
+        let shapes = shapes();
+        let probe = Shape::from(circle((15, 5), 5));
+        let crossing = shapes.map(|s| s.is_crossing(&probe));
+        assert!(crossing == [false, true, true, false, false, false]);
+        assert!(shapes.map(|s| probe.is_crossing(&s)) == crossing);
+        assert!(shapes.map(|s| s.is_crossing(&rect((0, 0), (50, 50)))) == [true; 6]);
+        assert!(contains(&shapes[1], &shapes[0]));
+        assert!(!contains(&shapes[0], &shapes[1]));
+        assert!(contains(&rect((-5, -5), (60, 40)), &shapes[4]));
+        assert!(shapes.iter().all(|s| s.is_crossing(s)));
+    }
+
+    #[test]
+    fn translate_bounding_rect_test() {
+        let moved = shapes().map(|s| s.translate((1, 2)));
+        let expected = [
+            rect((6, 7), (0, 0)),
+            rect((1, 2), (10, 10)),
+            rect((16, -3), (10, 10)),
+            rect((1, 22), (10, 10)),
+            rect((21, 22), (10, 10)),
+            rect((41, 2), (10, 10)),
+        ];
+        for (s, r) in moved.iter().zip(expected) {
+            assert!(s.bounding_rect() == r);
+        }
+        assert!(Shape::from(rect((10, 10), (-5, -5))).bounding_rect() == rect((5, 5), (5, 5)));
+    }
+}
@@ -1,6 +1,6 @@
 use crate::*;
 
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Debug)]
 #[repr(C)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Size<T> {
@@ -10,7 +10,7 @@ pub struct Size<T> {
 
 impl<T> Size<T> {
     #[inline]
-    pub fn new(width: T, height: T) -> Self {
+    pub const fn new(width: T, height: T) -> Self {
         Self { width, height }
     }
 
@@ -18,6 +18,230 @@ impl<T> Size<T> {
     pub fn map<R>(self, mut f: impl FnMut(T) -> R) -> Size<R> {
         Size::new(f(self.width), f(self.height))
     }
+
+    #[inline]
+    pub fn transposed(self) -> Self {
+        Self::new(self.height, self.width)
+    }
+
+    #[inline]
+    pub fn with_width(self, width: T) -> Self {
+        Self { width, ..self }
+    }
+
+    #[inline]
+    pub fn with_height(self, height: T) -> Self {
+        Self { height, ..self }
+    }
+
+    /// Iterates references to the components in width, height order.
+    #[inline]
+    pub fn iter(&self) -> core::array::IntoIter<&T, 2> {
+        [&self.width, &self.height].into_iter()
+    }
+
+    #[inline]
+    pub fn as_array(&self) -> &[T; 2] {
+        // SAFETY: `Size<T>` is `repr(C)` with two fields of `T`, which has the same layout as `[T; 2]`.
+        unsafe { &*(self as *const Self as *const [T; 2]) }
+    }
+
+    #[inline]
+    pub fn as_array_mut(&mut self) -> &mut [T; 2] {
+        // SAFETY: `Size<T>` is `repr(C)` with two fields of `T`, which has the same layout as `[T; 2]`.
+        unsafe { &mut *(self as *mut Self as *mut [T; 2]) }
+    }
+}
+
+impl<T: Scalar + Zero + One> Size<T> {
+    /// Interpolates each component linearly. `t` outside of `0..=1` extrapolates.
+    ///
+    /// # Panics
+    ///
+    /// For unsigned `T`, panics on overflow in debug builds if an extrapolated component would be
+    /// negative.
+    #[inline]
+    pub fn lerp(self, other: impl Into<Self>, t: T) -> Self {
+        let other = other.into();
+        Self::new(
+            lerp(self.width, other.width, t),
+            lerp(self.height, other.height, t),
+        )
+    }
+
+    /// Same as `lerp` but `t` is clamped to `0..=1`.
+    #[inline]
+    pub fn lerp_clamped(self, other: impl Into<Self>, t: T) -> Self {
+        self.lerp(other, clamp_unit(t))
+    }
+}
+
+impl<T: PartialOrd + Copy> Size<T> {
+    /// Returns the componentwise minimum.
+    #[inline]
+    pub fn min(self, rhs: impl Into<Self>) -> Self {
+        let rhs = rhs.into();
+        Self::new(
+            partial_min(self.width, rhs.width),
+            partial_min(self.height, rhs.height),
+        )
+    }
+
+    /// Returns the componentwise maximum.
+    #[inline]
+    pub fn max(self, rhs: impl Into<Self>) -> Self {
+        let rhs = rhs.into();
+        Self::new(
+            partial_max(self.width, rhs.width),
+            partial_max(self.height, rhs.height),
+        )
+    }
+
+    /// Clamps each component to `lo..=hi`.
+    ///
+    /// When a component of `lo` is greater than the one of `hi`, that component becomes `hi`.
+    #[inline]
+    pub fn clamp(self, lo: impl Into<Self>, hi: impl Into<Self>) -> Self {
+        self.max(lo).min(hi)
+    }
+
+    #[inline]
+    pub fn min_element(self) -> T {
+        partial_min(self.width, self.height)
+    }
+
+    #[inline]
+    pub fn max_element(self) -> T {
+        partial_max(self.width, self.height)
+    }
+
+    /// Returns `true` if both dimensions are less than or equal to the ones of `other`.
+    #[inline]
+    pub fn fits_within(&self, other: impl Into<Self>) -> bool {
+        let other = other.into();
+        self.width <= other.width && self.height <= other.height
+    }
+
+    /// Same as `max`, for accumulating a size large enough for every item.
+    #[inline]
+    pub fn expand_to(self, other: impl Into<Self>) -> Self {
+        self.max(other)
+    }
+}
+
+impl<T: Float> Size<T> {
+    /// Returns `true` if every component differs from the one of `other` by at most `epsilon`.
+    #[inline]
+    pub fn approx_eq(&self, other: &Self, epsilon: T) -> bool {
+        (self.width - other.width).abs() <= epsilon && (self.height - other.height).abs() <= epsilon
+    }
+
+    #[inline]
+    pub fn floor(self) -> Self {
+        self.map(T::floor)
+    }
+
+    #[inline]
+    pub fn ceil(self) -> Self {
+        self.map(T::ceil)
+    }
+
+    #[inline]
+    pub fn round(self) -> Self {
+        self.map(T::round)
+    }
+
+    /// Returns `width / height`, which is infinite if only `height` is zero and NaN if both are.
+    #[inline]
+    pub fn aspect_ratio(self) -> T {
+        self.width / self.height
+    }
+
+    /// Returns the largest size within `bounds` with the same aspect ratio as `self`.
+    ///
+    /// Returns `(0, 0)` if both dimensions of `self` are zero.
+    #[inline]
+    pub fn fit_into(self, bounds: impl Into<Self>) -> Self {
+        let bounds = bounds.into();
+        self.scale_to(T::min(
+            bounds.width / self.width,
+            bounds.height / self.height,
+        ))
+    }
+
+    /// Returns the smallest size covering `bounds` with the same aspect ratio as `self`.
+    ///
+    /// Returns `(0, 0)` if either dimension of `self` is zero.
+    #[inline]
+    pub fn fill(self, bounds: impl Into<Self>) -> Self {
+        let bounds = bounds.into();
+        self.scale_to(T::max(
+            bounds.width / self.width,
+            bounds.height / self.height,
+        ))
+    }
+
+    #[inline]
+    fn scale_to(self, scale: T) -> Self {
+        if scale.is_finite() {
+            self * scale
+        } else {
+            Self::new(T::zero(), T::zero())
+        }
+    }
+}
+
+impl<T: core::ops::Mul<T, Output = T>> Size<T> {
+    #[inline]
+    pub fn area(self) -> T {
+        self.width * self.height
+    }
+}
+
+impl<T: num::traits::CheckedMul> Size<T> {
+    /// Returns `None` if the area overflows.
+    #[inline]
+    pub fn checked_area(self) -> Option<T> {
+        self.width.checked_mul(&self.height)
+    }
+}
+
+impl<T: Zero + PartialOrd> Size<T> {
+    /// Returns `true` unless both dimensions are positive. NaN dimensions are empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        !(self.width > T::zero() && self.height > T::zero())
+    }
+}
+
+impl<T: ToPrimitive + NumCast> Size<T> {
+    /// Same as `fit_into` but for any numeric type, rounding the result down.
+    ///
+    /// The computation is done in `f64`. Returns `None` if the result is not representable in `T`.
+    #[inline]
+    pub fn fit_into_floor(self, bounds: impl Into<Self>) -> Option<Self> {
+        self.as_f64()
+            .fit_into(bounds.into().as_f64())
+            .floor()
+            .cast()
+    }
+}
+
+impl<T: TotalCmpKey> Size<T> {
+    /// Returns a key ordered lexicographically like the derived `Ord`, using `total_cmp` for
+    /// each component. It makes float components usable as `BTreeMap` or `HashMap` keys.
+    #[inline]
+    pub fn total_cmp_key(self) -> (T::Key, T::Key) {
+        (self.width.total_cmp_key(), self.height.total_cmp_key())
+    }
+}
+
+impl<T: Widen> Size<T> {
+    /// Converts each component to `T::Wide`, e.g. to compute products without overflow.
+    #[inline]
+    pub fn widen(self) -> Size<T::Wide> {
+        self.map(T::widen)
+    }
 }
 
 impl<T: ToPrimitive> Size<T> {
@@ -25,6 +249,109 @@ impl<T: ToPrimitive> Size<T> {
     pub fn cast<U: NumCast>(self) -> Option<Size<U>> {
         Some(Size::new(U::from(self.width)?, U::from(self.height)?))
     }
+
+    #[inline]
+    pub fn try_cast<U: NumCast>(self) -> Result<Size<U>, CastError>
+    where
+        T: Copy,
+    {
+        Ok(Size::new(
+            cast_component(self.width, "Size", "width")?,
+            cast_component(self.height, "Size", "height")?,
+        ))
+    }
+
+    /// Converts each component to `f32`. See [the conversions of `Point`](Point#conversions).
+    #[inline]
+    pub fn as_f32(self) -> Size<f32> {
+        self.map(|v| v.to_f32().unwrap_or(f32::NAN))
+    }
+
+    /// Converts each component to `f64`. See [the conversions of `Point`](Point#conversions).
+    #[inline]
+    pub fn as_f64(self) -> Size<f64> {
+        self.map(|v| v.to_f64().unwrap_or(f64::NAN))
+    }
+
+    /// Converts each component to `i32`. See [the conversions of `Point`](Point#conversions).
+    #[inline]
+    pub fn as_i32(self) -> Size<i32> {
+        self.map(|v| v.to_f64().map_or(0, |v| v as i32))
+    }
+
+    /// Converts each component to `u32`. See [the conversions of `Point`](Point#conversions).
+    #[inline]
+    pub fn as_u32(self) -> Size<u32> {
+        self.map(|v| v.to_f64().map_or(0, |v| v as u32))
+    }
+}
+
+impl<T: num::traits::CheckedAdd> Size<T> {
+    /// Returns `None` if either component overflows.
+    #[inline]
+    pub fn checked_add(self, rhs: impl Into<Self>) -> Option<Self> {
+        let rhs = rhs.into();
+        Some(Size::new(
+            self.width.checked_add(&rhs.width)?,
+            self.height.checked_add(&rhs.height)?,
+        ))
+    }
+}
+
+impl<T: num::traits::CheckedSub> Size<T> {
+    /// Returns `None` if either component overflows.
+    #[inline]
+    pub fn checked_sub(self, rhs: impl Into<Self>) -> Option<Self> {
+        let rhs = rhs.into();
+        Some(Size::new(
+            self.width.checked_sub(&rhs.width)?,
+            self.height.checked_sub(&rhs.height)?,
+        ))
+    }
+}
+
+impl<T: num::traits::SaturatingAdd> Size<T> {
+    #[inline]
+    pub fn saturating_add(self, rhs: impl Into<Self>) -> Self {
+        let rhs = rhs.into();
+        Size::new(
+            self.width.saturating_add(&rhs.width),
+            self.height.saturating_add(&rhs.height),
+        )
+    }
+}
+
+impl<T: num::traits::SaturatingSub> Size<T> {
+    #[inline]
+    pub fn saturating_sub(self, rhs: impl Into<Self>) -> Self {
+        let rhs = rhs.into();
+        Size::new(
+            self.width.saturating_sub(&rhs.width),
+            self.height.saturating_sub(&rhs.height),
+        )
+    }
+}
+
+impl<T: num::traits::WrappingAdd> Size<T> {
+    #[inline]
+    pub fn wrapping_add(self, rhs: impl Into<Self>) -> Self {
+        let rhs = rhs.into();
+        Size::new(
+            self.width.wrapping_add(&rhs.width),
+            self.height.wrapping_add(&rhs.height),
+        )
+    }
+}
+
+impl<T: num::traits::WrappingSub> Size<T> {
+    #[inline]
+    pub fn wrapping_sub(self, rhs: impl Into<Self>) -> Self {
+        let rhs = rhs.into();
+        Size::new(
+            self.width.wrapping_sub(&rhs.width),
+            self.height.wrapping_sub(&rhs.height),
+        )
+    }
 }
 
 impl<T> From<(T, T)> for Size<T> {
@@ -48,6 +375,16 @@ impl<T> From<Vector<T>> for Size<T> {
     }
 }
 
+/// Parses `"WxH"` or `"W,H"`, allowing whitespace around each component.
+impl<T: core::str::FromStr> core::str::FromStr for Size<T> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [width, height] = parse_components(s, &['x', ','], "Size", ["width", "height"])?;
+        Ok(Self::new(width, height))
+    }
+}
+
 impl<T> PartialEq<(T, T)> for Size<T>
 where
     T: PartialEq,
@@ -88,9 +425,45 @@ where
     }
 }
 
-impl<T, U> std::ops::Add<U> for Size<T>
+impl<T: Zero> Zero for Size<T> {
+    #[inline]
+    fn zero() -> Self {
+        Self::new(T::zero(), T::zero())
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.width.is_zero() && self.height.is_zero()
+    }
+}
+
+/// The unit scale factor, `(1, 1)`.
+///
+/// `One` requires `Size * Size`, which is the componentwise product, so `(1, 1)` is its identity.
+impl<T: One + Copy> One for Size<T> {
+    #[inline]
+    fn one() -> Self {
+        Self::new(T::one(), T::one())
+    }
+}
+
+impl<T: Zero> core::iter::Sum for Size<T> {
+    #[inline]
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |acc, v| acc + v)
+    }
+}
+
+impl<'a, T: Zero + Copy> core::iter::Sum<&'a Size<T>> for Size<T> {
+    #[inline]
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl<T, U> core::ops::Add<U> for Size<T>
 where
-    T: std::ops::Add<T, Output = T>,
+    T: core::ops::Add<T, Output = T>,
     U: Into<Self>,
 {
     type Output = Self;
@@ -102,9 +475,9 @@ where
     }
 }
 
-impl<T, U> std::ops::Sub<U> for Size<T>
+impl<T, U> core::ops::Sub<U> for Size<T>
 where
-    T: std::ops::Sub<T, Output = T>,
+    T: core::ops::Sub<T, Output = T>,
     U: Into<Self>,
 {
     type Output = Self;
@@ -116,9 +489,21 @@ where
     }
 }
 
-impl<T> std::ops::Mul<T> for Size<T>
+impl<T> core::ops::Neg for Size<T>
 where
-    T: std::ops::Mul<T, Output = T> + Copy,
+    T: core::ops::Neg<Output = T>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self::new(-self.width, -self.height)
+    }
+}
+
+impl<T> core::ops::Mul<T> for Size<T>
+where
+    T: core::ops::Mul<T, Output = T> + Copy,
 {
     type Output = Self;
 
@@ -128,9 +513,9 @@ where
     }
 }
 
-impl<T> std::ops::Div<T> for Size<T>
+impl<T> core::ops::Div<T> for Size<T>
 where
-    T: std::ops::Div<T, Output = T> + Copy,
+    T: core::ops::Div<T, Output = T> + Copy,
 {
     type Output = Self;
 
@@ -140,9 +525,9 @@ where
     }
 }
 
-impl<T, U> std::ops::AddAssign<U> for Size<T>
+impl<T, U> core::ops::AddAssign<U> for Size<T>
 where
-    T: std::ops::AddAssign,
+    T: core::ops::AddAssign,
     U: Into<Self>,
 {
     #[inline]
@@ -153,9 +538,9 @@ where
     }
 }
 
-impl<T, U> std::ops::SubAssign<U> for Size<T>
+impl<T, U> core::ops::SubAssign<U> for Size<T>
 where
-    T: std::ops::SubAssign,
+    T: core::ops::SubAssign,
     U: Into<Self>,
 {
     #[inline]
@@ -166,9 +551,9 @@ where
     }
 }
 
-impl<T> std::ops::MulAssign<T> for Size<T>
+impl<T> core::ops::MulAssign<T> for Size<T>
 where
-    T: std::ops::MulAssign + Copy,
+    T: core::ops::MulAssign + Copy,
 {
     #[inline]
     fn mul_assign(&mut self, rhs: T) {
@@ -177,9 +562,9 @@ where
     }
 }
 
-impl<T> std::ops::DivAssign<T> for Size<T>
+impl<T> core::ops::DivAssign<T> for Size<T>
 where
-    T: std::ops::DivAssign + Copy,
+    T: core::ops::DivAssign + Copy,
 {
     #[inline]
     fn div_assign(&mut self, rhs: T) {
@@ -188,8 +573,47 @@ where
     }
 }
 
+impl<T> core::ops::Index<usize> for Size<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: usize) -> &T {
+        match index {
+            0 => &self.width,
+            1 => &self.height,
+            _ => panic!("Size index out of range: {}", index),
+        }
+    }
+}
+
+impl<T> core::ops::IndexMut<usize> for Size<T> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        match index {
+            0 => &mut self.width,
+            1 => &mut self.height,
+            _ => panic!("Size index out of range: {}", index),
+        }
+    }
+}
+
+/// Iterates the components in width, height order.
+impl<T> IntoIterator for Size<T> {
+    type Item = T;
+    type IntoIter = core::array::IntoIter<T, 2>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        [self.width, self.height].into_iter()
+    }
+}
+
+impl_componentwise_ops!(Size { width, height }, Size<T>, (T, T), [T; 2]);
+
+impl_left_scalar_mul!(Size);
+
 #[inline]
-pub fn size<T>(width: T, height: T) -> Size<T> {
+pub const fn size<T>(width: T, height: T) -> Size<T> {
     Size::new(width, height)
 }
 
@@ -197,11 +621,252 @@ pub fn size<T>(width: T, height: T) -> Size<T> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn as_test() {
+        assert!(size(640u32, 480).as_f64() == (640.0, 480.0));
+        assert!(size(10.7f32, -3.2).as_u32() == (10, 0));
+        assert!(size(5e9f64, 1.0).as_u32() == (u32::MAX, 1));
+    }
+
+    #[test]
+    fn try_cast_test() {
+        assert!(size(1, 2).try_cast::<u8>() == Ok(size(1, 2)));
+        let e = size(-1, 2).try_cast::<u8>().unwrap_err();
+        assert!(e.type_name() == "Size" && e.component() == "width");
+        let e = size(1, 1000).try_cast::<u8>().unwrap_err();
+        assert!(e.type_name() == "Size" && e.component() == "height");
+        assert!(size(1, 2).cast::<u8>().is_some());
+    }
+
+    #[test]
+    fn const_test() {
+        const A: Size<u32> = Size::new(1, 2);
+        const B: Size<u32> = size(3, 4);
+        assert!(A == (1, 2) && B == (3, 4));
+    }
+
+    #[test]
+    fn lerp_test() {
+        let a = size(10.0f32, 20.0);
+        let b = size(30.0f32, 60.0);
+        assert!(a.lerp(b, 0.0) == a && a.lerp(b, 1.0) == b);
+        assert!(a.lerp(b, 0.5) == (20.0, 40.0));
+        assert!(a.lerp(b, 1.5) == (40.0, 80.0));
+        assert!(a.lerp_clamped(b, 1.5) == b);
+    }
+
+    #[test]
+    fn min_max_test() {
+        let a = size(640u32, 480);
+        assert!(a.min((320, 600)) == (320, 480) && a.max((320, 600)) == (640, 600));
+        assert!(a.clamp((100, 100), (500, 400)) == (500, 400));
+        assert!(a.min_element() == 480 && a.max_element() == 640);
+    }
+
+    #[test]
+    fn sum_test() {
+        let v = [size(1.5f32, 2.0), size(-0.5, 1.0)];
+        assert!(v.iter().sum::<Size<f32>>() == (1.0, 3.0));
+        assert!(v.into_iter().sum::<Size<f32>>() == (1.0, 3.0));
+        assert!([].iter().sum::<Size<u32>>() == (0, 0));
+    }
+
+    #[test]
+    fn approx_eq_test() {
+        let a = size(2.0f32.sqrt().powi(2), 1.0);
+        assert!(a != (2.0, 1.0));
+        assert!(a.approx_eq(&size(2.0, 1.0), f32::EPSILON * 2.0));
+        assert!(!a.approx_eq(&size(2.0, 1.5), 0.1));
+    }
+
+    #[test]
+    fn with_test() {
+        let a = size(1, 2);
+        assert!(a.transposed() == (2, 1));
+        assert!(a.with_width(10) == (10, 2));
+        assert!(a.with_height(20) == (1, 20));
+    }
+
+    #[test]
+    fn checked_saturating_wrapping_test() {
+        let a = size(255u8, 0);
+        assert!(a.checked_add((0, 255)) == Some(size(255, 255)));
+        assert!(a.checked_add((1, 0)).is_none());
+        assert!(a.checked_sub((0, 1)).is_none());
+        assert!(a.saturating_add((1, 1)) == (255, 1));
+        assert!(a.saturating_sub((1, 1)) == (254, 0));
+        assert!(a.wrapping_add((1, 0)) == (0, 0));
+        assert!(a.wrapping_sub((0, 1)) == (255, 255));
+    }
+
+    #[test]
+    fn ord_test() {
+        use std::collections::BTreeSet;
+        let set = [size(2u32, 1), size(1, 5), size(1, 3)]
+            .into_iter()
+            .collect::<BTreeSet<_>>();
+        assert!(set.into_iter().collect::<Vec<_>>() == [size(1, 3), size(1, 5), size(2, 1)]);
+        assert!(size(1.0f64, 2.0).total_cmp_key() < size(1.0, 3.0).total_cmp_key());
+    }
+
+    #[test]
+    fn round_test() {
+        let a = size(1.2f32, 3.5);
+        assert!(a.floor() == (1.0, 3.0) && a.ceil() == (2.0, 4.0) && a.round() == (1.0, 4.0));
+    }
+
+    #[test]
+    fn area_test() {
+        assert!(size(3, 4).area() == 12);
+        assert!(size(1.5f32, 2.0).area() == 3.0);
+        assert!(size(70000u32, 70000).checked_area().is_none());
+        assert!(size(300u32, 200).checked_area() == Some(60000));
+    }
+
+    #[test]
+    fn is_empty_test() {
+        assert!(!size(1, 1).is_empty());
+        assert!(size(0, 5).is_empty() && size(5, 0).is_empty());
+        assert!(size(-1, 5).is_empty());
+        assert!(size(0u32, 0).is_empty());
+        assert!(size(f32::NAN, 1.0).is_empty());
+    }
+
+    #[test]
+    fn aspect_ratio_test() {
+        assert!(size(16.0f32, 9.0).aspect_ratio() == 16.0 / 9.0);
+        assert!(size(1.0f32, 0.0).aspect_ratio() == f32::INFINITY);
+        assert!(size(0.0f64, 0.0).aspect_ratio().is_nan());
+    }
+
+    #[test]
+    fn fit_fill_test() {
+        let portrait = size(600.0f32, 800.0);
+        let landscape = size(1920.0f32, 1080.0);
+        assert!(portrait.fit_into(landscape) == (810.0, 1080.0));
+        assert!(portrait.fill(landscape) == (1920.0, 2560.0));
+        assert!(landscape.fit_into(portrait) == (600.0, 337.5));
+        assert!(landscape.fill(portrait) == (800.0 * 16.0 / 9.0, 800.0));
+        assert!(size(0.0f32, 0.0).fit_into(landscape) == (0.0, 0.0));
+        assert!(size(0.0f32, 10.0).fit_into(landscape) == (0.0, 1080.0));
+        assert!(size(0.0f32, 10.0).fill(landscape) == (0.0, 0.0));
+        assert!(portrait.fit_into((0.0, 0.0)) == (0.0, 0.0));
+    }
+
+    #[test]
+    fn fit_into_floor_test() {
+        assert!(size(600u32, 800).fit_into_floor((1920, 1080)) == Some(size(810, 1080)));
+        assert!(size(3u32, 2).fit_into_floor((100, 100)) == Some(size(100, 66)));
+        assert!(size(0u32, 0).fit_into_floor((100, 100)) == Some(size(0, 0)));
+    }
+
+    #[test]
+    fn fits_within_test() {
+        assert!(size(16u32, 16).fits_within((32, 16)));
+        assert!(!size(16u32, 17).fits_within((32, 16)));
+        assert!(size(-1, 0).fits_within((0, 0)));
+        assert!(size(1.5f32, 2.0).fits_within((1.5, 2.0)));
+        assert!(!size(f32::NAN, 1.0).fits_within((10.0, 10.0)));
+    }
+
+    #[test]
+    fn expand_to_test() {
+        let items = [size(10u32, 3), size(4, 8), size(6, 6)];
+        let total = items.iter().fold(size(0, 0), |acc, s| acc.expand_to(*s));
+        assert!(total == (10, 8));
+        assert!(size(-2, 5).expand_to((-3, 7)) == (-2, 7));
+        assert!(size(0.5f64, 1.0).clamp((1.0, 0.0), (2.0, 0.5)) == (1.0, 0.5));
+    }
+
     #[test]
     fn map_test() {
         assert!(size(1, 2).map(|x| x + 1) == size(2, 3));
     }
 
+    #[test]
+    fn zero_one_test() {
+        fn zero<T: Zero>() -> T {
+            Zero::zero()
+        }
+        fn one<T: One>() -> T {
+            One::one()
+        }
+        assert!(zero::<Size<u32>>() == (0, 0));
+        assert!(zero::<Size<f32>>() == (0.0, 0.0));
+        assert!(!Zero::is_zero(&size(0, 1)));
+        assert!(one::<Size<u32>>() == (1, 1));
+        assert!(size(3.0, 4.0) * one::<Size<f32>>() == (3.0, 4.0));
+    }
+
+    #[test]
+    fn componentwise_test() {
+        assert!(size(2, 3) * size(4, 5) == (8, 15));
+        assert!(size(2, 3) * (4, 5) == (8, 15));
+        assert!(size(2, 3) * 2 == (4, 6));
+        assert!(size(8.0f32, 15.0) / [4.0, 5.0] == (2.0, 3.0));
+        let mut a = size(2, 3);
+        a *= (4, 5);
+        assert!(a == (8, 15));
+        a /= size(2, 5);
+        assert!(a == (4, 3));
+    }
+
+    #[test]
+    fn as_array_test() {
+        let mut a = size(1, 2);
+        assert!(*a.as_array() == [1, 2]);
+        a.as_array_mut()[1] = 10;
+        assert!(a.height == 10);
+        assert!(a.into_iter().eq(a.as_array().iter().copied()));
+    }
+
+    #[test]
+    fn index_test() {
+        let mut a = size(1, 2);
+        assert!(a[0] == 1 && a[1] == 2);
+        a[0] = 10;
+        assert!(a.width == 10);
+    }
+
+    #[test]
+    #[should_panic(expected = "Size index out of range: 2")]
+    fn index_out_of_range_test() {
+        let a = size(1, 2);
+        let _ = a[2];
+    }
+
+    #[test]
+    fn into_iter_test() {
+        let a = size(1, 2);
+        let it = a.into_iter();
+        assert!(it.len() == 2 && it.size_hint() == (2, Some(2)));
+        assert!(a.into_iter().collect::<Vec<_>>() == [a.width, a.height]);
+        assert!(a.iter().copied().collect::<Vec<_>>() == [1, 2]);
+        assert!(a.iter().len() == 2);
+    }
+
+    #[test]
+    fn from_str_round_trip_test() {
+        for s in [size(0, 0), size(640, 480), size(u32::MAX, 1)] {
+            assert!(format!("{}x{}", s.width, s.height).parse::<Size<u32>>() == Ok(s));
+            assert!(format!("{},{}", s.width, s.height).parse::<Size<u32>>() == Ok(s));
+        }
+        for s in [size(1.5f32, 0.1), size(f32::MAX, f32::EPSILON)] {
+            assert!(format!("{} x {}", s.width, s.height).parse::<Size<f32>>() == Ok(s));
+        }
+    }
+
+    #[test]
+    fn from_str_test() {
+        assert!("640x480".parse::<Size<u32>>() == Ok(size(640, 480)));
+        assert!("640, 480".parse::<Size<u32>>() == Ok(size(640, 480)));
+        assert!(" 1.5 x 2 ".parse::<Size<f32>>() == Ok(size(1.5, 2.0)));
+        let e = "640x".parse::<Size<u32>>().unwrap_err();
+        assert!(e.kind() == ParseErrorKind::Missing && e.component() == Some("height"));
+        let e = "wide,480".parse::<Size<u32>>().unwrap_err();
+        assert!(e.kind() == ParseErrorKind::Invalid && e.component() == Some("width"));
+    }
+
     #[test]
     fn eq_test() {
         assert!(size(1, 2) == size(1, 2));
@@ -233,6 +898,12 @@ mod tests {
         assert!(c == size(5, 5));
     }
 
+    #[test]
+    fn neg_test() {
+        assert!(-size(1, -2) == (-1, 2));
+        assert!(-size(1.5f32, 0.0) == (-1.5, 0.0));
+    }
+
     #[test]
     fn mul_test() {
         let a = size(1, 2);
@@ -240,6 +911,12 @@ mod tests {
         assert!(b == (2, 4));
     }
 
+    #[test]
+    fn left_mul_test() {
+        assert!(2u32 * size(640u32, 480) == (1280, 960));
+        assert!(1.5f32 * size(2.0f32, 4.0) == size(2.0, 4.0) * 1.5);
+    }
+
     #[test]
     fn div_test() {
         let a = size(2, 6);
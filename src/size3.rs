@@ -0,0 +1,338 @@
+use crate::*;
+
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Size3<T> {
+    pub width: T,
+    pub height: T,
+    pub depth: T,
+}
+
+impl<T> Size3<T> {
+    #[inline]
+    pub const fn new(width: T, height: T, depth: T) -> Self {
+        Self {
+            width,
+            height,
+            depth,
+        }
+    }
+
+    #[inline]
+    pub fn map<R>(self, mut f: impl FnMut(T) -> R) -> Size3<R> {
+        Size3::new(f(self.width), f(self.height), f(self.depth))
+    }
+
+    #[inline]
+    pub fn truncate(self) -> Size<T> {
+        Size::new(self.width, self.height)
+    }
+}
+
+impl<T> Size<T> {
+    #[inline]
+    pub fn extend(self, depth: T) -> Size3<T> {
+        Size3::new(self.width, self.height, depth)
+    }
+}
+
+impl<T: ToPrimitive> Size3<T> {
+    #[inline]
+    pub fn cast<U: NumCast>(self) -> Option<Size3<U>> {
+        Some(Size3::new(
+            U::from(self.width)?,
+            U::from(self.height)?,
+            U::from(self.depth)?,
+        ))
+    }
+}
+
+impl<T> From<(T, T, T)> for Size3<T> {
+    #[inline]
+    fn from(src: (T, T, T)) -> Size3<T> {
+        Size3::new(src.0, src.1, src.2)
+    }
+}
+
+impl<T: Copy> From<[T; 3]> for Size3<T> {
+    #[inline]
+    fn from(src: [T; 3]) -> Size3<T> {
+        Size3::new(src[0], src[1], src[2])
+    }
+}
+
+impl<T> From<Vector3<T>> for Size3<T> {
+    #[inline]
+    fn from(src: Vector3<T>) -> Size3<T> {
+        Size3::new(src.x, src.y, src.z)
+    }
+}
+
+impl<T> PartialEq<(T, T, T)> for Size3<T>
+where
+    T: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &(T, T, T)) -> bool {
+        self.width == other.0 && self.height == other.1 && self.depth == other.2
+    }
+}
+
+impl<T> PartialEq<[T; 3]> for Size3<T>
+where
+    T: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &[T; 3]) -> bool {
+        self.width == other[0] && self.height == other[1] && self.depth == other[2]
+    }
+}
+
+impl<T> PartialEq<Size3<T>> for (T, T, T)
+where
+    T: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Size3<T>) -> bool {
+        self.0 == other.width && self.1 == other.height && self.2 == other.depth
+    }
+}
+
+impl<T> PartialEq<Size3<T>> for [T; 3]
+where
+    T: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Size3<T>) -> bool {
+        self[0] == other.width && self[1] == other.height && self[2] == other.depth
+    }
+}
+
+impl<T, U> core::ops::Add<U> for Size3<T>
+where
+    T: core::ops::Add<T, Output = T>,
+    U: Into<Self>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: U) -> Self {
+        let rhs = rhs.into();
+        Self::new(
+            self.width + rhs.width,
+            self.height + rhs.height,
+            self.depth + rhs.depth,
+        )
+    }
+}
+
+impl<T, U> core::ops::Sub<U> for Size3<T>
+where
+    T: core::ops::Sub<T, Output = T>,
+    U: Into<Self>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: U) -> Self {
+        let rhs = rhs.into();
+        Self::new(
+            self.width - rhs.width,
+            self.height - rhs.height,
+            self.depth - rhs.depth,
+        )
+    }
+}
+
+impl<T> core::ops::Mul<T> for Size3<T>
+where
+    T: core::ops::Mul<T, Output = T> + Copy,
+{
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: T) -> Self {
+        Self::new(self.width * rhs, self.height * rhs, self.depth * rhs)
+    }
+}
+
+impl<T> core::ops::Div<T> for Size3<T>
+where
+    T: core::ops::Div<T, Output = T> + Copy,
+{
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: T) -> Self {
+        Self::new(self.width / rhs, self.height / rhs, self.depth / rhs)
+    }
+}
+
+impl<T, U> core::ops::AddAssign<U> for Size3<T>
+where
+    T: core::ops::AddAssign,
+    U: Into<Self>,
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: U) {
+        let rhs = rhs.into();
+        self.width += rhs.width;
+        self.height += rhs.height;
+        self.depth += rhs.depth;
+    }
+}
+
+impl<T, U> core::ops::SubAssign<U> for Size3<T>
+where
+    T: core::ops::SubAssign,
+    U: Into<Self>,
+{
+    #[inline]
+    fn sub_assign(&mut self, rhs: U) {
+        let rhs = rhs.into();
+        self.width -= rhs.width;
+        self.height -= rhs.height;
+        self.depth -= rhs.depth;
+    }
+}
+
+impl<T> core::ops::MulAssign<T> for Size3<T>
+where
+    T: core::ops::MulAssign + Copy,
+{
+    #[inline]
+    fn mul_assign(&mut self, rhs: T) {
+        self.width *= rhs;
+        self.height *= rhs;
+        self.depth *= rhs;
+    }
+}
+
+impl<T> core::ops::DivAssign<T> for Size3<T>
+where
+    T: core::ops::DivAssign + Copy,
+{
+    #[inline]
+    fn div_assign(&mut self, rhs: T) {
+        self.width /= rhs;
+        self.height /= rhs;
+        self.depth /= rhs;
+    }
+}
+
+#[inline]
+pub const fn size3<T>(width: T, height: T, depth: T) -> Size3<T> {
+    Size3::new(width, height, depth)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn const_test() {
+        const A: Size3<i32> = Size3::new(1, 2, 3);
+        const B: Size3<i32> = size3(1, 2, 3);
+        assert!(A == (1, 2, 3) && B == (1, 2, 3));
+    }
+
+    #[test]
+    fn map_test() {
+        assert!(size3(1, 2, 3).map(|x| x + 1) == size3(2, 3, 4));
+    }
+
+    #[test]
+    fn cast_test() {
+        assert!(size3(1.5f32, 2.0, 3.0).cast::<u32>() == Some(size3(1, 2, 3)));
+        assert!(size3(256, 0, 0).cast::<u8>().is_none());
+    }
+
+    #[test]
+    fn extend_truncate_test() {
+        assert!(size(1, 2).extend(3) == (1, 2, 3));
+        assert!(size3(1, 2, 3).truncate() == (1, 2));
+    }
+
+    #[test]
+    fn eq_test() {
+        assert!(size3(1, 2, 3) == size3(1, 2, 3));
+        assert!(size3(1, 2, 3) == (1, 2, 3));
+        assert!(size3(1, 2, 3) == [1, 2, 3]);
+        assert!((1, 2, 3) == size3(1, 2, 3));
+        assert!([1, 2, 3] == size3(1, 2, 3));
+    }
+
+    #[test]
+    fn add_test() {
+        let a = size3(1, 2, 3);
+        let b = size3(6, 7, 8);
+        let c = a + b;
+        assert!(c == (7, 9, 11));
+        let a = size3(1, 2, 3);
+        let b = vector3(6, 7, 8);
+        let c = a + b;
+        assert!(c == (7, 9, 11));
+        let c = a + (6, 7, 8);
+        assert!(c == (7, 9, 11));
+    }
+
+    #[test]
+    fn sub_test() {
+        let a = size3(6, 7, 8);
+        let b = vector3(1, 2, 3);
+        let c = a - b;
+        assert!(c == size3(5, 5, 5));
+    }
+
+    #[test]
+    fn mul_test() {
+        let a = size3(1, 2, 3);
+        let b = a * 2;
+        assert!(b == (2, 4, 6));
+    }
+
+    #[test]
+    fn div_test() {
+        let a = size3(2, 6, 8);
+        let b = a / 2;
+        assert!(b == (1, 3, 4));
+    }
+
+    #[test]
+    fn add_assign_test() {
+        let mut a = size3(1, 2, 3);
+        let b = size3(6, 7, 8);
+        a += b;
+        assert!(a == (7, 9, 11));
+        let mut a = size3(1, 2, 3);
+        let b = vector3(6, 7, 8);
+        a += b;
+        assert!(a == (7, 9, 11));
+        let mut a = size3(1, 2, 3);
+        a += (6, 7, 8);
+        assert!(a == (7, 9, 11));
+    }
+
+    #[test]
+    fn sub_assign_test() {
+        let mut a = size3(6, 7, 8);
+        let b = vector3(1, 2, 3);
+        a -= b;
+        assert!(a == (5, 5, 5));
+    }
+
+    #[test]
+    fn mul_assign_test() {
+        let mut a = size3(1, 2, 3);
+        a *= 2;
+        assert!(a == (2, 4, 6));
+    }
+
+    #[test]
+    fn div_assign_test() {
+        let mut a = size3(3, 6, 9);
+        a /= 3;
+        assert!(a == (1, 2, 3));
+    }
+}
@@ -0,0 +1,237 @@
+//! [proptest](https://docs.rs/proptest) strategies for the geometry types.
+//!
+//! The strategies combining the strategies of components are named in the plural, e.g. `points`,
+//! so that they can be imported together with the constructors of the crate root.
+//!
+//! ```
+//! use gecl::*;
+//! use gecl::strategies::*;
+//! use proptest::prelude::*;
+//!
+//! proptest! {
+//!     fn stays_inside(rc in rect_in(rect((0.0, 0.0), (100.0, 100.0)))) {
+//!         prop_assert!(rect((0.0, 0.0), (100.0, 100.0)).contains(&rc));
+//!     }
+//! }
+//! # stays_inside();
+//! ```
+use crate::*;
+use core::fmt::Debug;
+use proptest::{
+    arbitrary::Arbitrary,
+    strategy::{BoxedStrategy, Strategy},
+};
+
+/// A component type that can be sampled uniformly from a closed range.
+///
+/// The `Arbitrary` impls draw components from `DEFAULT_MIN..=DEFAULT_MAX`, which is kept small
+/// so that sums and squares of components do not overflow.
+pub trait Uniform: Copy + Debug + PartialOrd + 'static {
+    const ZERO: Self;
+    const DEFAULT_MIN: Self;
+    const DEFAULT_MAX: Self;
+
+    fn uniform(lo: Self, hi: Self) -> BoxedStrategy<Self>;
+}
+
+macro_rules! impl_uniform {
+    ($($t:ty => $min:expr, $max:expr);*) => {
+        $(
+            impl Uniform for $t {
+                const ZERO: Self = 0 as $t;
+                const DEFAULT_MIN: Self = $min;
+                const DEFAULT_MAX: Self = $max;
+
+                #[inline]
+                fn uniform(lo: Self, hi: Self) -> BoxedStrategy<Self> {
+                    (lo..=hi).boxed()
+                }
+            }
+        )*
+    };
+}
+
+impl_uniform!(
+    i32 => -1000, 1000;
+    i64 => -1000, 1000;
+    u32 => 0, 1000;
+    u64 => 0, 1000;
+    f32 => -1000.0, 1000.0;
+    f64 => -1000.0, 1000.0
+);
+
+#[inline]
+fn default_range<T: Uniform>() -> BoxedStrategy<T> {
+    T::uniform(T::DEFAULT_MIN, T::DEFAULT_MAX)
+}
+
+#[inline]
+pub fn points<S: Strategy>(x: S, y: S) -> impl Strategy<Value = Point<S::Value>> {
+    (x, y).prop_map(|(x, y)| Point::new(x, y))
+}
+
+#[inline]
+pub fn vectors<S: Strategy>(x: S, y: S) -> impl Strategy<Value = Vector<S::Value>> {
+    (x, y).prop_map(|(x, y)| Vector::new(x, y))
+}
+
+#[inline]
+pub fn sizes<S: Strategy>(width: S, height: S) -> impl Strategy<Value = Size<S::Value>> {
+    (width, height).prop_map(|(width, height)| Size::new(width, height))
+}
+
+#[inline]
+pub fn rects<P, S, T>(origin: P, size: S) -> impl Strategy<Value = Rect<T>>
+where
+    P: Strategy<Value = Point<T>>,
+    S: Strategy<Value = Size<T>>,
+    T: Debug,
+{
+    (origin, size).prop_map(|(origin, size)| Rect::new(origin, size))
+}
+
+#[inline]
+pub fn circles<P, S, T>(center: P, radius: S) -> impl Strategy<Value = Circle<T>>
+where
+    P: Strategy<Value = Point<T>>,
+    S: Strategy<Value = T>,
+    T: Debug,
+{
+    (center, radius).prop_map(|(center, radius)| Circle::new(center, radius))
+}
+
+/// Sizes with each dimension in `0..=max`.
+#[inline]
+pub fn non_negative_size<T: Uniform>(max: T) -> impl Strategy<Value = Size<T>> {
+    sizes(T::uniform(T::ZERO, max), T::uniform(T::ZERO, max))
+}
+
+/// Points inside `bounds`, including its edges. `bounds` must have a non-negative size.
+#[inline]
+pub fn point_in<T>(bounds: Rect<T>) -> impl Strategy<Value = Point<T>>
+where
+    T: Uniform + core::ops::Add<T, Output = T>,
+{
+    let ep = bounds.endpoint();
+    points(
+        T::uniform(bounds.origin.x, ep.x),
+        T::uniform(bounds.origin.y, ep.y),
+    )
+}
+
+/// Rects with a non-negative size inside `bounds`. `bounds` must have a non-negative size.
+#[inline]
+pub fn rect_in<T>(bounds: Rect<T>) -> impl Strategy<Value = Rect<T>>
+where
+    T: Uniform + core::ops::Add<T, Output = T> + core::ops::Sub<T, Output = T>,
+{
+    (point_in(bounds), point_in(bounds)).prop_map(|(a, b)| Rect::from_points(a, b))
+}
+
+/// Circles with a center in the default range and a radius drawn from `radius`.
+#[inline]
+pub fn circle_with_radius<T, S>(radius: S) -> impl Strategy<Value = Circle<T>>
+where
+    T: Uniform,
+    S: Strategy<Value = T>,
+{
+    circles(points(default_range(), default_range()), radius)
+}
+
+impl<T: Uniform> Arbitrary for Point<T> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        points(default_range(), default_range()).boxed()
+    }
+}
+
+impl<T: Uniform> Arbitrary for Vector<T> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        vectors(default_range(), default_range()).boxed()
+    }
+}
+
+/// Sizes are never negative.
+impl<T: Uniform> Arbitrary for Size<T> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        non_negative_size(T::DEFAULT_MAX).boxed()
+    }
+}
+
+impl<T: Uniform> Arbitrary for Rect<T> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        rects(Point::arbitrary(), Size::arbitrary()).boxed()
+    }
+}
+
+impl<T: Uniform> Arbitrary for Circle<T> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: ()) -> Self::Strategy {
+        circle_with_radius(T::uniform(T::ZERO, T::DEFAULT_MAX)).boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn bounds() -> Rect<i32> {
+        rect((-50, -50), (100, 100))
+    }
+
+    proptest! {
+        #[test]
+        fn rect_in_test(rc in rect_in(bounds())) {
+            prop_assert!(rc.size.width >= 0 && rc.size.height >= 0);
+            prop_assert!(bounds().contains(&rc));
+        }
+
+        #[test]
+        fn point_in_rect_in_test(
+            (rc, pt) in rect_in(bounds()).prop_flat_map(|rc| (Just(rc), point_in(rc)))
+        ) {
+            prop_assert!(rc.contains(&pt));
+            prop_assert!(bounds().contains(&pt));
+        }
+
+        #[test]
+        fn rect_rect_symmetric_test(a in any::<Rect<i32>>(), b in any::<Rect<i32>>()) {
+            prop_assert!(a.is_crossing(&b) == b.is_crossing(&a));
+            if a.contains(&b) {
+                prop_assert!(a.is_crossing(&b));
+            }
+        }
+
+        #[test]
+        fn points_test(pt in points(0..10, 20..30), sz in sizes(0u32..5, 5..10)) {
+            prop_assert!(rect((0, 20), (9, 9)).contains(&pt));
+            prop_assert!(sz.width < 5 && sz.height >= 5);
+        }
+
+        #[test]
+        fn circle_with_radius_test(c in circle_with_radius(0.0f32..10.0)) {
+            prop_assert!(c.radius >= 0.0 && c.radius < 10.0);
+            prop_assert!(c.contains(&c.center));
+        }
+
+        #[test]
+        fn circle_point_test(c in any::<Circle<f64>>(), pt in any::<Point<f64>>()) {
+            let d = c.center - pt;
+            prop_assert!(c.is_crossing(&pt) == (d.x * d.x + d.y * d.y <= c.radius * c.radius));
+        }
+    }
+}
@@ -0,0 +1,230 @@
+use crate::*;
+
+/// The first contact of a shape moving by a velocity with another shape.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct SweepHit<T> {
+    /// The moving shape touches at `velocity * t` from its start, with `t` in `[0, 1]`.
+    pub t: T,
+    /// A point where the shapes touch.
+    pub point: Point<T>,
+    /// The unit normal of the other shape at `point`, facing the moving shape.
+    pub normal: Vector<T>,
+}
+
+impl<T: Float> SweepHit<T> {
+    #[inline]
+    fn start(point: Point<T>, normal: Vector<T>) -> Option<Self> {
+        Some(Self {
+            t: T::zero(),
+            point,
+            normal,
+        })
+    }
+}
+
+/// Returns the first contact of `circle` moving by `velocity` with `rect`, or `None` if they do
+/// not touch within the move.
+///
+/// Shapes overlapping at the start give `t == 0` with the normal of `Rect::penetration`. Shapes
+/// which only touch at the start give `t == 0` if moving into each other and `None` otherwise.
+pub fn sweep_circle_rect<T: Float>(
+    circle: &Circle<T>,
+    velocity: Vector<T>,
+    rect: &Rect<T>,
+) -> Option<SweepHit<T>> {
+    let (c, r) = (circle.center, circle.radius);
+    if let Some(v) = rect.penetration(circle) {
+        return SweepHit::start(rect.clamp_point(c), v.normalize());
+    }
+    let nearest = rect.clamp_point(c);
+    if nearest.distance_squared(c) <= r * r {
+        let normal = (c - nearest).normalize_or(Vector::zero());
+        return if velocity.dot(normal) < T::zero() {
+            SweepHit::start(nearest, normal)
+        } else {
+            None
+        };
+    }
+    // the center hits `rect` inflated by `r` with the corners rounded
+    let ray = Ray::from_origin_direction(c, velocity);
+    let (lo, hi) = rect.bounds();
+    let mut hit = ray.cast_rect(&rect.normalized().inflate((r, r)))?;
+    let p = hit.point;
+    if (p.x < lo.x || p.x > hi.x) && (p.y < lo.y || p.y > hi.y) {
+        hit = ray.cast_circle(&Circle::new(rect.clamp_point(p), r))?;
+    }
+    (hit.t <= T::one()).then(|| SweepHit {
+        t: hit.t,
+        point: hit.point - hit.normal * r,
+        normal: hit.normal,
+    })
+}
+
+/// Returns the first contact of `a` moving by `velocity` with `b`, or `None` if they do not
+/// touch within the move.
+///
+/// Circles overlapping at the start give `t == 0` with the normal of `Circle::penetration`.
+/// Circles which only touch at the start give `t == 0` if moving into each other and `None`
+/// otherwise.
+pub fn sweep_circle_circle<T: Float>(
+    a: &Circle<T>,
+    velocity: Vector<T>,
+    b: &Circle<T>,
+) -> Option<SweepHit<T>> {
+    let m = a.center - b.center;
+    let r = a.radius + b.radius;
+    let (d2, rr) = (m.abs_pow2(), r * r);
+    if d2 <= rr {
+        let normal = m.normalize_or(vector(T::one(), T::zero()));
+        let point = b.center + normal * b.radius;
+        return if d2 < rr || velocity.dot(m) < T::zero() {
+            SweepHit::start(point, normal)
+        } else {
+            None
+        };
+    }
+    let hit =
+        Ray::from_origin_direction(a.center, velocity).cast_circle(&Circle::new(b.center, r))?;
+    (hit.t <= T::one()).then(|| SweepHit {
+        t: hit.t,
+        point: b.center + hit.normal * b.radius,
+        normal: hit.normal,
+    })
+}
+
+/// Returns the first contact of `a` moving by `velocity` with `b`, or `None` if they do not
+/// touch within the move.
+///
+/// Rects overlapping at the start give `t == 0` with the normal of `Rect::penetration`. Rects
+/// which only touch at the start give `t == 0` if moving into each other and `None` otherwise.
+pub fn sweep_rect_rect<T: Float>(
+    a: &Rect<T>,
+    velocity: Vector<T>,
+    b: &Rect<T>,
+) -> Option<SweepHit<T>> {
+    let contact = |a: &Rect<T>| b.clamp_point(a.clamp_point(b.center()));
+    if let Some(v) = b.penetration(a) {
+        return SweepHit::start(contact(a), v.normalize());
+    }
+    let (a_lo, a_hi) = a.bounds();
+    let (b_lo, b_hi) = b.bounds();
+    if a.is_crossing(b) {
+        // the axes on which the rects touch, facing `a`
+        let side = |a_lo: T, a_hi: T, b_lo: T, b_hi: T| {
+            if a_hi <= b_lo {
+                -T::one()
+            } else if a_lo >= b_hi {
+                T::one()
+            } else {
+                T::zero()
+            }
+        };
+        let n = vector(
+            side(a_lo.x, a_hi.x, b_lo.x, b_hi.x),
+            side(a_lo.y, a_hi.y, b_lo.y, b_hi.y),
+        );
+        let into = |n: T, v: T| n == T::zero() || n * v < T::zero();
+        return if into(n.x, velocity.x) && into(n.y, velocity.y) {
+            SweepHit::start(contact(a), n.normalize())
+        } else {
+            None
+        };
+    }
+    // the origin of `a` hits `b` grown by the size of `a` toward the origin
+    let extent = a_hi - a_lo;
+    let expanded = Rect::new(b_lo - extent, (b_hi - b_lo) + extent);
+    let hit = Ray::from_origin_direction(a_lo, velocity).cast_rect(&expanded)?;
+    (hit.t <= T::one()).then(|| SweepHit {
+        t: hit.t,
+        point: contact(&a.normalized().translate(velocity * hit.t)),
+        normal: hit.normal,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn circle_through_thin_wall_test() {
+        let wall = rect((50.0f32, 0.0), (1.0, 10.0));
+        let bullet = circle((0.0f32, 5.0), 1.0);
+        let v = vector(100.0, 0.0);
+        assert!(!is_crossing(&bullet, &wall));
+        assert!(!is_crossing(&(bullet + v), &wall));
+        let hit = sweep_circle_rect(&bullet, v, &wall).unwrap();
+        assert!(hit.t == 0.49);
+        assert!(hit.point == point(50.0, 5.0));
+        assert!(hit.normal == vector(-1.0, 0.0));
+        assert!(sweep_circle_rect(&bullet, v * 0.4, &wall).is_none());
+        assert!(sweep_circle_rect(&bullet, -v, &wall).is_none());
+    }
+
+    #[test]
+    fn circle_rect_corner_test() {
+        let r = rect((0.0f32, 0.0), (10.0, 10.0));
+        let c = circle((-5.0f32, -5.0), 1.0);
+        let hit = sweep_circle_rect(&c, vector(10.0, 10.0), &r).unwrap();
+        let d = 1.0 / 2.0f32.sqrt();
+        assert!((hit.t - (5.0 - d) / 10.0).abs() < 1e-6);
+        assert!(hit.point.approx_eq(&point(0.0, 0.0), 1e-6));
+        assert!(hit.normal.approx_eq(&vector(-d, -d), 1e-6));
+        // passes the corner within the inflated box but outside the rounded corner
+        let c = circle((-0.9f32, -5.0), 1.0);
+        assert!(sweep_circle_rect(&c, vector(-1.0, 10.0), &r).is_none());
+    }
+
+    #[test]
+    fn circle_rect_start_test() {
+        let r = rect((0.0f32, 0.0), (10.0, 10.0));
+        let c = circle((5.0f32, -0.5), 1.0);
+        let hit = sweep_circle_rect(&c, vector(0.0, -5.0), &r).unwrap();
+        assert!(hit.t == 0.0 && hit.normal == vector(0.0, -1.0));
+        let c = circle((5.0f32, -1.0), 1.0);
+        assert!(sweep_circle_rect(&c, vector(0.0, -5.0), &r).is_none());
+        assert!(sweep_circle_rect(&c, vector(5.0, 0.0), &r).is_none());
+        let hit = sweep_circle_rect(&c, vector(0.0, 5.0), &r).unwrap();
+        assert!(hit.t == 0.0 && hit.point == point(5.0, 0.0));
+    }
+
+    #[test]
+    fn circle_circle_test() {
+        let a = circle((0.0f32, 0.0), 1.0);
+        let b = circle((10.0f32, 0.0), 2.0);
+        let hit = sweep_circle_circle(&a, vector(20.0, 0.0), &b).unwrap();
+        assert!(hit.t == 0.35);
+        assert!(hit.point == point(8.0, 0.0));
+        assert!(hit.normal == vector(-1.0, 0.0));
+        assert!(sweep_circle_circle(&a, vector(6.0, 0.0), &b).is_none());
+        assert!(sweep_circle_circle(&a, vector(20.0, 20.0), &b).is_none());
+        let touching = circle((7.0f32, 0.0), 1.0);
+        assert!(sweep_circle_circle(&touching, vector(-1.0, 0.0), &b).is_none());
+        assert!(sweep_circle_circle(&touching, vector(0.0, 1.0), &b).is_none());
+        let hit = sweep_circle_circle(&touching, vector(1.0, 0.0), &b).unwrap();
+        assert!(hit.t == 0.0 && hit.normal == vector(-1.0, 0.0));
+        let hit = sweep_circle_circle(&circle((10.0, 0.0), 1.0), vector(0.0, 0.0), &b).unwrap();
+        assert!(hit.t == 0.0 && hit.normal == vector(1.0, 0.0));
+    }
+
+    #[test]
+    fn rect_rect_test() {
+        let a = rect((0.0f32, 0.0), (2.0, 2.0));
+        let wall = rect((10.0f32, -5.0), (1.0, 10.0));
+        let hit = sweep_rect_rect(&a, vector(16.0, 0.0), &wall).unwrap();
+        assert!(hit.t == 0.5);
+        assert!(hit.normal == vector(-1.0, 0.0));
+        assert!(hit.point.x == 10.0 && (0.0..=2.0).contains(&hit.point.y));
+        assert!(sweep_rect_rect(&a, vector(7.0, 0.0), &wall).is_none());
+        assert!(sweep_rect_rect(&a, vector(16.0, 16.0), &wall).is_none());
+        let hit = sweep_rect_rect(&a, vector(16.0, -4.0), &wall).unwrap();
+        assert!(hit.t == 0.5 && hit.normal == vector(-1.0, 0.0));
+        let floor = rect((-10.0f32, 2.0), (20.0, 1.0));
+        assert!(sweep_rect_rect(&a, vector(5.0, 0.0), &floor).is_none());
+        assert!(sweep_rect_rect(&a, vector(0.0, -1.0), &floor).is_none());
+        let hit = sweep_rect_rect(&a, vector(1.0, 1.0), &floor).unwrap();
+        assert!(hit.t == 0.0 && hit.normal == vector(0.0, -1.0));
+        let sunk = rect((0.0f32, 1.0), (2.0, 2.0));
+        let hit = sweep_rect_rect(&sunk, vector(0.0, 0.0), &floor).unwrap();
+        assert!(hit.t == 0.0 && hit.normal == vector(0.0, -1.0));
+    }
+}
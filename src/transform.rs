@@ -0,0 +1,327 @@
+use crate::*;
+
+/// A 2D affine transform stored as a 3x2 matrix.
+///
+/// Points are treated as row vectors, so `(x, y)` maps to
+/// `(x * m11 + y * m21 + m31, x * m12 + y * m22 + m32)`.
+/// `a * b` applies `a` first and then `b`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Transform<T> {
+    pub m11: T,
+    pub m12: T,
+    pub m21: T,
+    pub m22: T,
+    pub m31: T,
+    pub m32: T,
+}
+
+impl<T> Transform<T> {
+    #[inline]
+    pub const fn new(m11: T, m12: T, m21: T, m22: T, m31: T, m32: T) -> Self {
+        Self {
+            m11,
+            m12,
+            m21,
+            m22,
+            m31,
+            m32,
+        }
+    }
+}
+
+impl<T: Float> Transform<T> {
+    #[inline]
+    pub fn identity() -> Self {
+        let (o, z) = (T::one(), T::zero());
+        Self::new(o, z, z, o, z, z)
+    }
+
+    #[inline]
+    pub fn translation(v: impl Into<Vector<T>>) -> Self {
+        let v = v.into();
+        let (o, z) = (T::one(), T::zero());
+        Self::new(o, z, z, o, v.x, v.y)
+    }
+
+    /// Rotates from the x axis toward the y axis by `radians`.
+    #[inline]
+    pub fn rotation(radians: T) -> Self {
+        let (s, c) = radians.sin_cos();
+        let z = T::zero();
+        Self::new(c, s, -s, c, z, z)
+    }
+
+    #[inline]
+    pub fn scale(sx: T, sy: T) -> Self {
+        let z = T::zero();
+        Self::new(sx, z, z, sy, z, z)
+    }
+
+    /// Scales, then rotates and then translates.
+    #[inline]
+    pub fn from_trs(translation: impl Into<Vector<T>>, radians: T, scale: (T, T)) -> Self {
+        Self::scale(scale.0, scale.1) * Self::rotation(radians) * Self::translation(translation)
+    }
+
+    #[inline]
+    pub fn transform_point(&self, pt: impl Into<Point<T>>) -> Point<T> {
+        let pt = pt.into();
+        Point::new(
+            pt.x * self.m11 + pt.y * self.m21 + self.m31,
+            pt.x * self.m12 + pt.y * self.m22 + self.m32,
+        )
+    }
+
+    /// Transforms `v` without the translation.
+    #[inline]
+    pub fn transform_vector(&self, v: impl Into<Vector<T>>) -> Vector<T> {
+        let v = v.into();
+        Vector::new(
+            v.x * self.m11 + v.y * self.m21,
+            v.x * self.m12 + v.y * self.m22,
+        )
+    }
+
+    #[inline]
+    pub fn determinant(&self) -> T {
+        self.m11 * self.m22 - self.m12 * self.m21
+    }
+
+    /// Returns `None` when the determinant is zero relative to the magnitude of its terms.
+    pub fn inverse(&self) -> Option<Self> {
+        let det = self.determinant();
+        let tolerance = T::epsilon() * ((self.m11 * self.m22).abs() + (self.m12 * self.m21).abs());
+        if !det.is_finite() || det.abs() <= tolerance {
+            return None;
+        }
+        let m11 = self.m22 / det;
+        let m12 = -self.m12 / det;
+        let m21 = -self.m21 / det;
+        let m22 = self.m11 / det;
+        Some(Self::new(
+            m11,
+            m12,
+            m21,
+            m22,
+            -(self.m31 * m11 + self.m32 * m21),
+            -(self.m31 * m12 + self.m32 * m22),
+        ))
+    }
+
+    /// Maps `pt` back through the inverse transform, e.g. from screen to world coordinates.
+    #[inline]
+    pub fn try_transform_point_inverse(&self, pt: impl Into<Point<T>>) -> Option<Point<T>> {
+        Some(self.inverse()?.transform_point(pt))
+    }
+
+    /// Returns the axis-aligned bounding rect of the four transformed corners.
+    pub fn transform_rect(&self, rc: &Rect<T>) -> Rect<T> {
+        let ep = rc.endpoint();
+        let corners = [
+            self.transform_point(rc.origin),
+            self.transform_point((ep.x, rc.origin.y)),
+            self.transform_point((rc.origin.x, ep.y)),
+            self.transform_point(ep),
+        ];
+        let (min, max) = corners[1..]
+            .iter()
+            .fold((corners[0], corners[0]), |(min, max), p| {
+                (
+                    point(min.x.min(p.x), min.y.min(p.y)),
+                    point(max.x.max(p.x), max.y.max(p.y)),
+                )
+            });
+        Rect::new(min, max - min)
+    }
+}
+
+impl<T: Float> Rect<T> {
+    /// Returns the transform mapping `self` onto `target`, e.g. from world to screen space.
+    ///
+    /// If `flip_y` is `true`, the top edge of `self` maps to the bottom edge of `target`.
+    /// Map back with `Transform::inverse`.
+    pub fn map_to(&self, target: &Rect<T>, flip_y: bool) -> Transform<T> {
+        let sx = target.size.width / self.size.width;
+        let sy = target.size.height / self.size.height;
+        let z = T::zero();
+        let m31 = target.origin.x - self.origin.x * sx;
+        if flip_y {
+            let m32 = target.origin.y + self.endpoint().y * sy;
+            Transform::new(sx, z, z, -sy, m31, m32)
+        } else {
+            let m32 = target.origin.y - self.origin.y * sy;
+            Transform::new(sx, z, z, sy, m31, m32)
+        }
+    }
+}
+
+impl<T: Float> core::ops::Mul<Transform<T>> for Transform<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Transform<T>) -> Self {
+        Self::new(
+            self.m11 * rhs.m11 + self.m12 * rhs.m21,
+            self.m11 * rhs.m12 + self.m12 * rhs.m22,
+            self.m21 * rhs.m11 + self.m22 * rhs.m21,
+            self.m21 * rhs.m12 + self.m22 * rhs.m22,
+            self.m31 * rhs.m11 + self.m32 * rhs.m21 + rhs.m31,
+            self.m31 * rhs.m12 + self.m32 * rhs.m22 + rhs.m32,
+        )
+    }
+}
+
+impl<T: Float> core::ops::MulAssign<Transform<T>> for Transform<T> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Transform<T>) {
+        *self = *self * rhs;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f32::consts::FRAC_PI_2;
+
+    fn near(a: Point<f32>, b: Point<f32>) -> bool {
+        (a.x - b.x).abs() < 1e-5 && (a.y - b.y).abs() < 1e-5
+    }
+
+    #[test]
+    fn identity_test() {
+        let t = Transform::<f32>::identity();
+        assert!(t.transform_point((1.5, -2.0)) == (1.5, -2.0));
+        assert!(t.transform_vector((1.5, -2.0)) == (1.5, -2.0));
+        let rc = rect((1.0, 2.0), (3.0, 4.0));
+        assert!(t.transform_rect(&rc) == rc);
+        let s = Transform::scale(2.0f32, 3.0);
+        assert!(s * t == s && t * s == s);
+    }
+
+    #[test]
+    fn translation_test() {
+        let t = Transform::translation((10.0f32, 20.0));
+        assert!(t.transform_point((1.0, 2.0)) == (11.0, 22.0));
+        assert!(t.transform_vector((1.0, 2.0)) == (1.0, 2.0));
+    }
+
+    #[test]
+    fn rotation_test() {
+        let t = Transform::rotation(FRAC_PI_2);
+        assert!(near(t.transform_point((1.0, 0.0)), point(0.0, 1.0)));
+        assert!(near(t.transform_point((0.0, 1.0)), point(-1.0, 0.0)));
+    }
+
+    #[test]
+    fn compose_test() {
+        let s = Transform::scale(2.0f32, 2.0);
+        let t = Transform::translation((10.0, 0.0));
+        // scale first, then translate
+        assert!((s * t).transform_point((1.0, 1.0)) == (12.0, 2.0));
+        // translate first, then scale
+        assert!((t * s).transform_point((1.0, 1.0)) == (22.0, 2.0));
+        let mut m = s;
+        m *= t;
+        assert!(m == s * t);
+        let p = point(3.0f32, -4.0);
+        let r = Transform::rotation(0.3);
+        assert!(near(
+            (s * r * t).transform_point(p),
+            t.transform_point(r.transform_point(s.transform_point(p)))
+        ));
+    }
+
+    #[test]
+    fn from_trs_test() {
+        let m = Transform::from_trs((10.0f32, 20.0), FRAC_PI_2, (2.0, 3.0));
+        assert!(near(m.transform_point((1.0, 1.0)), point(7.0, 22.0)));
+        assert!(near(m.transform_vector((1.0, 0.0)).into(), point(0.0, 2.0)));
+    }
+
+    #[test]
+    fn determinant_test() {
+        assert!(Transform::<f32>::identity().determinant() == 1.0);
+        assert!(Transform::scale(2.0f32, 3.0).determinant() == 6.0);
+        assert!((Transform::rotation(0.7f32).determinant() - 1.0).abs() < 1e-6);
+        assert!(Transform::translation((5.0f32, 6.0)).determinant() == 1.0);
+    }
+
+    #[test]
+    fn inverse_test() {
+        for i in 0..50 {
+            let f = i as f64;
+            let m = Transform::from_trs(
+                (f * 3.7 - 90.0, 40.0 - f * 1.3),
+                f * 0.37,
+                (0.1 + (f * 0.71) % 5.0, -2.0 + (f * 0.53) % 4.5),
+            );
+            let inv = m.inverse().unwrap();
+            let p = point(f * 2.1 - 30.0, 17.0 - f);
+            let q = inv.transform_point(m.transform_point(p));
+            assert!((q.x - p.x).abs() < 1e-9 && (q.y - p.y).abs() < 1e-9);
+            let q = m.try_transform_point_inverse(m.transform_point(p)).unwrap();
+            assert!((q.x - p.x).abs() < 1e-9 && (q.y - p.y).abs() < 1e-9);
+        }
+        let m = Transform::scale(1e-4f32, 1e-4);
+        assert!(m.inverse().is_some());
+    }
+
+    #[test]
+    fn singular_test() {
+        assert!(Transform::scale(0.0f32, 1.0).inverse().is_none());
+        assert!(Transform::scale(0.0f32, 0.0).inverse().is_none());
+        assert!((Transform::scale(2.0f32, 0.0) * Transform::rotation(0.5))
+            .try_transform_point_inverse((1.0, 1.0))
+            .is_none());
+        assert!(Transform::new(1.0f32, 2.0, 2.0, 4.0, 3.0, 3.0)
+            .inverse()
+            .is_none());
+        assert!(Transform::new(f32::NAN, 0.0, 0.0, 1.0, 0.0, 0.0)
+            .inverse()
+            .is_none());
+    }
+
+    #[test]
+    fn map_to_test() {
+        let world = rect((-10.0f32, -5.0), (20.0, 10.0));
+        let screen = rect((0.0f32, 0.0), (800.0, 400.0));
+        let m = world.map_to(&screen, false);
+        assert!(m.transform_point(world.origin) == screen.origin);
+        assert!(m.transform_point(world.endpoint()) == screen.endpoint());
+        assert!(m.transform_point((0.0, 0.0)) == (400.0, 200.0));
+        assert!(m.transform_rect(&world) == screen);
+        let inv = m.inverse().unwrap();
+        assert!(inv.transform_point((400.0, 200.0)) == (0.0, 0.0));
+        assert!(inv.transform_point(screen.endpoint()) == world.endpoint());
+        assert!(screen.map_to(&world, false) == inv);
+    }
+
+    #[test]
+    fn map_to_flip_y_test() {
+        let world = rect((-10.0f32, -5.0), (20.0, 10.0));
+        let screen = rect((100.0f32, 50.0), (800.0, 400.0));
+        let m = world.map_to(&screen, true);
+        assert!(m.transform_point((-10.0, -5.0)) == (100.0, 450.0));
+        assert!(m.transform_point((10.0, 5.0)) == (900.0, 50.0));
+        assert!(m.transform_point((-10.0, 5.0)) == (100.0, 50.0));
+        assert!(m.transform_rect(&world) == screen);
+        let p = m.try_transform_point_inverse((300.0, 150.0)).unwrap();
+        assert!(p == (-5.0, 2.5));
+        assert!(m.transform_point(p) == (300.0, 150.0));
+    }
+
+    #[test]
+    fn transform_rect_test() {
+        let t = Transform::rotation(FRAC_PI_2);
+        let rc = t.transform_rect(&rect((1.0f32, 2.0), (3.0, 4.0)));
+        assert!(near(rc.origin, point(-6.0, 1.0)));
+        assert!(near(rc.endpoint(), point(-2.0, 4.0)));
+        let t = Transform::rotation(core::f32::consts::FRAC_PI_4);
+        let rc = t.transform_rect(&rect((0.0f32, 0.0), (1.0, 1.0)));
+        let h = core::f32::consts::SQRT_2 / 2.0;
+        assert!(near(rc.origin, point(-h, 0.0)));
+        assert!(near(rc.endpoint(), point(h, 2.0 * h)));
+    }
+}
@@ -0,0 +1,171 @@
+use crate::*;
+use core::cmp::Ordering;
+
+/// A triangle with the vertices `a`, `b` and `c` in either winding order.
+///
+/// A triangle whose vertices are collinear behaves like the segment covering them.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Debug)]
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Triangle<T> {
+    pub a: Point<T>,
+    pub b: Point<T>,
+    pub c: Point<T>,
+}
+
+impl<T> Triangle<T> {
+    #[inline]
+    pub fn new(a: impl Into<Point<T>>, b: impl Into<Point<T>>, c: impl Into<Point<T>>) -> Self {
+        Self {
+            a: a.into(),
+            b: b.into(),
+            c: c.into(),
+        }
+    }
+
+    /// A `const` variant of `Triangle::new`.
+    #[inline]
+    pub const fn from_points(a: Point<T>, b: Point<T>, c: Point<T>) -> Self {
+        Self { a, b, c }
+    }
+}
+
+impl<T: Copy> Triangle<T> {
+    #[inline]
+    pub fn vertices(&self) -> [Point<T>; 3] {
+        [self.a, self.b, self.c]
+    }
+
+    /// Returns the edges `a`-`b`, `b`-`c` and `c`-`a`.
+    #[inline]
+    pub fn edges(&self) -> [Segment<T>; 3] {
+        [
+            Segment::from_start_end(self.a, self.b),
+            Segment::from_start_end(self.b, self.c),
+            Segment::from_start_end(self.c, self.a),
+        ]
+    }
+}
+
+impl<T> Triangle<T>
+where
+    T: core::ops::Add<T, Output = T> + Copy,
+{
+    #[inline]
+    pub fn translate(&self, d: impl Into<Vector<T>>) -> Self {
+        let d = d.into();
+        Self::from_points(self.a + d, self.b + d, self.c + d)
+    }
+}
+
+impl<T: Scalar> Triangle<T> {
+    /// Returns the smallest rect containing the triangle.
+    #[inline]
+    pub fn bounding_rect(&self) -> Rect<T> {
+        let lo = self.a.min(self.b).min(self.c);
+        let hi = self.a.max(self.b).max(self.c);
+        Rect::new(lo, hi - lo)
+    }
+}
+
+impl<T: Scalar + Zero> Triangle<T> {
+    /// Returns `true` if the vertices are collinear, so the area is zero.
+    #[inline]
+    pub fn is_degenerate(&self) -> bool {
+        Segment::from_start_end(self.a, self.b).orientation(self.c) == Ordering::Equal
+    }
+
+    /// Returns `true` if `p` is inside or on the edges of the triangle.
+    ///
+    /// `p` is inside if it is not on opposite sides of two edges, which holds for either winding
+    /// order.
+    pub fn contains_point(&self, p: impl Into<Point<T>>) -> bool {
+        let p = p.into();
+        let edges = self.edges();
+        if self.is_degenerate() {
+            return edges.iter().any(|e| e.contains_point(p));
+        }
+        let sides = edges.map(|e| e.orientation(p));
+        !(sides.contains(&Ordering::Less) && sides.contains(&Ordering::Greater))
+    }
+}
+
+impl<T: Float> Triangle<T> {
+    /// Returns the signed area, which is positive for vertices counterclockwise in y-up
+    /// coordinates.
+    #[inline]
+    pub fn signed_area(&self) -> T {
+        (self.b - self.a).cross(self.c - self.a) / (T::one() + T::one())
+    }
+
+    #[inline]
+    pub fn area(&self) -> T {
+        self.signed_area().abs()
+    }
+
+    /// Returns the average of the vertices, which is also the center of mass.
+    #[inline]
+    pub fn centroid(&self) -> Point<T> {
+        let s = self.a + self.b + self.c;
+        let three = T::from(3).unwrap();
+        Point::new(s.x / three, s.y / three)
+    }
+}
+
+#[inline]
+pub fn triangle<T>(
+    a: impl Into<Point<T>>,
+    b: impl Into<Point<T>>,
+    c: impl Into<Point<T>>,
+) -> Triangle<T> {
+    Triangle::new(a, b, c)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn area_test() {
+        let t = triangle((0.0f32, 0.0), (4.0, 0.0), (0.0, 3.0));
+        assert!(t.signed_area() == 6.0);
+        assert!(t.area() == 6.0);
+        let r = triangle(t.a, t.c, t.b);
+        assert!(r.signed_area() == -6.0);
+        assert!(r.area() == 6.0);
+        assert!(t.centroid().approx_eq(&point(4.0 / 3.0, 1.0), 1e-6));
+        assert!(t.bounding_rect() == rect((0.0, 0.0), (4.0, 3.0)));
+    }
+
+    #[test]
+    fn contains_point_test() {
+        let ccw = triangle((0u32, 0), (10, 0), (0, 10));
+        let cw = triangle((0u32, 0), (0, 10), (10, 0));
+        let cases = [
+            ((2, 2), true),
+            ((5, 5), true),
+            ((0, 7), true),
+            ((10, 0), true),
+            ((6, 5), false),
+            ((11, 0), false),
+        ];
+        for (p, expected) in cases {
+            assert!(ccw.contains_point(p) == expected);
+            assert!(cw.contains_point(p) == expected);
+        }
+    }
+
+    #[test]
+    fn degenerate_test() {
+        let t = triangle((0, 0), (10, 10), (5, 5));
+        assert!(t.is_degenerate());
+        assert!(t.contains_point((7, 7)));
+        assert!(t.contains_point((10, 10)));
+        assert!(!t.contains_point((11, 11)));
+        assert!(!t.contains_point((7, 6)));
+        let p = triangle((3, 3), (3, 3), (3, 3));
+        assert!(p.contains_point((3, 3)));
+        assert!(!p.contains_point((3, 4)));
+        assert!(!triangle((0, 0), (10, 0), (0, 10)).is_degenerate());
+    }
+}
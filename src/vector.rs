@@ -1,6 +1,6 @@
 use crate::*;
 
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Debug)]
 #[repr(C)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Vector<T> {
@@ -10,7 +10,7 @@ pub struct Vector<T> {
 
 impl<T> Vector<T> {
     #[inline]
-    pub fn new(x: T, y: T) -> Self {
+    pub const fn new(x: T, y: T) -> Self {
         Self { x, y }
     }
 
@@ -18,6 +18,110 @@ impl<T> Vector<T> {
     pub fn map<R>(self, mut f: impl FnMut(T) -> R) -> Vector<R> {
         Vector::new(f(self.x), f(self.y))
     }
+
+    #[inline]
+    pub fn yx(self) -> Self {
+        Self::new(self.y, self.x)
+    }
+
+    #[inline]
+    pub fn with_x(self, x: T) -> Self {
+        Self { x, ..self }
+    }
+
+    #[inline]
+    pub fn with_y(self, y: T) -> Self {
+        Self { y, ..self }
+    }
+
+    /// Iterates references to the components in x, y order.
+    #[inline]
+    pub fn iter(&self) -> core::array::IntoIter<&T, 2> {
+        [&self.x, &self.y].into_iter()
+    }
+
+    #[inline]
+    pub fn as_array(&self) -> &[T; 2] {
+        // SAFETY: `Vector<T>` is `repr(C)` with two fields of `T`, which has the same layout as `[T; 2]`.
+        unsafe { &*(self as *const Self as *const [T; 2]) }
+    }
+
+    #[inline]
+    pub fn as_array_mut(&mut self) -> &mut [T; 2] {
+        // SAFETY: `Vector<T>` is `repr(C)` with two fields of `T`, which has the same layout as `[T; 2]`.
+        unsafe { &mut *(self as *mut Self as *mut [T; 2]) }
+    }
+}
+
+impl<T: Scalar + Zero + One> Vector<T> {
+    /// Interpolates each component linearly. `t` outside of `0..=1` extrapolates.
+    ///
+    /// # Panics
+    ///
+    /// For unsigned `T`, panics on overflow in debug builds if an extrapolated component would be
+    /// negative.
+    #[inline]
+    pub fn lerp(self, other: impl Into<Self>, t: T) -> Self {
+        let other = other.into();
+        Self::new(lerp(self.x, other.x, t), lerp(self.y, other.y, t))
+    }
+
+    /// Same as `lerp` but `t` is clamped to `0..=1`.
+    #[inline]
+    pub fn lerp_clamped(self, other: impl Into<Self>, t: T) -> Self {
+        self.lerp(other, clamp_unit(t))
+    }
+}
+
+impl<T: PartialOrd + Copy> Vector<T> {
+    /// Returns the componentwise minimum.
+    #[inline]
+    pub fn min(self, rhs: impl Into<Self>) -> Self {
+        let rhs = rhs.into();
+        Self::new(partial_min(self.x, rhs.x), partial_min(self.y, rhs.y))
+    }
+
+    /// Returns the componentwise maximum.
+    #[inline]
+    pub fn max(self, rhs: impl Into<Self>) -> Self {
+        let rhs = rhs.into();
+        Self::new(partial_max(self.x, rhs.x), partial_max(self.y, rhs.y))
+    }
+
+    /// Clamps each component to `lo..=hi`.
+    ///
+    /// When a component of `lo` is greater than the one of `hi`, that component becomes `hi`.
+    #[inline]
+    pub fn clamp(self, lo: impl Into<Self>, hi: impl Into<Self>) -> Self {
+        self.max(lo).min(hi)
+    }
+
+    #[inline]
+    pub fn min_element(self) -> T {
+        partial_min(self.x, self.y)
+    }
+
+    #[inline]
+    pub fn max_element(self) -> T {
+        partial_max(self.x, self.y)
+    }
+}
+
+impl<T: TotalCmpKey> Vector<T> {
+    /// Returns a key ordered lexicographically like the derived `Ord`, using `total_cmp` for
+    /// each component. It makes float components usable as `BTreeMap` or `HashMap` keys.
+    #[inline]
+    pub fn total_cmp_key(self) -> (T::Key, T::Key) {
+        (self.x.total_cmp_key(), self.y.total_cmp_key())
+    }
+}
+
+impl<T: Widen> Vector<T> {
+    /// Converts each component to `T::Wide`, e.g. to compute products without overflow.
+    #[inline]
+    pub fn widen(self) -> Vector<T::Wide> {
+        self.map(T::widen)
+    }
 }
 
 impl<T: ToPrimitive> Vector<T> {
@@ -25,11 +129,46 @@ impl<T: ToPrimitive> Vector<T> {
     pub fn cast<U: NumCast>(self) -> Option<Vector<U>> {
         Some(Vector::new(U::from(self.x)?, U::from(self.y)?))
     }
+
+    #[inline]
+    pub fn try_cast<U: NumCast>(self) -> Result<Vector<U>, CastError>
+    where
+        T: Copy,
+    {
+        Ok(Vector::new(
+            cast_component(self.x, "Vector", "x")?,
+            cast_component(self.y, "Vector", "y")?,
+        ))
+    }
+
+    /// Converts each component to `f32`. See [the conversions of `Point`](Point#conversions).
+    #[inline]
+    pub fn as_f32(self) -> Vector<f32> {
+        self.map(|v| v.to_f32().unwrap_or(f32::NAN))
+    }
+
+    /// Converts each component to `f64`. See [the conversions of `Point`](Point#conversions).
+    #[inline]
+    pub fn as_f64(self) -> Vector<f64> {
+        self.map(|v| v.to_f64().unwrap_or(f64::NAN))
+    }
+
+    /// Converts each component to `i32`. See [the conversions of `Point`](Point#conversions).
+    #[inline]
+    pub fn as_i32(self) -> Vector<i32> {
+        self.map(|v| v.to_f64().map_or(0, |v| v as i32))
+    }
+
+    /// Converts each component to `u32`. See [the conversions of `Point`](Point#conversions).
+    #[inline]
+    pub fn as_u32(self) -> Vector<u32> {
+        self.map(|v| v.to_f64().map_or(0, |v| v as u32))
+    }
 }
 
 impl<T> Vector<T>
 where
-    T: std::ops::Add<T, Output = T> + std::ops::Mul<T, Output = T>,
+    T: core::ops::Add<T, Output = T> + core::ops::Mul<T, Output = T>,
 {
     #[inline]
     pub fn dot(self, rhs: impl Into<Self>) -> T {
@@ -40,7 +179,7 @@ where
 
 impl<T> Vector<T>
 where
-    T: std::ops::Sub<T, Output = T> + std::ops::Mul<T, Output = T>,
+    T: core::ops::Sub<T, Output = T> + core::ops::Mul<T, Output = T>,
 {
     #[inline]
     pub fn cross(self, rhs: impl Into<Self>) -> T {
@@ -51,7 +190,7 @@ where
 
 impl<T> Vector<T>
 where
-    T: std::ops::Add<T, Output = T> + std::ops::Mul<T, Output = T> + Copy,
+    T: core::ops::Add<T, Output = T> + core::ops::Mul<T, Output = T> + Copy,
 {
     #[inline]
     pub fn abs_pow2(self) -> T {
@@ -59,11 +198,154 @@ where
     }
 }
 
+impl<T: Scalar> Vector<T> {
+    /// Reflects `self` off a surface with the unit `normal`.
+    #[inline]
+    pub fn reflect(self, normal: impl Into<Self>) -> Self {
+        let normal = normal.into();
+        let d = self.dot(normal);
+        self - normal * (d + d)
+    }
+}
+
+impl<T: Scalar + Zero> Vector<T> {
+    /// Returns `|x| + |y|`.
+    #[inline]
+    pub fn manhattan_length(self) -> T {
+        abs_diff(self.x, T::zero()) + abs_diff(self.y, T::zero())
+    }
+}
+
+impl<T: core::ops::Neg<Output = T>> Vector<T> {
+    /// Same as `-self`.
+    #[inline]
+    pub fn reversed(self) -> Self {
+        -self
+    }
+
+    /// Returns `(-y, x)`, rotated 90 degrees counterclockwise with the y axis up, which is
+    /// clockwise on screen with the y axis down.
+    #[inline]
+    pub fn perp(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    /// Returns `(y, -x)`, rotated 90 degrees clockwise with the y axis up, which is
+    /// counterclockwise on screen with the y axis down.
+    #[inline]
+    pub fn perp_cw(self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+}
+
 impl<T: Float> Vector<T> {
     #[inline]
     pub fn abs(self) -> T {
         T::sqrt(self.x.powi(2) + self.y.powi(2))
     }
+
+    /// Returns `true` if every component differs from the one of `other` by at most `epsilon`.
+    #[inline]
+    pub fn approx_eq(&self, other: &Self, epsilon: T) -> bool {
+        (self.x - other.x).abs() <= epsilon && (self.y - other.y).abs() <= epsilon
+    }
+
+    /// Returns the unit vector in the same direction. The result is NaN for the zero vector.
+    #[inline]
+    pub fn normalize(self) -> Self {
+        self / self.abs()
+    }
+
+    /// Returns `None` if the length is not greater than `T::epsilon()` or is not finite.
+    #[inline]
+    pub fn try_normalize(self) -> Option<Self> {
+        let len = self.abs();
+        (len > T::epsilon() && len.is_finite()).then(|| self / len)
+    }
+
+    #[inline]
+    pub fn normalize_or(self, fallback: Self) -> Self {
+        self.try_normalize().unwrap_or(fallback)
+    }
+
+    /// Returns the component of `self` parallel to `other`, or `None` if `other` is zero.
+    #[inline]
+    pub fn project_onto(self, other: impl Into<Self>) -> Option<Self> {
+        let other = other.into();
+        let d = other.abs_pow2();
+        (!d.is_zero()).then(|| other * (self.dot(other) / d))
+    }
+
+    /// Returns the component of `self` perpendicular to `other`, or `None` if `other` is zero.
+    #[inline]
+    pub fn reject_from(self, other: impl Into<Self>) -> Option<Self> {
+        self.project_onto(other).map(|p| self - p)
+    }
+
+    /// Returns the unit vector at `radians` counterclockwise from the x axis.
+    #[inline]
+    pub fn from_angle(radians: T) -> Self {
+        let (sin, cos) = radians.sin_cos();
+        Self::new(cos, sin)
+    }
+
+    /// Returns `atan2(y, x)` in `-PI..=PI`.
+    #[inline]
+    pub fn angle(self) -> T {
+        self.y.atan2(self.x)
+    }
+
+    /// Returns the signed angle in `-PI..=PI` to rotate `self` onto `other`, positive for
+    /// counterclockwise with the y axis up.
+    ///
+    /// Returns NaN if either vector is zero.
+    #[inline]
+    pub fn angle_between(self, other: impl Into<Self>) -> T {
+        let other = other.into();
+        if self.abs_pow2().is_zero() || other.abs_pow2().is_zero() {
+            return T::nan();
+        }
+        self.cross(other).atan2(self.dot(other))
+    }
+
+    /// Returns the unsigned angle in `0..=PI` between `self` and `other`.
+    ///
+    /// Returns NaN if either vector is zero.
+    #[inline]
+    pub fn angle_to(self, other: impl Into<Self>) -> T {
+        self.angle_between(other).abs()
+    }
+
+    #[inline]
+    pub fn rotate(self, radians: T) -> Self {
+        let (sin, cos) = radians.sin_cos();
+        Self::new(self.x * cos - self.y * sin, self.x * sin + self.y * cos)
+    }
+
+    #[inline]
+    pub fn floor(self) -> Self {
+        self.map(T::floor)
+    }
+
+    #[inline]
+    pub fn ceil(self) -> Self {
+        self.map(T::ceil)
+    }
+
+    #[inline]
+    pub fn round(self) -> Self {
+        self.map(T::round)
+    }
+}
+
+/// Parses `"x,y"`, allowing whitespace around each component.
+impl<T: core::str::FromStr> core::str::FromStr for Vector<T> {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x, y] = parse_components(s, &[','], "Vector", ["x", "y"])?;
+        Ok(Self::new(x, y))
+    }
 }
 
 impl<T> PartialEq<(T, T)> for Vector<T>
@@ -106,6 +388,69 @@ where
     }
 }
 
+impl<T: num::traits::CheckedAdd> Vector<T> {
+    /// Returns `None` if either component overflows.
+    #[inline]
+    pub fn checked_add(self, rhs: impl Into<Self>) -> Option<Self> {
+        let rhs = rhs.into();
+        Some(Vector::new(
+            self.x.checked_add(&rhs.x)?,
+            self.y.checked_add(&rhs.y)?,
+        ))
+    }
+}
+
+impl<T: num::traits::CheckedSub> Vector<T> {
+    /// Returns `None` if either component overflows.
+    #[inline]
+    pub fn checked_sub(self, rhs: impl Into<Self>) -> Option<Self> {
+        let rhs = rhs.into();
+        Some(Vector::new(
+            self.x.checked_sub(&rhs.x)?,
+            self.y.checked_sub(&rhs.y)?,
+        ))
+    }
+}
+
+impl<T: num::traits::SaturatingAdd> Vector<T> {
+    #[inline]
+    pub fn saturating_add(self, rhs: impl Into<Self>) -> Self {
+        let rhs = rhs.into();
+        Vector::new(self.x.saturating_add(&rhs.x), self.y.saturating_add(&rhs.y))
+    }
+}
+
+impl<T: num::traits::SaturatingSub> Vector<T> {
+    #[inline]
+    pub fn saturating_sub(self, rhs: impl Into<Self>) -> Self {
+        let rhs = rhs.into();
+        Vector::new(self.x.saturating_sub(&rhs.x), self.y.saturating_sub(&rhs.y))
+    }
+}
+
+impl<T: num::traits::WrappingAdd> Vector<T> {
+    #[inline]
+    pub fn wrapping_add(self, rhs: impl Into<Self>) -> Self {
+        let rhs = rhs.into();
+        Vector::new(self.x.wrapping_add(&rhs.x), self.y.wrapping_add(&rhs.y))
+    }
+}
+
+impl<T: num::traits::WrappingSub> Vector<T> {
+    #[inline]
+    pub fn wrapping_sub(self, rhs: impl Into<Self>) -> Self {
+        let rhs = rhs.into();
+        Vector::new(self.x.wrapping_sub(&rhs.x), self.y.wrapping_sub(&rhs.y))
+    }
+}
+
+impl<T: num::traits::WrappingMul> Vector<T> {
+    #[inline]
+    pub fn wrapping_mul(self, rhs: T) -> Self {
+        Vector::new(self.x.wrapping_mul(&rhs), self.y.wrapping_mul(&rhs))
+    }
+}
+
 impl<T> From<(T, T)> for Vector<T> {
     #[inline]
     fn from(src: (T, T)) -> Vector<T> {
@@ -134,9 +479,35 @@ impl<T> From<Size<T>> for Vector<T> {
     }
 }
 
-impl<T, U> std::ops::Add<U> for Vector<T>
+impl<T: Zero> Zero for Vector<T> {
+    #[inline]
+    fn zero() -> Self {
+        Self::new(T::zero(), T::zero())
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.x.is_zero() && self.y.is_zero()
+    }
+}
+
+impl<T: Zero> core::iter::Sum for Vector<T> {
+    #[inline]
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |acc, v| acc + v)
+    }
+}
+
+impl<'a, T: Zero + Copy> core::iter::Sum<&'a Vector<T>> for Vector<T> {
+    #[inline]
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl<T, U> core::ops::Add<U> for Vector<T>
 where
-    T: std::ops::Add<T, Output = T>,
+    T: core::ops::Add<T, Output = T>,
     U: Into<Self>,
 {
     type Output = Self;
@@ -148,9 +519,9 @@ where
     }
 }
 
-impl<T, U> std::ops::Sub<U> for Vector<T>
+impl<T, U> core::ops::Sub<U> for Vector<T>
 where
-    T: std::ops::Sub<T, Output = T>,
+    T: core::ops::Sub<T, Output = T>,
     U: Into<Self>,
 {
     type Output = Self;
@@ -162,9 +533,21 @@ where
     }
 }
 
-impl<T> std::ops::Mul<T> for Vector<T>
+impl<T> core::ops::Neg for Vector<T>
+where
+    T: core::ops::Neg<Output = T>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self::new(-self.x, -self.y)
+    }
+}
+
+impl<T> core::ops::Mul<T> for Vector<T>
 where
-    T: std::ops::Mul<T, Output = T> + Copy,
+    T: core::ops::Mul<T, Output = T> + Copy,
 {
     type Output = Self;
 
@@ -174,9 +557,9 @@ where
     }
 }
 
-impl<T> std::ops::Div<T> for Vector<T>
+impl<T> core::ops::Div<T> for Vector<T>
 where
-    T: std::ops::Div<T, Output = T> + Copy,
+    T: core::ops::Div<T, Output = T> + Copy,
 {
     type Output = Self;
 
@@ -186,9 +569,9 @@ where
     }
 }
 
-impl<T, U> std::ops::AddAssign<U> for Vector<T>
+impl<T, U> core::ops::AddAssign<U> for Vector<T>
 where
-    T: std::ops::AddAssign<T>,
+    T: core::ops::AddAssign<T>,
     U: Into<Self>,
 {
     #[inline]
@@ -199,9 +582,9 @@ where
     }
 }
 
-impl<T, U> std::ops::SubAssign<U> for Vector<T>
+impl<T, U> core::ops::SubAssign<U> for Vector<T>
 where
-    T: std::ops::SubAssign<T>,
+    T: core::ops::SubAssign<T>,
     U: Into<Self>,
 {
     #[inline]
@@ -212,9 +595,9 @@ where
     }
 }
 
-impl<T> std::ops::MulAssign<T> for Vector<T>
+impl<T> core::ops::MulAssign<T> for Vector<T>
 where
-    T: std::ops::MulAssign<T> + Copy,
+    T: core::ops::MulAssign<T> + Copy,
 {
     #[inline]
     fn mul_assign(&mut self, rhs: T) {
@@ -223,9 +606,9 @@ where
     }
 }
 
-impl<T> std::ops::DivAssign<T> for Vector<T>
+impl<T> core::ops::DivAssign<T> for Vector<T>
 where
-    T: std::ops::DivAssign<T> + Copy,
+    T: core::ops::DivAssign<T> + Copy,
 {
     #[inline]
     fn div_assign(&mut self, rhs: T) {
@@ -234,8 +617,47 @@ where
     }
 }
 
+impl<T> core::ops::Index<usize> for Vector<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: usize) -> &T {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            _ => panic!("Vector index out of range: {}", index),
+        }
+    }
+}
+
+impl<T> core::ops::IndexMut<usize> for Vector<T> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            _ => panic!("Vector index out of range: {}", index),
+        }
+    }
+}
+
+/// Iterates the components in x, y order.
+impl<T> IntoIterator for Vector<T> {
+    type Item = T;
+    type IntoIter = core::array::IntoIter<T, 2>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        [self.x, self.y].into_iter()
+    }
+}
+
+impl_componentwise_ops!(Vector { x, y }, Vector<T>, (T, T), [T; 2]);
+
+impl_left_scalar_mul!(Vector);
+
 #[inline]
-pub fn vector<T>(x: T, y: T) -> Vector<T> {
+pub const fn vector<T>(x: T, y: T) -> Vector<T> {
     Vector::new(x, y)
 }
 
@@ -243,6 +665,109 @@ pub fn vector<T>(x: T, y: T) -> Vector<T> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn as_test() {
+        assert!(vector(3u8, 4).as_f32() == (3.0, 4.0));
+        assert!(vector(-0.5f64, 2.5).as_i32() == (0, 2));
+        assert!(vector(-0.5f64, f64::INFINITY).as_u32() == (0, u32::MAX));
+        assert!(vector(f32::NEG_INFINITY, 0.0).as_i32() == (i32::MIN, 0));
+    }
+
+    #[test]
+    fn try_cast_test() {
+        assert!(vector(1, 2).try_cast::<u8>() == Ok(vector(1, 2)));
+        let e = vector(300, 2).try_cast::<u8>().unwrap_err();
+        assert!(e.type_name() == "Vector" && e.component() == "x");
+        let e = vector(1, -2).try_cast::<u8>().unwrap_err();
+        assert!(e.type_name() == "Vector" && e.component() == "y");
+        assert!(vector(1, 2).cast::<u8>().is_some());
+    }
+
+    #[test]
+    fn wrapping_test() {
+        assert!(vector(i32::MAX, 0).wrapping_add((1, -1)) == (i32::MIN, -1));
+        assert!(vector(i32::MIN, 5).wrapping_sub((1, 10)) == (i32::MAX, -5));
+        assert!(vector(i32::MAX, 3).wrapping_mul(2) == (-2, 6));
+    }
+
+    #[test]
+    fn const_test() {
+        const A: Vector<i32> = Vector::new(1, 2);
+        const B: Vector<i32> = vector(3, 4);
+        assert!(A == (1, 2) && B == (3, 4));
+    }
+
+    #[test]
+    fn lerp_test() {
+        let a = vector(0.1f32, -3.0);
+        let b = vector(0.7f32, 5.0);
+        assert!(a.lerp(b, 0.0) == a && a.lerp(b, 1.0) == b);
+        assert!(a.lerp((0.7, 5.0), 0.5) == (0.4, 1.0));
+        assert!(vector(0.0f32, 0.0).lerp((1.0, 2.0), 2.0) == (2.0, 4.0));
+        assert!(vector(0.0f32, 0.0).lerp_clamped((1.0, 2.0), 2.0) == (1.0, 2.0));
+        assert!(vector(0.0f32, 0.0).lerp_clamped((1.0, 2.0), -1.0) == (0.0, 0.0));
+        assert!(vector(1, 2).lerp((3, 4), 1) == (3, 4));
+    }
+
+    #[test]
+    fn min_max_test() {
+        let a = vector(1, 20);
+        let b = vector(10, 2);
+        assert!(a.min(b) == (1, 2) && a.max(b) == (10, 20));
+        assert!(a.min((0, 30)) == (0, 20));
+        assert!(vector(-1.5f32, 2.0).min_element() == -1.5);
+        assert!(vector(-1.5f32, 2.0).max_element() == 2.0);
+    }
+
+    #[test]
+    fn clamp_test() {
+        assert!(vector(-5, 50).clamp((0, 0), (10, 10)) == (0, 10));
+        assert!(vector(5.0f32, 5.0).clamp((0.0, 0.0), (10.0, 10.0)) == (5.0, 5.0));
+        // `lo` greater than `hi` yields `hi`
+        assert!(vector(5, 5).clamp((8, 0), (2, 10)) == (2, 5));
+    }
+
+    #[test]
+    fn with_test() {
+        let a = vector(1, 2);
+        assert!(a.yx() == (2, 1));
+        assert!(a.with_x(10) == (10, 2));
+        assert!(a.with_y(20) == (1, 20));
+    }
+
+    #[test]
+    fn checked_saturating_test() {
+        let a = vector(127i8, -128);
+        assert!(a.checked_add((0, 1)) == Some(vector(127, -127)));
+        assert!(a.checked_add((1, 0)).is_none());
+        assert!(a.checked_sub((0, 1)).is_none());
+        assert!(a.saturating_add((1, -1)) == (127, -128));
+        assert!(a.saturating_sub((-1, 1)) == (127, -128));
+        assert!(vector(255u8, 0).saturating_add((1, 1)) == (255, 1));
+        assert!(vector(255u8, 0).checked_sub((0, 1)).is_none());
+    }
+
+    #[test]
+    fn ord_test() {
+        assert!(vector(1, 9) < vector(2, 0) && vector(1, 0) < vector(1, 1));
+        let key = vector(f32::NAN, 0.0).total_cmp_key();
+        assert!(key == vector(f32::NAN, 0.0).total_cmp_key());
+        assert!(vector(1.0f32, 0.0).total_cmp_key() < key);
+    }
+
+    #[test]
+    fn round_test() {
+        let a = vector(-0.2f64, 0.7);
+        assert!(a.floor() == (-1.0, 0.0) && a.ceil() == (-0.0, 1.0) && a.round() == (-0.0, 1.0));
+    }
+
+    #[test]
+    fn manhattan_length_test() {
+        assert!(vector(-3, 4).manhattan_length() == 7);
+        assert!(vector(3u32, 4).manhattan_length() == 7);
+        assert!(vector(-0.5f32, -1.0).manhattan_length() == 1.5);
+    }
+
     #[test]
     fn map_test() {
         assert!(vector(1, 2).map(|x| x * 2) == (2, 4));
@@ -271,6 +796,174 @@ mod tests {
         assert!(d.abs() <= f32::EPSILON);
     }
 
+    #[test]
+    fn normalize_test() {
+        let v = vector(3.0f32, 4.0).normalize();
+        assert!(v == (0.6, 0.8));
+        assert!((v.abs() - 1.0).abs() <= f32::EPSILON);
+        assert!(vector(0.0f32, 0.0).normalize().x.is_nan());
+        let v = vector(1e-3f64, -2e-3).try_normalize().unwrap();
+        assert!((v.abs() - 1.0).abs() <= f64::EPSILON);
+        assert!(vector(0.0f32, 0.0).try_normalize().is_none());
+        assert!(vector(f32::EPSILON / 2.0, 0.0).try_normalize().is_none());
+        assert!(vector(f32::EPSILON * 2.0, 0.0).try_normalize() == Some(vector(1.0, 0.0)));
+        assert!(vector(f32::INFINITY, 0.0).try_normalize().is_none());
+        assert!(vector(0.0f32, 0.0).normalize_or(vector(0.0, 1.0)) == (0.0, 1.0));
+        assert!(vector(2.0f32, 0.0).normalize_or(vector(0.0, 1.0)) == (1.0, 0.0));
+    }
+
+    #[test]
+    fn rotate_test() {
+        use core::f32::consts::{FRAC_PI_2, PI};
+        let v = vector(3.0f32, -2.0);
+        assert!(v.rotate(0.0) == v);
+        let mut r = v;
+        for _ in 0..4 {
+            r = r.rotate(FRAC_PI_2);
+        }
+        assert!((r - v).abs() <= f32::EPSILON * 8.0);
+        let r = vector(1.0f32, 0.0).rotate(FRAC_PI_2);
+        assert!(r.x.abs() <= f32::EPSILON && (r.y - 1.0).abs() <= f32::EPSILON);
+        let r = vector(1.0f64, 1.0).rotate(core::f64::consts::PI);
+        assert!((r - vector(-1.0, -1.0)).abs() <= f64::EPSILON * 4.0);
+        assert!((vector(0.0f32, 2.0).angle() - FRAC_PI_2).abs() <= f32::EPSILON);
+        assert!((vector(-1.0f32, 0.0).angle() - PI).abs() <= f32::EPSILON);
+    }
+
+    #[test]
+    fn from_angle_test() {
+        use core::f32::consts::FRAC_PI_4;
+        let v = Vector::from_angle(FRAC_PI_4);
+        assert!((v.abs() - 1.0).abs() <= f32::EPSILON);
+        assert!((v.x - v.y).abs() <= f32::EPSILON);
+        let a = 2.5f32;
+        assert!((Vector::from_angle(a).angle() - a).abs() <= f32::EPSILON * 4.0);
+        let v = vector(2.0f32, 1.0);
+        assert!((v.rotate(0.5).angle() - (v.angle() + 0.5)).abs() <= f32::EPSILON * 4.0);
+    }
+
+    #[test]
+    fn perp_test() {
+        let v = vector(3, 5);
+        assert!(v.perp() == (-5, 3));
+        assert!(v.perp_cw() == (5, -3));
+        assert!(v.dot(v.perp()) == 0 && v.dot(v.perp_cw()) == 0);
+        assert!(v.perp().perp() == (-3, -5));
+        assert!(v.perp().perp_cw() == v);
+        // counterclockwise with y up: the cross product is positive
+        assert!(v.cross(v.perp()) > 0);
+        let r = vector(1.0f32, 0.0).rotate(core::f32::consts::FRAC_PI_2);
+        assert!((r - vector(1.0, 0.0).perp()).abs() <= f32::EPSILON);
+    }
+
+    #[test]
+    fn reflect_test() {
+        // bounce off a vertical wall whose normal points to the left
+        let v = vector(1.0f32, 1.0);
+        assert!(v.reflect((-1.0, 0.0)) == (-1.0, 1.0));
+        assert!(vector(3, -2).reflect((0, 1)) == (3, 2));
+        let n = vector(1.0f64, 1.0).normalize();
+        let r = vector(1.0f64, 0.0).reflect(n);
+        assert!((r - vector(0.0, -1.0)).abs() <= f64::EPSILON * 2.0);
+    }
+
+    #[test]
+    fn project_test() {
+        let v = vector(3.0f32, 4.0);
+        assert!(v.project_onto((2.0, 0.0)) == Some(vector(3.0, 0.0)));
+        assert!(v.reject_from((2.0, 0.0)) == Some(vector(0.0, 4.0)));
+        assert!(v.project_onto((0.0, 0.0)).is_none());
+        assert!(v.reject_from((0.0, 0.0)).is_none());
+        let p = vector(2.0f64, 0.0).project_onto((1.0, 1.0)).unwrap();
+        assert!(p == (1.0, 1.0));
+    }
+
+    #[test]
+    fn angle_between_test() {
+        use core::f32::consts::{FRAC_PI_2, PI};
+        let a = vector(2.0f32, 0.0);
+        assert!((a.angle_between((0.0, 3.0)) - FRAC_PI_2).abs() <= f32::EPSILON);
+        assert!((a.angle_between((0.0, -3.0)) + FRAC_PI_2).abs() <= f32::EPSILON);
+        assert!((a.angle_to((0.0, -3.0)) - FRAC_PI_2).abs() <= f32::EPSILON);
+        assert!(a.angle_between((5.0, 0.0)) == 0.0);
+        assert!((a.angle_between((-1.0, 0.0)).abs() - PI).abs() <= f32::EPSILON);
+        assert!((a.angle_to((-1.0, 0.0)) - PI).abs() <= f32::EPSILON);
+        let b = vector(1.0f32, 1.0);
+        assert!((b.angle_between(b.rotate(0.3)) - 0.3).abs() <= f32::EPSILON * 4.0);
+        assert!(a.angle_between((0.0, 0.0)).is_nan());
+        assert!(vector(0.0f32, 0.0).angle_to((1.0, 0.0)).is_nan());
+    }
+
+    #[test]
+    fn sum_test() {
+        let v = [vector(1, 2), vector(-3, 4), vector(5, -10)];
+        assert!(v.iter().sum::<Vector<i32>>() == (3, -4));
+        assert!(v.into_iter().sum::<Vector<i32>>() == (3, -4));
+        assert!(core::iter::empty::<Vector<f32>>().sum::<Vector<f32>>() == (0.0, 0.0));
+    }
+
+    #[test]
+    fn approx_eq_test() {
+        let a = vector(1.0f32, 0.0).rotate(core::f32::consts::PI);
+        assert!(a != (-1.0, 0.0));
+        assert!(a.approx_eq(&vector(-1.0, 0.0), 1e-6));
+        assert!(!a.approx_eq(&vector(-1.0, 0.1), 1e-6));
+        assert!(!vector(f32::NAN, 0.0).approx_eq(&vector(f32::NAN, 0.0), 1.0));
+    }
+
+    #[test]
+    fn zero_test() {
+        fn zero<T: Zero>() -> T {
+            Zero::zero()
+        }
+        assert!(zero::<Vector<i32>>() == (0, 0));
+        assert!(zero::<Vector<f32>>() == (0.0, 0.0));
+        assert!(Zero::is_zero(&vector(0, 0)));
+        assert!(!Zero::is_zero(&vector(0.0, 1.0)));
+    }
+
+    #[test]
+    fn as_array_test() {
+        let mut a = vector(1, 2);
+        assert!(*a.as_array() == [1, 2]);
+        a.as_array_mut()[1] = 10;
+        assert!(a.y == 10);
+        assert!(a.into_iter().eq(a.as_array().iter().copied()));
+    }
+
+    #[test]
+    fn index_test() {
+        let mut a = vector(1, 2);
+        assert!(a[0] == 1 && a[1] == 2);
+        a[0] = 10;
+        assert!(a.x == 10);
+    }
+
+    #[test]
+    #[should_panic(expected = "Vector index out of range: 2")]
+    fn index_out_of_range_test() {
+        let a = vector(1, 2);
+        let _ = a[2];
+    }
+
+    #[test]
+    fn into_iter_test() {
+        let a = vector(1, 2);
+        let it = a.into_iter();
+        assert!(it.len() == 2 && it.size_hint() == (2, Some(2)));
+        assert!(a.into_iter().collect::<Vec<_>>() == [a.x, a.y]);
+        assert!(a.iter().copied().collect::<Vec<_>>() == [1, 2]);
+        assert!(a.iter().len() == 2);
+    }
+
+    #[test]
+    fn from_str_test() {
+        assert!("1,-2".parse::<Vector<i32>>() == Ok(vector(1, -2)));
+        assert!(" 1.5 ,  2 ".parse::<Vector<f32>>() == Ok(vector(1.5, 2.0)));
+        let e = "1,".parse::<Vector<i32>>().unwrap_err();
+        assert!(e.kind() == ParseErrorKind::Missing && e.component() == Some("y"));
+    }
+
     #[test]
     fn eq_test() {
         assert!(vector(1, 2) == vector(1, 2));
@@ -300,6 +993,14 @@ mod tests {
         assert!(c == (5, 5));
     }
 
+    #[test]
+    fn neg_test() {
+        assert!(-vector(1, -2) == (-1, 2));
+        assert!(-vector(1.5f32, 0.0) == (-1.5, 0.0));
+        assert!(vector(3, -4).reversed() == -vector(3, -4));
+        assert!(vector(0.5f64, 2.0).reversed() == (-0.5, -2.0));
+    }
+
     #[test]
     fn mul_test() {
         let a = vector(1, 2);
@@ -307,6 +1008,32 @@ mod tests {
         assert!(b == (2, 4));
     }
 
+    #[test]
+    fn componentwise_test() {
+        let v = vector(2, 3);
+        assert!(v * 2 == (4, 6));
+        assert!(v * vector(2, 3) == (4, 9));
+        assert!(v * (2, 3) == (4, 9));
+        assert!(v * [2, 3] == (4, 9));
+        assert!(vector(4.0f32, 9.0) / vector(2.0, 3.0) == (2.0, 3.0));
+        assert!(vector(4.0f32, 9.0) / 2.0 == (2.0, 4.5));
+        let mut a = vector(1.5f64, 2.0);
+        a *= (2.0, 0.5);
+        assert!(a == (3.0, 1.0));
+        a /= [3.0, 0.5];
+        assert!(a == (1.0, 2.0));
+        a *= 2.0;
+        assert!(a == (2.0, 4.0));
+    }
+
+    #[test]
+    fn left_mul_test() {
+        assert!(2 * vector(1, -2) == vector(1, -2) * 2);
+        assert!(2.0f32 * vector(1.5f32, 2.0) == (3.0, 4.0));
+        assert!(3u64 * vector(1u64, 2) == (3, 6));
+        assert!(0.5f64 * vector(1.0f64, 2.0) == vector(1.0, 2.0) * 0.5);
+    }
+
     #[test]
     fn div_test() {
         let a = vector(2, 6);
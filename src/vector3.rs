@@ -0,0 +1,401 @@
+use crate::*;
+
+#[derive(Clone, Copy, PartialEq, Eq, Default, Debug)]
+#[repr(C)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vector3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Vector3<T> {
+    #[inline]
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+
+    #[inline]
+    pub fn map<R>(self, mut f: impl FnMut(T) -> R) -> Vector3<R> {
+        Vector3::new(f(self.x), f(self.y), f(self.z))
+    }
+
+    #[inline]
+    pub fn truncate(self) -> Vector<T> {
+        Vector::new(self.x, self.y)
+    }
+}
+
+impl<T> Vector<T> {
+    #[inline]
+    pub fn extend(self, z: T) -> Vector3<T> {
+        Vector3::new(self.x, self.y, z)
+    }
+}
+
+impl<T: ToPrimitive> Vector3<T> {
+    #[inline]
+    pub fn cast<U: NumCast>(self) -> Option<Vector3<U>> {
+        Some(Vector3::new(
+            U::from(self.x)?,
+            U::from(self.y)?,
+            U::from(self.z)?,
+        ))
+    }
+}
+
+impl<T> Vector3<T>
+where
+    T: core::ops::Add<T, Output = T> + core::ops::Mul<T, Output = T>,
+{
+    #[inline]
+    pub fn dot(self, rhs: impl Into<Self>) -> T {
+        let rhs = rhs.into();
+        self.x * rhs.x + self.y * rhs.y + self.z * rhs.z
+    }
+}
+
+impl<T> Vector3<T>
+where
+    T: core::ops::Sub<T, Output = T> + core::ops::Mul<T, Output = T> + Copy,
+{
+    #[inline]
+    pub fn cross(self, rhs: impl Into<Self>) -> Self {
+        let rhs = rhs.into();
+        Self::new(
+            self.y * rhs.z - self.z * rhs.y,
+            self.z * rhs.x - self.x * rhs.z,
+            self.x * rhs.y - self.y * rhs.x,
+        )
+    }
+}
+
+impl<T> Vector3<T>
+where
+    T: core::ops::Add<T, Output = T> + core::ops::Mul<T, Output = T> + Copy,
+{
+    #[inline]
+    pub fn abs_pow2(self) -> T {
+        self.x * self.x + self.y * self.y + self.z * self.z
+    }
+}
+
+impl<T: Float> Vector3<T> {
+    #[inline]
+    pub fn abs(self) -> T {
+        T::sqrt(self.x.powi(2) + self.y.powi(2) + self.z.powi(2))
+    }
+}
+
+impl<T> PartialEq<(T, T, T)> for Vector3<T>
+where
+    T: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &(T, T, T)) -> bool {
+        self.x == other.0 && self.y == other.1 && self.z == other.2
+    }
+}
+
+impl<T> PartialEq<[T; 3]> for Vector3<T>
+where
+    T: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &[T; 3]) -> bool {
+        self.x == other[0] && self.y == other[1] && self.z == other[2]
+    }
+}
+
+impl<T> PartialEq<Vector3<T>> for (T, T, T)
+where
+    T: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Vector3<T>) -> bool {
+        self.0 == other.x && self.1 == other.y && self.2 == other.z
+    }
+}
+
+impl<T> PartialEq<Vector3<T>> for [T; 3]
+where
+    T: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Vector3<T>) -> bool {
+        self[0] == other.x && self[1] == other.y && self[2] == other.z
+    }
+}
+
+impl<T> From<(T, T, T)> for Vector3<T> {
+    #[inline]
+    fn from(src: (T, T, T)) -> Vector3<T> {
+        Vector3::new(src.0, src.1, src.2)
+    }
+}
+
+impl<T: Copy> From<[T; 3]> for Vector3<T> {
+    #[inline]
+    fn from(src: [T; 3]) -> Vector3<T> {
+        Vector3::new(src[0], src[1], src[2])
+    }
+}
+
+impl<T> From<Point3<T>> for Vector3<T> {
+    #[inline]
+    fn from(src: Point3<T>) -> Vector3<T> {
+        Vector3::new(src.x, src.y, src.z)
+    }
+}
+
+impl<T> From<Size3<T>> for Vector3<T> {
+    #[inline]
+    fn from(src: Size3<T>) -> Vector3<T> {
+        Vector3::new(src.width, src.height, src.depth)
+    }
+}
+
+impl<T, U> core::ops::Add<U> for Vector3<T>
+where
+    T: core::ops::Add<T, Output = T>,
+    U: Into<Self>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: U) -> Self {
+        let rhs = rhs.into();
+        Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl<T, U> core::ops::Sub<U> for Vector3<T>
+where
+    T: core::ops::Sub<T, Output = T>,
+    U: Into<Self>,
+{
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: U) -> Self {
+        let rhs = rhs.into();
+        Self::new(self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl<T> core::ops::Mul<T> for Vector3<T>
+where
+    T: core::ops::Mul<T, Output = T> + Copy,
+{
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: T) -> Self {
+        Self::new(self.x * rhs, self.y * rhs, self.z * rhs)
+    }
+}
+
+impl<T> core::ops::Div<T> for Vector3<T>
+where
+    T: core::ops::Div<T, Output = T> + Copy,
+{
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: T) -> Self {
+        Self::new(self.x / rhs, self.y / rhs, self.z / rhs)
+    }
+}
+
+impl<T, U> core::ops::AddAssign<U> for Vector3<T>
+where
+    T: core::ops::AddAssign<T>,
+    U: Into<Self>,
+{
+    #[inline]
+    fn add_assign(&mut self, rhs: U) {
+        let rhs = rhs.into();
+        self.x += rhs.x;
+        self.y += rhs.y;
+        self.z += rhs.z;
+    }
+}
+
+impl<T, U> core::ops::SubAssign<U> for Vector3<T>
+where
+    T: core::ops::SubAssign<T>,
+    U: Into<Self>,
+{
+    #[inline]
+    fn sub_assign(&mut self, rhs: U) {
+        let rhs = rhs.into();
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+        self.z -= rhs.z;
+    }
+}
+
+impl<T> core::ops::MulAssign<T> for Vector3<T>
+where
+    T: core::ops::MulAssign<T> + Copy,
+{
+    #[inline]
+    fn mul_assign(&mut self, rhs: T) {
+        self.x *= rhs;
+        self.y *= rhs;
+        self.z *= rhs;
+    }
+}
+
+impl<T> core::ops::DivAssign<T> for Vector3<T>
+where
+    T: core::ops::DivAssign<T> + Copy,
+{
+    #[inline]
+    fn div_assign(&mut self, rhs: T) {
+        self.x /= rhs;
+        self.y /= rhs;
+        self.z /= rhs;
+    }
+}
+
+#[inline]
+pub const fn vector3<T>(x: T, y: T, z: T) -> Vector3<T> {
+    Vector3::new(x, y, z)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn const_test() {
+        const A: Vector3<i32> = Vector3::new(1, 2, 3);
+        const B: Vector3<i32> = vector3(1, 2, 3);
+        assert!(A == (1, 2, 3) && B == (1, 2, 3));
+    }
+
+    #[test]
+    fn map_test() {
+        assert!(vector3(1, 2, 3).map(|x| x * 2) == (2, 4, 6));
+    }
+
+    #[test]
+    fn cast_test() {
+        assert!(vector3(1.5f32, -2.0, 3.0).cast::<i32>() == Some(vector3(1, -2, 3)));
+        assert!(vector3(-1, 0, 0).cast::<u32>().is_none());
+    }
+
+    #[test]
+    fn extend_truncate_test() {
+        assert!(vector(1, 2).extend(3) == (1, 2, 3));
+        assert!(vector3(1, 2, 3).truncate() == (1, 2));
+    }
+
+    #[test]
+    #[allow(clippy::identity_op)]
+    fn dot_test() {
+        assert!(vector3(1, 2, 3).dot((4, 5, 6)) == 1 * 4 + 2 * 5 + 3 * 6);
+    }
+
+    #[test]
+    fn cross_test() {
+        assert!(vector3(1, 0, 0).cross((0, 1, 0)) == (0, 0, 1));
+        assert!(vector3(0, 1, 0).cross((1, 0, 0)) == (0, 0, -1));
+        assert!(vector3(1, 2, 3).cross((4, 5, 6)) == (-3, 6, -3));
+        let a = vector3(1, 2, 3);
+        let b = vector3(-4, 5, 7);
+        let c = a.cross(b);
+        assert!(c.dot(a) == 0 && c.dot(b) == 0);
+    }
+
+    #[test]
+    fn abs_pow2_test() {
+        assert!(vector3(2, 3, 4).abs_pow2() == 2 * 2 + 3 * 3 + 4 * 4);
+    }
+
+    #[test]
+    fn abs_test() {
+        let d = vector3(2.0, 3.0, 4.0).abs() - f32::sqrt(2.0 * 2.0 + 3.0 * 3.0 + 4.0 * 4.0);
+        assert!(d.abs() <= f32::EPSILON);
+    }
+
+    #[test]
+    fn eq_test() {
+        assert!(vector3(1, 2, 3) == vector3(1, 2, 3));
+        assert!(vector3(1, 2, 3) == (1, 2, 3));
+        assert!(vector3(1, 2, 3) == [1, 2, 3]);
+        assert!((1, 2, 3) == vector3(1, 2, 3));
+        assert!([1, 2, 3] == vector3(1, 2, 3));
+    }
+
+    #[test]
+    fn add_test() {
+        let a = vector3(1, 2, 3);
+        let b = vector3(6, 7, 8);
+        let c = a + b;
+        assert!(c == (7, 9, 11));
+        let c = a + (6, 7, 8);
+        assert!(c == (7, 9, 11));
+    }
+
+    #[test]
+    fn sub_test() {
+        let a = vector3(1, 2, 3);
+        let b = vector3(6, 7, 8);
+        let c = b - a;
+        assert!(c == (5, 5, 5));
+        let c = b - (1, 2, 3);
+        assert!(c == (5, 5, 5));
+    }
+
+    #[test]
+    fn mul_test() {
+        let a = vector3(1, 2, 3);
+        let b = a * 2;
+        assert!(b == (2, 4, 6));
+    }
+
+    #[test]
+    fn div_test() {
+        let a = vector3(2, 6, 8);
+        let b = a / 2;
+        assert!(b == (1, 3, 4));
+    }
+
+    #[test]
+    fn add_assign_test() {
+        let mut a = vector3(1, 2, 3);
+        let b = vector3(6, 7, 8);
+        a += b;
+        assert!(a == (7, 9, 11));
+        let mut a = vector3(1, 2, 3);
+        a += (6, 7, 8);
+        assert!(a == (7, 9, 11));
+    }
+
+    #[test]
+    fn sub_assign_test() {
+        let mut a = vector3(6, 7, 8);
+        let b = vector3(1, 2, 3);
+        a -= b;
+        assert!(a == (5, 5, 5));
+        let mut a = vector3(6, 7, 8);
+        a -= (1, 2, 3);
+        assert!(a == (5, 5, 5));
+    }
+
+    #[test]
+    fn mul_assign_test() {
+        let mut a = vector3(1, 2, 3);
+        a *= 2;
+        assert!(a == (2, 4, 6));
+    }
+
+    #[test]
+    fn div_assign_test() {
+        let mut a = vector3(3, 6, 9);
+        a /= 3;
+        assert!(a == (1, 2, 3));
+    }
+}
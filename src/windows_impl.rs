@@ -0,0 +1,120 @@
+use crate::*;
+use windows_sys::Win32::Foundation::{POINT, RECT, SIZE};
+
+impl From<POINT> for Point<i32> {
+    #[inline]
+    fn from(src: POINT) -> Self {
+        Point::new(src.x, src.y)
+    }
+}
+
+impl From<Point<i32>> for POINT {
+    #[inline]
+    fn from(src: Point<i32>) -> Self {
+        POINT { x: src.x, y: src.y }
+    }
+}
+
+impl From<SIZE> for Size<i32> {
+    #[inline]
+    fn from(src: SIZE) -> Self {
+        Size::new(src.cx, src.cy)
+    }
+}
+
+impl From<Size<i32>> for SIZE {
+    #[inline]
+    fn from(src: Size<i32>) -> Self {
+        SIZE {
+            cx: src.width,
+            cy: src.height,
+        }
+    }
+}
+
+/// `right` and `bottom` of `RECT` are exclusive, so they map to `Rect::endpoint`.
+/// A `RECT` with `right < left` gives a negative width.
+///
+/// The size is computed with wrapping arithmetic, so a `RECT` whose width or height does not fit
+/// `i32` wraps instead of panicking, and still converts back to the same `RECT`.
+impl From<RECT> for Rect<i32> {
+    #[inline]
+    fn from(src: RECT) -> Self {
+        Rect::new(
+            (src.left, src.top),
+            (
+                src.right.wrapping_sub(src.left),
+                src.bottom.wrapping_sub(src.top),
+            ),
+        )
+    }
+}
+
+/// `right` and `bottom` of the result are `Rect::endpoint`, exclusive as `RECT` expects.
+///
+/// They are computed with wrapping arithmetic like the conversion from `RECT`, so an endpoint
+/// past `i32::MAX` wraps instead of panicking.
+impl From<Rect<i32>> for RECT {
+    #[inline]
+    fn from(src: Rect<i32>) -> Self {
+        RECT {
+            left: src.origin.x,
+            top: src.origin.y,
+            right: src.origin.x.wrapping_add(src.size.width),
+            bottom: src.origin.y.wrapping_add(src.size.height),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn point_test() {
+        let p: POINT = point(-10, 20).into();
+        assert!(p.x == -10 && p.y == 20);
+        assert!(Point::from(p) == (-10, 20));
+    }
+
+    #[test]
+    fn size_test() {
+        let s: SIZE = size(30, 40).into();
+        assert!(s.cx == 30 && s.cy == 40);
+        assert!(Size::from(s) == (30, 40));
+    }
+
+    #[test]
+    fn rect_test() {
+        let rc: RECT = rect((-10, -20), (30, 40)).into();
+        assert!(rc.left == -10 && rc.top == -20 && rc.right == 20 && rc.bottom == 20);
+        assert!(Rect::from(rc) == rect((-10, -20), (30, 40)));
+        let rc = RECT {
+            left: -5,
+            top: -6,
+            right: -1,
+            bottom: 0,
+        };
+        let r = Rect::from(rc);
+        assert!(r == rect((-5, -6), (4, 6)));
+        let back = RECT::from(r);
+        assert!(back.left == -5 && back.top == -6 && back.right == -1 && back.bottom == 0);
+    }
+
+    #[test]
+    fn rect_wrapping_test() {
+        let rc = RECT {
+            left: i32::MIN,
+            top: -1,
+            right: i32::MAX,
+            bottom: i32::MAX,
+        };
+        let r = Rect::from(rc);
+        assert!(r == rect((i32::MIN, -1), (-1, i32::MIN)));
+        let back = RECT::from(r);
+        assert!(back.left == i32::MIN && back.top == -1);
+        assert!(back.right == i32::MAX && back.bottom == i32::MAX);
+        let rc = RECT::from(rect((i32::MAX, 0), (1, 1)));
+        assert!(rc.right == i32::MIN && rc.bottom == 1);
+    }
+}
@@ -0,0 +1,91 @@
+use crate::*;
+use winit::dpi::{LogicalPosition, LogicalSize, PhysicalPosition, PhysicalSize};
+
+macro_rules! impl_winit_position {
+    ($($t:ident),*) => {
+        $(
+            impl<T> From<$t<T>> for Point<T> {
+                #[inline]
+                fn from(src: $t<T>) -> Self {
+                    Point::new(src.x, src.y)
+                }
+            }
+
+            impl<T> From<Point<T>> for $t<T> {
+                #[inline]
+                fn from(src: Point<T>) -> Self {
+                    $t::new(src.x, src.y)
+                }
+            }
+        )*
+    };
+}
+
+impl_winit_position!(PhysicalPosition, LogicalPosition);
+
+macro_rules! impl_winit_size {
+    ($($t:ident),*) => {
+        $(
+            impl<T> From<$t<T>> for Size<T> {
+                #[inline]
+                fn from(src: $t<T>) -> Self {
+                    Size::new(src.width, src.height)
+                }
+            }
+
+            impl<T> From<Size<T>> for $t<T> {
+                #[inline]
+                fn from(src: Size<T>) -> Self {
+                    $t::new(src.width, src.height)
+                }
+            }
+        )*
+    };
+}
+
+impl_winit_size!(PhysicalSize, LogicalSize);
+
+impl<T: Zero> Rect<T> {
+    /// Creates a rect at the origin `(0, 0)` with the size of `size`.
+    #[inline]
+    pub fn from_physical_size(size: PhysicalSize<T>) -> Self {
+        Rect::new((T::zero(), T::zero()), size)
+    }
+}
+
+/// Returns the inner area of `window` in physical pixels with the origin `(0, 0)`.
+#[inline]
+pub fn inner_rect(window: &winit::window::Window) -> Rect<u32> {
+    Rect::from_physical_size(window.inner_size())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn physical_test() {
+        let s = PhysicalSize::<u32>::from(size(640, 480));
+        assert!(s.width == 640 && s.height == 480);
+        assert!(Size::from(s) == (640, 480));
+        let p = PhysicalPosition::<u32>::from(point(10, 20));
+        assert!(p.x == 10 && p.y == 20);
+        assert!(Point::from(p) == (10, 20));
+    }
+
+    #[test]
+    fn logical_test() {
+        let s = LogicalSize::<f64>::from(size(640.5, 480.25));
+        assert!(s.width == 640.5 && s.height == 480.25);
+        assert!(Size::from(s) == (640.5, 480.25));
+        let p = LogicalPosition::<f64>::from(point(-1.5, 2.5));
+        assert!(p.x == -1.5 && p.y == 2.5);
+        assert!(Point::from(p) == (-1.5, 2.5));
+    }
+
+    #[test]
+    fn from_physical_size_test() {
+        let rc = Rect::from_physical_size(PhysicalSize::new(640u32, 480));
+        assert!(rc == rect((0, 0), (640, 480)));
+    }
+}
@@ -0,0 +1,64 @@
+//! Instantiates the non-float API with a fixed-point scalar.
+
+use fixed::types::I32F32;
+use gecl::*;
+
+fn f(v: f64) -> I32F32 {
+    I32F32::from_num(v)
+}
+
+fn pt(x: f64, y: f64) -> Point<I32F32> {
+    point(f(x), f(y))
+}
+
+#[test]
+fn point_test() {
+    let a = pt(1.5, -2.25);
+    assert!(a + vector(f(0.5), f(0.25)) == pt(2.0, -2.0));
+    assert!(a - pt(0.5, 0.75) == vector(f(1.0), f(-3.0)));
+    assert!(a * f(2.0) == pt(3.0, -4.5));
+    assert!(a / f(0.5) == pt(3.0, -4.5));
+    assert!(a.cast::<f64>() == Some(point(1.5, -2.25)));
+}
+
+#[test]
+fn vector_test() {
+    let a = vector(f(3.0), f(4.0));
+    let b = vector(f(-1.0), f(2.0));
+    assert!(a.dot(b) == f(5.0));
+    assert!(a.cross(b) == f(10.0));
+    assert!(a.abs_pow2() == f(25.0));
+    assert!(a + b == vector(f(2.0), f(6.0)));
+}
+
+#[test]
+fn size_rect_test() {
+    let rc = rect(pt(1.0, 2.0), size(f(3.0), f(4.0)));
+    assert!(rc.endpoint() == pt(4.0, 6.0));
+    assert!(rc.translate(vector(f(1.0), f(1.0))) == rect(pt(2.0, 3.0), size(f(3.0), f(4.0))));
+    assert!(rc.scale(f(2.0), f(0.5)) == rect(pt(1.0, 2.0), size(f(6.0), f(2.0))));
+    assert!(Rect::from_points(pt(4.0, 6.0), pt(1.0, 2.0)) == rc);
+}
+
+#[test]
+fn circle_test() {
+    let c = circle(pt(0.0, 0.0), f(2.0));
+    assert!(c.translate(vector(f(1.0), f(1.0))) == circle(pt(1.0, 1.0), f(2.0)));
+    assert!(c.scale(f(1.5)) == circle(pt(0.0, 0.0), f(3.0)));
+}
+
+#[test]
+fn collision_test() {
+    let c = circle(pt(0.0, 0.0), f(2.0));
+    let rc = rect(pt(1.0, 1.0), size(f(2.0), f(2.0)));
+    assert!(is_crossing(&c, &pt(1.0, 1.0)));
+    assert!(!is_crossing(&c, &pt(1.5, 1.5)));
+    assert!(is_crossing(&c, &circle(pt(3.0, 0.0), f(1.0))));
+    assert!(is_crossing(&rc, &pt(3.0, 3.0)));
+    assert!(is_crossing(&rc, &rect(pt(2.5, 2.5), size(f(1.0), f(1.0)))));
+    assert!(is_crossing(&rc, &c));
+    assert!(!is_crossing(&rc, &circle(pt(-1.0, -1.0), f(2.0))));
+    assert!(contains(&c, &circle(pt(0.5, 0.0), f(1.5))));
+    assert!(contains(&rc, &circle(pt(2.0, 2.0), f(1.0))));
+    assert!(contains(&c, &rect(pt(-1.0, -1.0), size(f(2.0), f(2.0)))));
+}